@@ -102,6 +102,7 @@ mod unix {
         let cargo_rustc_link_search = env::var("OPENCV_LIB").unwrap_or("/usr/local/lib".into());
 
         println!("cargo:rustc-link-search=native={}", cargo_rustc_link_search);
+        println!("cargo:rustc-link-lib=opencv_calib3d");
         println!("cargo:rustc-link-lib=opencv_core");
         println!("cargo:rustc-link-lib=opencv_features2d");
         println!("cargo:rustc-link-lib=opencv_xfeatures2d");
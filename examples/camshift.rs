@@ -1,6 +1,7 @@
 extern crate cv;
 use cv::highgui::*;
 use cv::imgproc::*;
+use cv::video::tracking::*;
 use cv::videoio::*;
 use cv::*;
 
@@ -48,9 +49,8 @@ fn main() {
     let mut is_tracking = false;
 
     let mut hist = Mat::new();
-    let hsize = [16];
-    let hranges = [0_f32, 180_f32];
-    let pranges = [hranges];
+    let lowerb = Scalar::new(0, 30, 10, 0);
+    let upperb = Scalar::new(180, 256, 256, 0);
     let mut track_window = Rect::default();
 
     while let Some(mut m) = cap.read() {
@@ -58,19 +58,10 @@ fn main() {
 
         let hsv = m.cvt_color(ColorConversion::BGR2HSV);
 
-        let ch = [(0, 0)];
-        let hue = hsv.mix_channels(1, 1, &ch);
-        let mask = hsv.in_range(Scalar::new(0, 30, 10, 0), Scalar::new(180, 256, 256, 0));
-        let channels = [0];
-
         if selection_status.status {
             println!("Initialize tracking, setting up CAMShift search");
             let selection = selection_status.selection;
-            let roi = hue.roi(selection);
-            let maskroi = mask.roi(selection);
-
-            let raw_hist = roi.calc_hist(&channels, &maskroi, &hsize, &pranges);
-            hist = raw_hist.normalize(0.0, 255.0, NormType::MinMax);
+            hist = calc_hue_histogram(&hsv, selection, 16, lowerb, upperb);
 
             track_window = selection;
             m.rectangle(selection);
@@ -79,11 +70,15 @@ fn main() {
         }
 
         if is_tracking {
-            let back_project = hue.calc_back_project(&channels, &hist, &pranges) & mask;
+            let back_project = calc_hue_back_project(&hsv, &hist, lowerb, upperb);
             let criteria = TermCriteria::new(TermType::Count, 10, 1.0);
-            let track_box = back_project.camshift(track_window, &criteria);
-
-            m.rectangle(track_box.bounding_rect());
+            match back_project.cam_shift(&mut track_window, &criteria) {
+                Ok(track_box) => m.rectangle(track_box.bounding_rect()),
+                Err(e) => {
+                    println!("Lost track: {}", e);
+                    is_tracking = false;
+                }
+            }
         }
 
         m.show("Window", 30).unwrap();
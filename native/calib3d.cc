@@ -0,0 +1,739 @@
+#include "calib3d.h"
+#include "utils.h"
+
+namespace {
+
+std::vector<cv::Point2f> to_points(CVec<Point2f>* points) {
+    std::vector<cv::Point2f> result;
+    ffi_to_cv(*points, &result);
+    return result;
+}
+
+void mask_to_ffi(const cv::Mat& mask, CVec<unsigned char>* dest) {
+    std::vector<unsigned char> mask_vector;
+    if (!mask.empty()) {
+        mask_vector.assign(mask.begin<unsigned char>(), mask.end<unsigned char>());
+    }
+    cv_to_ffi(mask_vector, dest);
+}
+
+cv::Size to_size(Size2i size) {
+    return cv::Size(size.width, size.height);
+}
+
+void mats_to_ffi(const std::vector<cv::Mat>& mats, CVec<cv::Mat*>* dest) {
+    std::vector<cv::Mat*> result;
+    for (const cv::Mat& mat : mats) {
+        result.push_back(new cv::Mat(mat));
+    }
+    cv_to_ffi(result, dest);
+}
+
+cv::Mat or_empty(cv::Mat* mat) {
+    return mat != nullptr ? *mat : cv::Mat();
+}
+
+std::vector<cv::Point3f> to_points3(CVec<Point3f>* points) {
+    std::vector<cv::Point3f> result;
+    ffi_to_cv(*points, &result);
+    return result;
+}
+
+void copy_doubles(const cv::Mat& mat, double* dest) {
+    cv::Mat converted;
+    mat.convertTo(converted, CV_64F);
+    std::copy(converted.begin<double>(), converted.end<double>(), dest);
+}
+
+// Whether the OpenCV version is at least the first 3.4 (`since3`) or 4.x
+// (`since4`) release providing a feature, given as MAJOR * 10000 + MINOR * 100 + REVISION
+bool available_since(int since3, int since4) {
+    const int version = CV_VERSION_MAJOR * 10000 + CV_VERSION_MINOR * 100 + CV_VERSION_REVISION;
+    return version >= (CV_VERSION_MAJOR >= 4 ? since4 : since3);
+}
+
+// SOLVEPNP_IPPE, SOLVEPNP_IPPE_SQUARE and SOLVEPNP_SQPNP are missing from older headers
+void check_pnp_method(int method) {
+    if ((method == 6 || method == 7) && !available_since(30406, 40100)) {
+        CV_Error(cv::Error::StsNotImplemented, "IPPE requires OpenCV 3.4.6 or 4.1");
+    }
+    if (method == 8 && !available_since(30415, 40503)) {
+        CV_Error(cv::Error::StsNotImplemented, "SQPnP requires OpenCV 3.4.15 or 4.5.3");
+    }
+}
+
+}  // namespace
+
+extern "C" {
+
+EmptyResult cv_find_homography(CVec<Point2f>* src_points,
+                               CVec<Point2f>* dst_points,
+                               int method,
+                               double ransac_reproj_threshold,
+                               cv::Mat* homography,
+                               CVec<unsigned char>* mask) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat mask_mat;
+        *homography =
+            cv::findHomography(to_points(src_points), to_points(dst_points), method, ransac_reproj_threshold, mask_mat);
+        mask_to_ffi(mask_mat, mask);
+    });
+}
+
+EmptyResult cv_find_fundamental_mat(CVec<Point2f>* points1,
+                                    CVec<Point2f>* points2,
+                                    int method,
+                                    double ransac_reproj_threshold,
+                                    double confidence,
+                                    cv::Mat* fundamental,
+                                    CVec<unsigned char>* mask) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat mask_mat;
+        *fundamental = cv::findFundamentalMat(
+            to_points(points1), to_points(points2), method, ransac_reproj_threshold, confidence, mask_mat);
+        mask_to_ffi(mask_mat, mask);
+    });
+}
+
+EmptyResult cv_find_essential_mat(CVec<Point2f>* points1,
+                                  CVec<Point2f>* points2,
+                                  cv::Mat* camera_matrix,
+                                  int method,
+                                  double prob,
+                                  double threshold,
+                                  cv::Mat* essential,
+                                  CVec<unsigned char>* mask) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat mask_mat;
+        *essential = cv::findEssentialMat(
+            to_points(points1), to_points(points2), *camera_matrix, method, prob, threshold, mask_mat);
+        mask_to_ffi(mask_mat, mask);
+    });
+}
+
+void cv_recover_pose(cv::Mat* essential,
+                     CVec<Point2f>* points1,
+                     CVec<Point2f>* points2,
+                     cv::Mat* camera_matrix,
+                     cv::Mat* rotation,
+                     cv::Mat* translation,
+                     CVec<unsigned char>* provided_mask,
+                     CVec<unsigned char>* mask,
+                     Result<int>* result) {
+    *result = Result<int>::FromFunction([=]() {
+        cv::Mat mask_mat;
+        if (provided_mask->size > 0) {
+            std::vector<unsigned char> mask_vector(provided_mask->array, provided_mask->array + provided_mask->size);
+            cv::Mat(mask_vector, true).copyTo(mask_mat);
+        }
+        int inliers = cv::recoverPose(
+            *essential, to_points(points1), to_points(points2), *camera_matrix, *rotation, *translation, mask_mat);
+        mask_to_ffi(mask_mat, mask);
+        return inliers;
+    });
+}
+
+EmptyResult cv_estimate_affine_2d(CVec<Point2f>* from,
+                                  CVec<Point2f>* to,
+                                  int method,
+                                  double ransac_reproj_threshold,
+                                  bool partial,
+                                  cv::Mat* affine,
+                                  CVec<unsigned char>* mask) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<unsigned char> mask_vector;
+        if (partial) {
+            *affine = cv::estimateAffinePartial2D(
+                to_points(from), to_points(to), mask_vector, method, ransac_reproj_threshold);
+        } else {
+            *affine = cv::estimateAffine2D(to_points(from), to_points(to), mask_vector, method, ransac_reproj_threshold);
+        }
+        cv_to_ffi(mask_vector, mask);
+    });
+}
+
+void cv_find_chessboard_corners(
+    cv::Mat* image, Size2i pattern_size, int flags, CVec<Point2f>* corners, Result<bool>* result) {
+    *result = Result<bool>::FromFunction([=]() {
+        std::vector<cv::Point2f> corners_vector;
+        bool found = cv::findChessboardCorners(*image, to_size(pattern_size), corners_vector, flags);
+        cv_to_ffi(corners_vector, corners);
+        return found;
+    });
+}
+
+void cv_find_chessboard_corners_sb(
+    cv::Mat* image, Size2i pattern_size, int flags, CVec<Point2f>* corners, Result<bool>* result) {
+    *result = Result<bool>::FromFunction([=]() {
+#if CV_VERSION_MAJOR >= 4
+        std::vector<cv::Point2f> corners_vector;
+        bool found = cv::findChessboardCornersSB(*image, to_size(pattern_size), corners_vector, flags);
+        cv_to_ffi(corners_vector, corners);
+        return found;
+#else
+        CV_Error(cv::Error::StsNotImplemented, "findChessboardCornersSB requires OpenCV 4");
+        return false;
+#endif
+    });
+}
+
+void cv_find_circles_grid(
+    cv::Mat* image, Size2i pattern_size, int flags, CVec<Point2f>* centers, Result<bool>* result) {
+    *result = Result<bool>::FromFunction([=]() {
+        std::vector<cv::Point2f> centers_vector;
+        bool found = cv::findCirclesGrid(*image, to_size(pattern_size), centers_vector, flags);
+        cv_to_ffi(centers_vector, centers);
+        return found;
+    });
+}
+
+EmptyResult
+cv_draw_chessboard_corners(cv::Mat* image, Size2i pattern_size, CVec<Point2f>* corners, bool pattern_was_found) {
+    return EmptyResult::FromFunction([=]() {
+        cv::drawChessboardCorners(*image, to_size(pattern_size), to_points(corners), pattern_was_found);
+    });
+}
+
+void cv_calibrate_camera(CVec<CVec<Point3f>>* object_points,
+                         CVec<CVec<Point2f>>* image_points,
+                         Size2i image_size,
+                         int flags,
+                         cv::TermCriteria* criteria,
+                         cv::Mat* camera_matrix,
+                         cv::Mat* dist_coeffs,
+                         CVec<cv::Mat*>* rvecs,
+                         CVec<cv::Mat*>* tvecs,
+                         Result<double>* result) {
+    *result = Result<double>::FromFunction([=]() {
+        std::vector<std::vector<cv::Point3f>> object_points_vector;
+        std::vector<std::vector<cv::Point2f>> image_points_vector;
+        ffi_to_cv(*object_points, &object_points_vector);
+        ffi_to_cv(*image_points, &image_points_vector);
+        std::vector<cv::Mat> rvecs_vector;
+        std::vector<cv::Mat> tvecs_vector;
+        double rms = cv::calibrateCamera(object_points_vector,
+                                         image_points_vector,
+                                         to_size(image_size),
+                                         *camera_matrix,
+                                         *dist_coeffs,
+                                         rvecs_vector,
+                                         tvecs_vector,
+                                         flags,
+                                         *criteria);
+        mats_to_ffi(rvecs_vector, rvecs);
+        mats_to_ffi(tvecs_vector, tvecs);
+        return rms;
+    });
+}
+
+EmptyResult cv_calibration_write(const char* path,
+                                 cv::Mat* camera_matrix,
+                                 cv::Mat* dist_coeffs,
+                                 CVec<cv::Mat*>* rvecs,
+                                 CVec<cv::Mat*>* tvecs,
+                                 double rms) {
+    return EmptyResult::FromFunction([=]() {
+        cv::FileStorage fs(path, cv::FileStorage::WRITE);
+        if (!fs.isOpened()) {
+            CV_Error(cv::Error::StsError, std::string("Cannot open file storage ") + path);
+        }
+        std::vector<cv::Mat> rvecs_vector;
+        std::vector<cv::Mat> tvecs_vector;
+        ffi_to_cv(*rvecs, &rvecs_vector);
+        ffi_to_cv(*tvecs, &tvecs_vector);
+        fs << "camera_matrix" << *camera_matrix;
+        fs << "dist_coeffs" << *dist_coeffs;
+        fs << "rvecs" << rvecs_vector;
+        fs << "tvecs" << tvecs_vector;
+        fs << "rms" << rms;
+        fs.release();
+    });
+}
+
+void cv_calibration_read(const char* path,
+                         cv::Mat* camera_matrix,
+                         cv::Mat* dist_coeffs,
+                         CVec<cv::Mat*>* rvecs,
+                         CVec<cv::Mat*>* tvecs,
+                         Result<double>* rms) {
+    *rms = Result<double>::FromFunction([=]() {
+        cv::FileStorage fs(path, cv::FileStorage::READ);
+        if (!fs.isOpened()) {
+            CV_Error(cv::Error::StsError, std::string("Cannot open file storage ") + path);
+        }
+        if (fs["camera_matrix"].empty()) {
+            CV_Error(cv::Error::StsParseError, std::string("No calibration found in ") + path);
+        }
+        std::vector<cv::Mat> rvecs_vector;
+        std::vector<cv::Mat> tvecs_vector;
+        double result = 0;
+        fs["camera_matrix"] >> *camera_matrix;
+        fs["dist_coeffs"] >> *dist_coeffs;
+        fs["rvecs"] >> rvecs_vector;
+        fs["tvecs"] >> tvecs_vector;
+        fs["rms"] >> result;
+        mats_to_ffi(rvecs_vector, rvecs);
+        mats_to_ffi(tvecs_vector, tvecs);
+        return result;
+    });
+}
+
+EmptyResult cv_undistort(
+    cv::Mat* src, cv::Mat* camera_matrix, cv::Mat* dist_coeffs, cv::Mat* new_camera_matrix, cv::Mat* dst) {
+    return EmptyResult::FromFunction([=]() {
+        cv::undistort(*src, *dst, *camera_matrix, *dist_coeffs, or_empty(new_camera_matrix));
+    });
+}
+
+EmptyResult cv_undistort_points(CVec<Point2f>* points,
+                                cv::Mat* camera_matrix,
+                                cv::Mat* dist_coeffs,
+                                cv::Mat* r,
+                                cv::Mat* p,
+                                CVec<Point2f>* undistorted) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::Point2f> undistorted_vector;
+        cv::undistortPoints(
+            to_points(points), undistorted_vector, *camera_matrix, *dist_coeffs, or_empty(r), or_empty(p));
+        cv_to_ffi(undistorted_vector, undistorted);
+    });
+}
+
+EmptyResult cv_get_optimal_new_camera_matrix(cv::Mat* camera_matrix,
+                                             cv::Mat* dist_coeffs,
+                                             Size2i image_size,
+                                             double alpha,
+                                             Size2i new_image_size,
+                                             bool center_principal_point,
+                                             cv::Mat* new_camera_matrix,
+                                             Rect* valid_roi) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Rect roi;
+        *new_camera_matrix = cv::getOptimalNewCameraMatrix(*camera_matrix,
+                                                           *dist_coeffs,
+                                                           to_size(image_size),
+                                                           alpha,
+                                                           to_size(new_image_size),
+                                                           &roi,
+                                                           center_principal_point);
+        cv_to_ffi(roi, valid_roi);
+    });
+}
+
+EmptyResult cv_init_undistort_rectify_map(cv::Mat* camera_matrix,
+                                          cv::Mat* dist_coeffs,
+                                          cv::Mat* r,
+                                          cv::Mat* new_camera_matrix,
+                                          Size2i size,
+                                          int map_type,
+                                          cv::Mat* map1,
+                                          cv::Mat* map2) {
+    return EmptyResult::FromFunction([=]() {
+        cv::initUndistortRectifyMap(
+            *camera_matrix, *dist_coeffs, or_empty(r), *new_camera_matrix, to_size(size), map_type, *map1, *map2);
+    });
+}
+
+void cv_solve_pnp(CVec<Point3f>* object_points,
+                  CVec<Point2f>* image_points,
+                  cv::Mat* camera_matrix,
+                  cv::Mat* dist_coeffs,
+                  int method,
+                  double* rvec,
+                  double* tvec,
+                  Result<bool>* result) {
+    *result = Result<bool>::FromFunction([=]() {
+        check_pnp_method(method);
+        cv::Mat rvec_mat;
+        cv::Mat tvec_mat;
+        bool found = cv::solvePnP(to_points3(object_points),
+                                  to_points(image_points),
+                                  *camera_matrix,
+                                  *dist_coeffs,
+                                  rvec_mat,
+                                  tvec_mat,
+                                  false,
+                                  method);
+        if (found) {
+            copy_doubles(rvec_mat, rvec);
+            copy_doubles(tvec_mat, tvec);
+        }
+        return found;
+    });
+}
+
+void cv_solve_pnp_ransac(CVec<Point3f>* object_points,
+                         CVec<Point2f>* image_points,
+                         cv::Mat* camera_matrix,
+                         cv::Mat* dist_coeffs,
+                         int method,
+                         int iterations_count,
+                         float reprojection_error,
+                         double confidence,
+                         double* rvec,
+                         double* tvec,
+                         CVec<int>* inliers,
+                         Result<bool>* result) {
+    *result = Result<bool>::FromFunction([=]() {
+        check_pnp_method(method);
+        cv::Mat rvec_mat;
+        cv::Mat tvec_mat;
+        std::vector<int> inliers_vector;
+        bool found = cv::solvePnPRansac(to_points3(object_points),
+                                        to_points(image_points),
+                                        *camera_matrix,
+                                        *dist_coeffs,
+                                        rvec_mat,
+                                        tvec_mat,
+                                        false,
+                                        iterations_count,
+                                        reprojection_error,
+                                        confidence,
+                                        inliers_vector,
+                                        method);
+        if (found) {
+            copy_doubles(rvec_mat, rvec);
+            copy_doubles(tvec_mat, tvec);
+            cv_to_ffi(inliers_vector, inliers);
+        }
+        return found;
+    });
+}
+
+EmptyResult cv_rodrigues_to_matrix(const double* rvec, double* matrix) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat matrix_mat;
+        cv::Rodrigues(cv::Mat(3, 1, CV_64F, const_cast<double*>(rvec)), matrix_mat);
+        copy_doubles(matrix_mat, matrix);
+    });
+}
+
+EmptyResult cv_rodrigues_to_vector(const double* matrix, double* rvec) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat rvec_mat;
+        cv::Rodrigues(cv::Mat(3, 3, CV_64F, const_cast<double*>(matrix)), rvec_mat);
+        copy_doubles(rvec_mat, rvec);
+    });
+}
+
+EmptyResult cv_project_points(CVec<Point3f>* object_points,
+                              const double* rvec,
+                              const double* tvec,
+                              cv::Mat* camera_matrix,
+                              cv::Mat* dist_coeffs,
+                              CVec<Point2f>* image_points) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::Point2f> image_points_vector;
+        cv::projectPoints(to_points3(object_points),
+                          cv::Mat(3, 1, CV_64F, const_cast<double*>(rvec)),
+                          cv::Mat(3, 1, CV_64F, const_cast<double*>(tvec)),
+                          *camera_matrix,
+                          *dist_coeffs,
+                          image_points_vector);
+        cv_to_ffi(image_points_vector, image_points);
+    });
+}
+
+EmptyResult cv_triangulate_points(cv::Mat* projection1,
+                                  cv::Mat* projection2,
+                                  CVec<Point2f>* points1,
+                                  CVec<Point2f>* points2,
+                                  CVec<Point3d>* points) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat homogeneous;
+        cv::triangulatePoints(*projection1, *projection2, to_points(points1), to_points(points2), homogeneous);
+        homogeneous.convertTo(homogeneous, CV_64F);
+        std::vector<cv::Point3d> points_vector;
+        cv::convertPointsFromHomogeneous(cv::Mat(homogeneous.t()), points_vector);
+        cv_to_ffi(points_vector, points);
+    });
+}
+
+EmptyResult cv_convert_points_from_homogeneous(CVec<Point3f>* points, CVec<Point2f>* converted) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::Point2f> converted_vector;
+        cv::convertPointsFromHomogeneous(to_points3(points), converted_vector);
+        cv_to_ffi(converted_vector, converted);
+    });
+}
+
+EmptyResult cv_convert_points_to_homogeneous(CVec<Point2f>* points, CVec<Point3f>* converted) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::Point3f> converted_vector;
+        cv::convertPointsToHomogeneous(to_points(points), converted_vector);
+        cv_to_ffi(converted_vector, converted);
+    });
+}
+
+EmptyResult cv_decompose_projection_matrix(
+    cv::Mat* projection, double* camera_matrix, double* rotation, double* camera_position, double* euler_angles) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat camera_matrix_mat;
+        cv::Mat rotation_mat;
+        cv::Mat homogeneous_position;
+        cv::Mat euler_angles_mat;
+        cv::decomposeProjectionMatrix(*projection,
+                                      camera_matrix_mat,
+                                      rotation_mat,
+                                      homogeneous_position,
+                                      cv::noArray(),
+                                      cv::noArray(),
+                                      cv::noArray(),
+                                      euler_angles_mat);
+        homogeneous_position.convertTo(homogeneous_position, CV_64F);
+        cv::Mat position = homogeneous_position.rowRange(0, 3) / homogeneous_position.at<double>(3);
+        copy_doubles(camera_matrix_mat, camera_matrix);
+        copy_doubles(rotation_mat, rotation);
+        copy_doubles(position, camera_position);
+        copy_doubles(euler_angles_mat, euler_angles);
+    });
+}
+
+void cv_decompose_homography_mat(cv::Mat* homography,
+                                 cv::Mat* camera_matrix,
+                                 CVec<cv::Mat*>* rotations,
+                                 CVec<cv::Mat*>* translations,
+                                 CVec<cv::Mat*>* normals,
+                                 Result<int>* result) {
+    *result = Result<int>::FromFunction([=]() {
+        std::vector<cv::Mat> rotations_vector;
+        std::vector<cv::Mat> translations_vector;
+        std::vector<cv::Mat> normals_vector;
+        int solutions = cv::decomposeHomographyMat(
+            *homography, *camera_matrix, rotations_vector, translations_vector, normals_vector);
+        mats_to_ffi(rotations_vector, rotations);
+        mats_to_ffi(translations_vector, translations);
+        mats_to_ffi(normals_vector, normals);
+        return solutions;
+    });
+}
+
+void* cv_stereo_bm_new(int min_disparity,
+                       int num_disparities,
+                       int block_size,
+                       int pre_filter_size,
+                       int pre_filter_cap,
+                       int texture_threshold,
+                       int uniqueness_ratio,
+                       int speckle_window_size,
+                       int speckle_range,
+                       int disp12_max_diff) {
+    cv::Ptr<cv::StereoBM> matcher = cv::StereoBM::create(num_disparities, block_size);
+    matcher->setMinDisparity(min_disparity);
+    matcher->setPreFilterSize(pre_filter_size);
+    matcher->setPreFilterCap(pre_filter_cap);
+    matcher->setTextureThreshold(texture_threshold);
+    matcher->setUniquenessRatio(uniqueness_ratio);
+    matcher->setSpeckleWindowSize(speckle_window_size);
+    matcher->setSpeckleRange(speckle_range);
+    matcher->setDisp12MaxDiff(disp12_max_diff);
+    return new cv::Ptr<cv::StereoMatcher>(matcher);
+}
+
+void* cv_stereo_sgbm_new(int min_disparity,
+                         int num_disparities,
+                         int block_size,
+                         int p1,
+                         int p2,
+                         int disp12_max_diff,
+                         int pre_filter_cap,
+                         int uniqueness_ratio,
+                         int speckle_window_size,
+                         int speckle_range,
+                         int mode) {
+    cv::Ptr<cv::StereoSGBM> matcher = cv::StereoSGBM::create(min_disparity,
+                                                             num_disparities,
+                                                             block_size,
+                                                             p1,
+                                                             p2,
+                                                             disp12_max_diff,
+                                                             pre_filter_cap,
+                                                             uniqueness_ratio,
+                                                             speckle_window_size,
+                                                             speckle_range,
+                                                             mode);
+    return new cv::Ptr<cv::StereoMatcher>(matcher);
+}
+
+void cv_stereo_matcher_drop(cv::Ptr<cv::StereoMatcher>* matcher) {
+    delete matcher;
+    matcher = nullptr;
+}
+
+EmptyResult cv_stereo_matcher_compute(cv::Ptr<cv::StereoMatcher>* matcher,
+                                      cv::Mat* left,
+                                      cv::Mat* right,
+                                      cv::Mat* disparity) {
+    return EmptyResult::FromFunction([=]() {
+        (*matcher)->compute(*left, *right, *disparity);
+    });
+}
+
+int cv_stereo_matcher_get_min_disparity(cv::Ptr<cv::StereoMatcher>* matcher) {
+    return (*matcher)->getMinDisparity();
+}
+
+int cv_stereo_matcher_get_num_disparities(cv::Ptr<cv::StereoMatcher>* matcher) {
+    return (*matcher)->getNumDisparities();
+}
+
+int cv_stereo_matcher_get_block_size(cv::Ptr<cv::StereoMatcher>* matcher) {
+    return (*matcher)->getBlockSize();
+}
+
+void cv_stereo_calibrate(CVec<CVec<Point3f>>* object_points,
+                         CVec<CVec<Point2f>>* image_points1,
+                         CVec<CVec<Point2f>>* image_points2,
+                         cv::Mat* camera_matrix1,
+                         cv::Mat* dist_coeffs1,
+                         cv::Mat* camera_matrix2,
+                         cv::Mat* dist_coeffs2,
+                         Size2i image_size,
+                         int flags,
+                         cv::TermCriteria* criteria,
+                         cv::Mat* out_camera_matrix1,
+                         cv::Mat* out_dist_coeffs1,
+                         cv::Mat* out_camera_matrix2,
+                         cv::Mat* out_dist_coeffs2,
+                         cv::Mat* rotation,
+                         cv::Mat* translation,
+                         cv::Mat* essential,
+                         cv::Mat* fundamental,
+                         Result<double>* result) {
+    *result = Result<double>::FromFunction([=]() {
+        std::vector<std::vector<cv::Point3f>> object_points_vector;
+        std::vector<std::vector<cv::Point2f>> image_points1_vector;
+        std::vector<std::vector<cv::Point2f>> image_points2_vector;
+        ffi_to_cv(*object_points, &object_points_vector);
+        ffi_to_cv(*image_points1, &image_points1_vector);
+        ffi_to_cv(*image_points2, &image_points2_vector);
+        *out_camera_matrix1 = camera_matrix1->clone();
+        *out_dist_coeffs1 = dist_coeffs1->clone();
+        *out_camera_matrix2 = camera_matrix2->clone();
+        *out_dist_coeffs2 = dist_coeffs2->clone();
+        return cv::stereoCalibrate(object_points_vector,
+                                   image_points1_vector,
+                                   image_points2_vector,
+                                   *out_camera_matrix1,
+                                   *out_dist_coeffs1,
+                                   *out_camera_matrix2,
+                                   *out_dist_coeffs2,
+                                   to_size(image_size),
+                                   *rotation,
+                                   *translation,
+                                   *essential,
+                                   *fundamental,
+                                   flags,
+                                   *criteria);
+    });
+}
+
+EmptyResult cv_stereo_rectify(cv::Mat* camera_matrix1,
+                              cv::Mat* dist_coeffs1,
+                              cv::Mat* camera_matrix2,
+                              cv::Mat* dist_coeffs2,
+                              Size2i image_size,
+                              cv::Mat* rotation,
+                              cv::Mat* translation,
+                              bool zero_disparity,
+                              double alpha,
+                              cv::Mat* r1,
+                              cv::Mat* r2,
+                              cv::Mat* p1,
+                              cv::Mat* p2,
+                              cv::Mat* q,
+                              Rect* valid_roi1,
+                              Rect* valid_roi2) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Rect roi1;
+        cv::Rect roi2;
+        cv::stereoRectify(*camera_matrix1,
+                          *dist_coeffs1,
+                          *camera_matrix2,
+                          *dist_coeffs2,
+                          to_size(image_size),
+                          *rotation,
+                          *translation,
+                          *r1,
+                          *r2,
+                          *p1,
+                          *p2,
+                          *q,
+                          zero_disparity ? cv::CALIB_ZERO_DISPARITY : 0,
+                          alpha,
+                          cv::Size(),
+                          &roi1,
+                          &roi2);
+        cv_to_ffi(roi1, valid_roi1);
+        cv_to_ffi(roi2, valid_roi2);
+    });
+}
+
+EmptyResult cv_reproject_image_to_3d(cv::Mat* disparity, cv::Mat* q, bool handle_missing_values, cv::Mat* points) {
+    return EmptyResult::FromFunction([=]() {
+        cv::reprojectImageTo3D(*disparity, *points, *q, handle_missing_values, CV_32F);
+    });
+}
+
+EmptyResult cv_filter_speckles(cv::Mat* image, double new_value, int max_speckle_size, double max_diff) {
+    return EmptyResult::FromFunction([=]() {
+        cv::filterSpeckles(*image, new_value, max_speckle_size, max_diff);
+    });
+}
+
+EmptyResult cv_fisheye_undistort_image(
+    cv::Mat* distorted, cv::Mat* k, cv::Mat* d, cv::Mat* knew, Size2i new_size, cv::Mat* undistorted) {
+    return EmptyResult::FromFunction([=]() {
+        cv::fisheye::undistortImage(*distorted, *undistorted, *k, *d, knew != nullptr ? *knew : *k, to_size(new_size));
+    });
+}
+
+EmptyResult cv_fisheye_undistort_points(
+    CVec<Point2f>* points, cv::Mat* k, cv::Mat* d, cv::Mat* r, cv::Mat* p, CVec<Point2f>* undistorted) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::Point2f> undistorted_vector;
+        cv::fisheye::undistortPoints(to_points(points), undistorted_vector, *k, *d, or_empty(r), or_empty(p));
+        cv_to_ffi(undistorted_vector, undistorted);
+    });
+}
+
+EmptyResult cv_fisheye_init_undistort_rectify_map(
+    cv::Mat* k, cv::Mat* d, cv::Mat* r, cv::Mat* p, Size2i size, int map_type, cv::Mat* map1, cv::Mat* map2) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat rotation = r != nullptr ? *r : cv::Mat::eye(3, 3, CV_64F);
+        cv::fisheye::initUndistortRectifyMap(*k, *d, rotation, *p, to_size(size), map_type, *map1, *map2);
+    });
+}
+
+void cv_fisheye_calibrate(CVec<CVec<Point3f>>* object_points,
+                          CVec<CVec<Point2f>>* image_points,
+                          Size2i image_size,
+                          int flags,
+                          cv::TermCriteria* criteria,
+                          cv::Mat* k,
+                          cv::Mat* d,
+                          CVec<cv::Mat*>* rvecs,
+                          CVec<cv::Mat*>* tvecs,
+                          Result<double>* result) {
+    *result = Result<double>::FromFunction([=]() {
+        std::vector<std::vector<cv::Point3f>> object_points_vector;
+        std::vector<std::vector<cv::Point2f>> image_points_vector;
+        ffi_to_cv(*object_points, &object_points_vector);
+        ffi_to_cv(*image_points, &image_points_vector);
+        std::vector<cv::Mat> rvecs_vector;
+        std::vector<cv::Mat> tvecs_vector;
+        double rms = cv::fisheye::calibrate(object_points_vector,
+                                            image_points_vector,
+                                            to_size(image_size),
+                                            *k,
+                                            *d,
+                                            rvecs_vector,
+                                            tvecs_vector,
+                                            flags,
+                                            *criteria);
+        mats_to_ffi(rvecs_vector, rvecs);
+        mats_to_ffi(tvecs_vector, tvecs);
+        return rms;
+    });
+}
+}
@@ -0,0 +1,239 @@
+#ifndef CV_RS_CALIB3D_H
+#define CV_RS_CALIB3D_H
+
+#include "common.h"
+#include <opencv2/calib3d.hpp>
+#include <opencv2/core.hpp>
+#include <opencv2/imgproc.hpp>
+
+extern "C" {
+
+EmptyResult cv_find_homography(CVec<Point2f>* src_points,
+                               CVec<Point2f>* dst_points,
+                               int method,
+                               double ransac_reproj_threshold,
+                               cv::Mat* homography,
+                               CVec<unsigned char>* mask);
+EmptyResult cv_find_fundamental_mat(CVec<Point2f>* points1,
+                                    CVec<Point2f>* points2,
+                                    int method,
+                                    double ransac_reproj_threshold,
+                                    double confidence,
+                                    cv::Mat* fundamental,
+                                    CVec<unsigned char>* mask);
+EmptyResult cv_find_essential_mat(CVec<Point2f>* points1,
+                                  CVec<Point2f>* points2,
+                                  cv::Mat* camera_matrix,
+                                  int method,
+                                  double prob,
+                                  double threshold,
+                                  cv::Mat* essential,
+                                  CVec<unsigned char>* mask);
+void cv_recover_pose(cv::Mat* essential,
+                     CVec<Point2f>* points1,
+                     CVec<Point2f>* points2,
+                     cv::Mat* camera_matrix,
+                     cv::Mat* rotation,
+                     cv::Mat* translation,
+                     CVec<unsigned char>* provided_mask,
+                     CVec<unsigned char>* mask,
+                     Result<int>* result);
+EmptyResult cv_estimate_affine_2d(CVec<Point2f>* from,
+                                  CVec<Point2f>* to,
+                                  int method,
+                                  double ransac_reproj_threshold,
+                                  bool partial,
+                                  cv::Mat* affine,
+                                  CVec<unsigned char>* mask);
+
+void cv_find_chessboard_corners(
+    cv::Mat* image, Size2i pattern_size, int flags, CVec<Point2f>* corners, Result<bool>* result);
+void cv_find_chessboard_corners_sb(
+    cv::Mat* image, Size2i pattern_size, int flags, CVec<Point2f>* corners, Result<bool>* result);
+void cv_find_circles_grid(
+    cv::Mat* image, Size2i pattern_size, int flags, CVec<Point2f>* centers, Result<bool>* result);
+EmptyResult
+cv_draw_chessboard_corners(cv::Mat* image, Size2i pattern_size, CVec<Point2f>* corners, bool pattern_was_found);
+void cv_calibrate_camera(CVec<CVec<Point3f>>* object_points,
+                         CVec<CVec<Point2f>>* image_points,
+                         Size2i image_size,
+                         int flags,
+                         cv::TermCriteria* criteria,
+                         cv::Mat* camera_matrix,
+                         cv::Mat* dist_coeffs,
+                         CVec<cv::Mat*>* rvecs,
+                         CVec<cv::Mat*>* tvecs,
+                         Result<double>* result);
+EmptyResult cv_calibration_write(const char* path,
+                                 cv::Mat* camera_matrix,
+                                 cv::Mat* dist_coeffs,
+                                 CVec<cv::Mat*>* rvecs,
+                                 CVec<cv::Mat*>* tvecs,
+                                 double rms);
+void cv_calibration_read(const char* path,
+                         cv::Mat* camera_matrix,
+                         cv::Mat* dist_coeffs,
+                         CVec<cv::Mat*>* rvecs,
+                         CVec<cv::Mat*>* tvecs,
+                         Result<double>* rms);
+
+EmptyResult cv_undistort(
+    cv::Mat* src, cv::Mat* camera_matrix, cv::Mat* dist_coeffs, cv::Mat* new_camera_matrix, cv::Mat* dst);
+EmptyResult cv_undistort_points(CVec<Point2f>* points,
+                                cv::Mat* camera_matrix,
+                                cv::Mat* dist_coeffs,
+                                cv::Mat* r,
+                                cv::Mat* p,
+                                CVec<Point2f>* undistorted);
+EmptyResult cv_get_optimal_new_camera_matrix(cv::Mat* camera_matrix,
+                                             cv::Mat* dist_coeffs,
+                                             Size2i image_size,
+                                             double alpha,
+                                             Size2i new_image_size,
+                                             bool center_principal_point,
+                                             cv::Mat* new_camera_matrix,
+                                             Rect* valid_roi);
+EmptyResult cv_init_undistort_rectify_map(cv::Mat* camera_matrix,
+                                          cv::Mat* dist_coeffs,
+                                          cv::Mat* r,
+                                          cv::Mat* new_camera_matrix,
+                                          Size2i size,
+                                          int map_type,
+                                          cv::Mat* map1,
+                                          cv::Mat* map2);
+void cv_solve_pnp(CVec<Point3f>* object_points,
+                  CVec<Point2f>* image_points,
+                  cv::Mat* camera_matrix,
+                  cv::Mat* dist_coeffs,
+                  int method,
+                  double* rvec,
+                  double* tvec,
+                  Result<bool>* result);
+void cv_solve_pnp_ransac(CVec<Point3f>* object_points,
+                         CVec<Point2f>* image_points,
+                         cv::Mat* camera_matrix,
+                         cv::Mat* dist_coeffs,
+                         int method,
+                         int iterations_count,
+                         float reprojection_error,
+                         double confidence,
+                         double* rvec,
+                         double* tvec,
+                         CVec<int>* inliers,
+                         Result<bool>* result);
+EmptyResult cv_rodrigues_to_matrix(const double* rvec, double* matrix);
+EmptyResult cv_rodrigues_to_vector(const double* matrix, double* rvec);
+EmptyResult cv_project_points(CVec<Point3f>* object_points,
+                              const double* rvec,
+                              const double* tvec,
+                              cv::Mat* camera_matrix,
+                              cv::Mat* dist_coeffs,
+                              CVec<Point2f>* image_points);
+EmptyResult cv_triangulate_points(cv::Mat* projection1,
+                                  cv::Mat* projection2,
+                                  CVec<Point2f>* points1,
+                                  CVec<Point2f>* points2,
+                                  CVec<Point3d>* points);
+EmptyResult cv_convert_points_from_homogeneous(CVec<Point3f>* points, CVec<Point2f>* converted);
+EmptyResult cv_convert_points_to_homogeneous(CVec<Point2f>* points, CVec<Point3f>* converted);
+EmptyResult cv_decompose_projection_matrix(
+    cv::Mat* projection, double* camera_matrix, double* rotation, double* camera_position, double* euler_angles);
+void cv_decompose_homography_mat(cv::Mat* homography,
+                                 cv::Mat* camera_matrix,
+                                 CVec<cv::Mat*>* rotations,
+                                 CVec<cv::Mat*>* translations,
+                                 CVec<cv::Mat*>* normals,
+                                 Result<int>* result);
+
+// =============================================================================
+//   STEREO
+// =============================================================================
+void* cv_stereo_bm_new(int min_disparity,
+                       int num_disparities,
+                       int block_size,
+                       int pre_filter_size,
+                       int pre_filter_cap,
+                       int texture_threshold,
+                       int uniqueness_ratio,
+                       int speckle_window_size,
+                       int speckle_range,
+                       int disp12_max_diff);
+void* cv_stereo_sgbm_new(int min_disparity,
+                         int num_disparities,
+                         int block_size,
+                         int p1,
+                         int p2,
+                         int disp12_max_diff,
+                         int pre_filter_cap,
+                         int uniqueness_ratio,
+                         int speckle_window_size,
+                         int speckle_range,
+                         int mode);
+void cv_stereo_matcher_drop(cv::Ptr<cv::StereoMatcher>* matcher);
+EmptyResult cv_stereo_matcher_compute(cv::Ptr<cv::StereoMatcher>* matcher,
+                                      cv::Mat* left,
+                                      cv::Mat* right,
+                                      cv::Mat* disparity);
+int cv_stereo_matcher_get_min_disparity(cv::Ptr<cv::StereoMatcher>* matcher);
+int cv_stereo_matcher_get_num_disparities(cv::Ptr<cv::StereoMatcher>* matcher);
+int cv_stereo_matcher_get_block_size(cv::Ptr<cv::StereoMatcher>* matcher);
+void cv_stereo_calibrate(CVec<CVec<Point3f>>* object_points,
+                         CVec<CVec<Point2f>>* image_points1,
+                         CVec<CVec<Point2f>>* image_points2,
+                         cv::Mat* camera_matrix1,
+                         cv::Mat* dist_coeffs1,
+                         cv::Mat* camera_matrix2,
+                         cv::Mat* dist_coeffs2,
+                         Size2i image_size,
+                         int flags,
+                         cv::TermCriteria* criteria,
+                         cv::Mat* out_camera_matrix1,
+                         cv::Mat* out_dist_coeffs1,
+                         cv::Mat* out_camera_matrix2,
+                         cv::Mat* out_dist_coeffs2,
+                         cv::Mat* rotation,
+                         cv::Mat* translation,
+                         cv::Mat* essential,
+                         cv::Mat* fundamental,
+                         Result<double>* result);
+EmptyResult cv_stereo_rectify(cv::Mat* camera_matrix1,
+                              cv::Mat* dist_coeffs1,
+                              cv::Mat* camera_matrix2,
+                              cv::Mat* dist_coeffs2,
+                              Size2i image_size,
+                              cv::Mat* rotation,
+                              cv::Mat* translation,
+                              bool zero_disparity,
+                              double alpha,
+                              cv::Mat* r1,
+                              cv::Mat* r2,
+                              cv::Mat* p1,
+                              cv::Mat* p2,
+                              cv::Mat* q,
+                              Rect* valid_roi1,
+                              Rect* valid_roi2);
+EmptyResult cv_reproject_image_to_3d(cv::Mat* disparity, cv::Mat* q, bool handle_missing_values, cv::Mat* points);
+EmptyResult cv_filter_speckles(cv::Mat* image, double new_value, int max_speckle_size, double max_diff);
+
+// =============================================================================
+//   FISHEYE
+// =============================================================================
+EmptyResult cv_fisheye_undistort_image(
+    cv::Mat* distorted, cv::Mat* k, cv::Mat* d, cv::Mat* knew, Size2i new_size, cv::Mat* undistorted);
+EmptyResult cv_fisheye_undistort_points(
+    CVec<Point2f>* points, cv::Mat* k, cv::Mat* d, cv::Mat* r, cv::Mat* p, CVec<Point2f>* undistorted);
+EmptyResult cv_fisheye_init_undistort_rectify_map(
+    cv::Mat* k, cv::Mat* d, cv::Mat* r, cv::Mat* p, Size2i size, int map_type, cv::Mat* map1, cv::Mat* map2);
+void cv_fisheye_calibrate(CVec<CVec<Point3f>>* object_points,
+                          CVec<CVec<Point2f>>* image_points,
+                          Size2i image_size,
+                          int flags,
+                          cv::TermCriteria* criteria,
+                          cv::Mat* k,
+                          cv::Mat* d,
+                          CVec<cv::Mat*>* rvecs,
+                          CVec<cv::Mat*>* tvecs,
+                          Result<double>* result);
+}
+
+#endif  // CV_RS_CALIB3D_H
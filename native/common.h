@@ -15,6 +15,18 @@ typedef struct {
     float y;
 } Point2f;
 
+typedef struct {
+    float x;
+    float y;
+    float z;
+} Point3f;
+
+typedef struct {
+    double x;
+    double y;
+    double z;
+} Point3d;
+
 typedef struct {
     int width;
     int height;
@@ -1,8 +1,175 @@
 #include "features2d.h"
 #include "utils.h"
 
+namespace {
+
+cv::Scalar to_cv_scalar(Scalar color) {
+    return cv::Scalar(color.v0, color.v1, color.v2, color.v3);
+}
+
+std::vector<char> to_mask(const CVec<char>& mask) {
+    return std::vector<char>(mask.array, mask.array + mask.size);
+}
+
+cv::MSER* as_mser(cv::Ptr<cv::Feature2D>* detector) {
+    return dynamic_cast<cv::MSER*>(detector->get());
+}
+
+}  // namespace
+
 extern "C" {
 
+void cv_feature2d_drop(cv::Ptr<cv::Feature2D>* detector) {
+    delete detector;
+    detector = nullptr;
+}
+
+void cv_feature2d_detect(cv::Ptr<cv::Feature2D>* detector,
+                         cv::Mat* image,
+                         cv::Mat* mask,
+                         CVec<KeyPoint>* keypoints) {
+    std::vector<cv::KeyPoint> keypoints_vector;
+    detector->get()->detect(*image, keypoints_vector, *mask);
+    cv_to_ffi(keypoints_vector, keypoints);
+}
+
+void cv_feature2d_compute(cv::Ptr<cv::Feature2D>* detector,
+                          cv::Mat* image,
+                          CVec<KeyPoint>* provided_keypoints,
+                          CVec<KeyPoint>* keypoints,
+                          cv::Mat* descriptors) {
+    std::vector<cv::KeyPoint> keypoints_vector;
+    ffi_to_cv(*provided_keypoints, &keypoints_vector);
+    // Detector-only algorithms (FAST, AGAST, ...) throw on compute
+    if (detector->get()->descriptorSize() != 0) {
+        detector->get()->compute(*image, keypoints_vector, *descriptors);
+    }
+    cv_to_ffi(keypoints_vector, keypoints);
+}
+
+void cv_feature2d_detect_and_compute(cv::Ptr<cv::Feature2D>* detector,
+                                     cv::Mat* image,
+                                     cv::Mat* mask,
+                                     CVec<KeyPoint>* provided_keypoints,
+                                     CVec<KeyPoint>* keypoints,
+                                     cv::Mat* descriptors,
+                                     bool useProvidedKeypoints) {
+    std::vector<cv::KeyPoint> keypoints_vector;
+    if (useProvidedKeypoints) {
+        ffi_to_cv(*provided_keypoints, &keypoints_vector);
+    }
+    // Detector-only algorithms (FAST, AGAST, ...) throw on detectAndCompute
+    if (detector->get()->descriptorSize() != 0) {
+        detector->get()->detectAndCompute(*image, *mask, keypoints_vector, *descriptors, useProvidedKeypoints);
+    } else if (!useProvidedKeypoints) {
+        detector->get()->detect(*image, keypoints_vector, *mask);
+    }
+    cv_to_ffi(keypoints_vector, keypoints);
+}
+
+int cv_feature2d_descriptor_size(cv::Ptr<cv::Feature2D>* detector) {
+    return detector->get()->descriptorSize();
+}
+
+int cv_feature2d_descriptor_type(cv::Ptr<cv::Feature2D>* detector) {
+    return detector->get()->descriptorType();
+}
+
+int cv_feature2d_default_norm(cv::Ptr<cv::Feature2D>* detector) {
+    return detector->get()->defaultNorm();
+}
+
+void* cv_orb_new(int nfeatures,
+                 float scaleFactor,
+                 int nlevels,
+                 int edgeThreshold,
+                 int firstLevel,
+                 int WTA_K,
+                 int scoreType,
+                 int patchSize,
+                 int fastThreshold) {
+    cv::Ptr<cv::Feature2D> result = cv::ORB::create(nfeatures,
+                                                    scaleFactor,
+                                                    nlevels,
+                                                    edgeThreshold,
+                                                    firstLevel,
+                                                    WTA_K,
+                                                    scoreType,
+                                                    patchSize,
+                                                    fastThreshold);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
+void* cv_akaze_new(int descriptor_type,
+                   int descriptor_size,
+                   int descriptor_channels,
+                   float threshold,
+                   int nOctaves,
+                   int nOctaveLayers,
+                   int diffusivity) {
+    cv::Ptr<cv::Feature2D> result = cv::AKAZE::create(descriptor_type,
+                                                      descriptor_size,
+                                                      descriptor_channels,
+                                                      threshold,
+                                                      nOctaves,
+                                                      nOctaveLayers,
+                                                      diffusivity);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
+void* cv_kaze_new(bool extended, bool upright, float threshold, int nOctaves, int nOctaveLayers, int diffusivity) {
+    cv::Ptr<cv::Feature2D> result =
+        cv::KAZE::create(extended, upright, threshold, nOctaves, nOctaveLayers, diffusivity);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
+void* cv_brisk_new(int thresh, int octaves, float patternScale) {
+    cv::Ptr<cv::Feature2D> result = cv::BRISK::create(thresh, octaves, patternScale);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
+void* cv_fast_new(int threshold, bool nonmaxSuppression, int type) {
+    cv::Ptr<cv::Feature2D> result = cv::FastFeatureDetector::create(threshold, nonmaxSuppression, type);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
+void* cv_agast_new(int threshold, bool nonmaxSuppression, int type) {
+    cv::Ptr<cv::Feature2D> result = cv::AgastFeatureDetector::create(threshold, nonmaxSuppression, type);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
+void* cv_gftt_new(
+    int maxCorners, double qualityLevel, double minDistance, int blockSize, bool useHarrisDetector, double k) {
+    cv::Ptr<cv::Feature2D> result =
+        cv::GFTTDetector::create(maxCorners, qualityLevel, minDistance, blockSize, useHarrisDetector, k);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
+void* cv_simple_blob_detector_new(SimpleBlobDetectorParams params) {
+    cv::SimpleBlobDetector::Params cv_params;
+    cv_params.thresholdStep = params.threshold_step;
+    cv_params.minThreshold = params.min_threshold;
+    cv_params.maxThreshold = params.max_threshold;
+    cv_params.minRepeatability = params.min_repeatability;
+    cv_params.minDistBetweenBlobs = params.min_dist_between_blobs;
+    cv_params.filterByColor = params.filter_by_color;
+    cv_params.blobColor = params.blob_color;
+    cv_params.filterByArea = params.filter_by_area;
+    cv_params.minArea = params.min_area;
+    cv_params.maxArea = params.max_area;
+    cv_params.filterByCircularity = params.filter_by_circularity;
+    cv_params.minCircularity = params.min_circularity;
+    cv_params.maxCircularity = params.max_circularity;
+    cv_params.filterByInertia = params.filter_by_inertia;
+    cv_params.minInertiaRatio = params.min_inertia_ratio;
+    cv_params.maxInertiaRatio = params.max_inertia_ratio;
+    cv_params.filterByConvexity = params.filter_by_convexity;
+    cv_params.minConvexity = params.min_convexity;
+    cv_params.maxConvexity = params.max_convexity;
+    cv::Ptr<cv::Feature2D> result = cv::SimpleBlobDetector::create(cv_params);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
 void* cv_mser_new(int delta,
                   int min_area,
                   int max_area,
@@ -21,76 +188,64 @@ void* cv_mser_new(int delta,
                                                 area_threshold,
                                                 min_margin,
                                                 edge_blur_size);
-    return new cv::Ptr<cv::MSER>(result);
-}
-
-void cv_mser_drop(cv::Ptr<cv::MSER>* detector) {
-    delete detector;
-    detector = nullptr;
+    return new cv::Ptr<cv::Feature2D>(result);
 }
 
-void cv_mser_detect_regions(cv::Ptr<cv::MSER>* detector,
+void cv_mser_detect_regions(cv::Ptr<cv::Feature2D>* detector,
                             cv::Mat* image,
                             CVec<CVec<Point2i>>* msers,
                             CVec<Rect>* bboxes) {
     std::vector<std::vector<cv::Point>> msers_vector;
     std::vector<cv::Rect> bboxes_vector;
 
-    detector->get()->detectRegions(*image, msers_vector, bboxes_vector);
+    as_mser(detector)->detectRegions(*image, msers_vector, bboxes_vector);
 
     cv_to_ffi(msers_vector, msers);
     cv_to_ffi(bboxes_vector, bboxes);
 }
 
-void cv_mser_detect_and_compute(cv::Ptr<cv::MSER>* detector,
-                                cv::Mat* image,
-                                cv::Mat* mask,
-                                CVec<KeyPoint>* keypoints,
-                                cv::Mat* descriptors,
-                                bool useProvidedKeypoints) {
-    std::vector<cv::KeyPoint> keypoints_vector;
-    detector->get()->detectAndCompute(*image, *mask, keypoints_vector, *descriptors, useProvidedKeypoints);
-    cv_to_ffi(keypoints_vector, keypoints);
+int cv_mser_get_delta(cv::Ptr<cv::Feature2D>* detector) {
+    return as_mser(detector)->getDelta();
 }
 
-void* cv_surf_new(double hessianThreshold, int nOctaves, int nOctaveLayers, bool extended, bool upright) {
-    auto result = cv::xfeatures2d::SURF::create(hessianThreshold, nOctaves, nOctaveLayers, extended, upright);
-    return new cv::Ptr<cv::xfeatures2d::SURF>(result);
+void cv_mser_set_delta(cv::Ptr<cv::Feature2D>* detector, int delta) {
+    as_mser(detector)->setDelta(delta);
 }
-void cv_surf_drop(cv::Ptr<cv::xfeatures2d::SURF>* detector) {
-    delete detector;
-    detector = nullptr;
+
+int cv_mser_get_min_area(cv::Ptr<cv::Feature2D>* detector) {
+    return as_mser(detector)->getMinArea();
 }
 
-void cv_surf_detect_and_compute(cv::Ptr<cv::xfeatures2d::SURF>* detector,
-                                cv::Mat* image,
-                                cv::Mat* mask,
-                                CVec<KeyPoint>* keypoints,
-                                cv::Mat* descriptors,
-                                bool useProvidedKeypoints) {
-    std::vector<cv::KeyPoint> keypoints_vector;
-    detector->get()->detectAndCompute(*image, *mask, keypoints_vector, *descriptors, useProvidedKeypoints);
-    cv_to_ffi(keypoints_vector, keypoints);
+void cv_mser_set_min_area(cv::Ptr<cv::Feature2D>* detector, int min_area) {
+    as_mser(detector)->setMinArea(min_area);
 }
 
-void* cv_sift_new(int nfeatures, int nOctaveLayers, double contrastThreshold, double edgeThreshold, double sigma) {
-    auto result = cv::xfeatures2d::SIFT::create(nfeatures, nOctaveLayers, contrastThreshold, edgeThreshold, sigma);
-    return new cv::Ptr<cv::xfeatures2d::SIFT>(result);
+int cv_mser_get_max_area(cv::Ptr<cv::Feature2D>* detector) {
+    return as_mser(detector)->getMaxArea();
 }
-void cv_sift_drop(cv::Ptr<cv::xfeatures2d::SIFT>* detector) {
-    delete detector;
-    detector = nullptr;
+
+void cv_mser_set_max_area(cv::Ptr<cv::Feature2D>* detector, int max_area) {
+    as_mser(detector)->setMaxArea(max_area);
 }
 
-void cv_sift_detect_and_compute(cv::Ptr<cv::xfeatures2d::SIFT>* detector,
-                                cv::Mat* image,
-                                cv::Mat* mask,
-                                CVec<KeyPoint>* keypoints,
-                                cv::Mat* descriptors,
-                                bool useProvidedKeypoints) {
-    std::vector<cv::KeyPoint> keypoints_vector;
-    detector->get()->detectAndCompute(*image, *mask, keypoints_vector, *descriptors, useProvidedKeypoints);
-    cv_to_ffi(keypoints_vector, keypoints);
+bool cv_mser_get_pass2only(cv::Ptr<cv::Feature2D>* detector) {
+    return as_mser(detector)->getPass2Only();
+}
+
+void cv_mser_set_pass2only(cv::Ptr<cv::Feature2D>* detector, bool pass2only) {
+    as_mser(detector)->setPass2Only(pass2only);
+}
+
+void* cv_surf_new(double hessianThreshold, int nOctaves, int nOctaveLayers, bool extended, bool upright) {
+    cv::Ptr<cv::Feature2D> result =
+        cv::xfeatures2d::SURF::create(hessianThreshold, nOctaves, nOctaveLayers, extended, upright);
+    return new cv::Ptr<cv::Feature2D>(result);
+}
+
+void* cv_sift_new(int nfeatures, int nOctaveLayers, double contrastThreshold, double edgeThreshold, double sigma) {
+    cv::Ptr<cv::Feature2D> result =
+        cv::xfeatures2d::SIFT::create(nfeatures, nOctaveLayers, contrastThreshold, edgeThreshold, sigma);
+    return new cv::Ptr<cv::Feature2D>(result);
 }
 
 void* cv_matcher_new(const char* descriptorMatcherType) {
@@ -143,6 +298,76 @@ void cv_matcher_knn_match(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
     cv_to_ffi(matches_vector, matches);
 }
 
+void* cv_matcher_new_flann(
+    int index_type, int index_param0, int index_param1, int index_param2, int checks, float eps, bool sorted) {
+    cv::Ptr<cv::flann::IndexParams> index_params;
+    switch (index_type) {
+    case 0:
+        index_params = cv::makePtr<cv::flann::LinearIndexParams>();
+        break;
+    case 2:
+        index_params = cv::makePtr<cv::flann::LshIndexParams>(index_param0, index_param1, index_param2);
+        break;
+    default:
+        index_params = cv::makePtr<cv::flann::KDTreeIndexParams>(index_param0);
+        break;
+    }
+    auto search_params = cv::makePtr<cv::flann::SearchParams>(checks, eps, sorted);
+    cv::Ptr<cv::DescriptorMatcher> result = cv::makePtr<cv::FlannBasedMatcher>(index_params, search_params);
+    return new cv::Ptr<cv::DescriptorMatcher>(result);
+}
+
+void cv_matcher_clear(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher) {
+    descriptorMatcher.get()->clear();
+}
+
+void cv_matcher_get_train_descriptors(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
+                                      CVec<cv::Mat*>* descriptors) {
+    const std::vector<cv::Mat>& descriptors_vector = descriptorMatcher.get()->getTrainDescriptors();
+    std::vector<cv::Mat*> result;
+    for (const cv::Mat& mat : descriptors_vector) {
+        result.push_back(new cv::Mat(mat));
+    }
+    cv_to_ffi(result, descriptors);
+}
+
+void cv_matcher_match_masked(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
+                             cv::Mat& queryDescriptors,
+                             CVec<cv::Mat*>& masks,
+                             CVec<DMatch>* matches) {
+    std::vector<cv::Mat> masks_vector;
+    ffi_to_cv(masks, &masks_vector);
+    std::vector<cv::DMatch> matches_vector;
+    descriptorMatcher.get()->match(queryDescriptors, matches_vector, masks_vector);
+    cv_to_ffi(matches_vector, matches);
+}
+
+void cv_matcher_knn_match_masked(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
+                                 cv::Mat& queryDescriptors,
+                                 int k,
+                                 CVec<cv::Mat*>& masks,
+                                 bool compactResult,
+                                 CVec<CVec<DMatch>>* matches) {
+    std::vector<cv::Mat> masks_vector;
+    ffi_to_cv(masks, &masks_vector);
+    std::vector<std::vector<cv::DMatch>> matches_vector;
+    descriptorMatcher.get()->knnMatch(queryDescriptors, matches_vector, k, masks_vector, compactResult);
+    cv_to_ffi(matches_vector, matches);
+}
+
+void cv_matcher_radius_match(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
+                             cv::Mat& queryDescriptors,
+                             float maxDistance,
+                             CVec<cv::Mat*>& masks,
+                             bool compactResult,
+                             CVec<CVec<DMatch>>* matches) {
+    std::vector<cv::Mat> masks_vector;
+    ffi_to_cv(masks, &masks_vector);
+    std::vector<std::vector<cv::DMatch>> matches_vector;
+    descriptorMatcher.get()->radiusMatch(queryDescriptors, matches_vector, maxDistance, masks_vector, compactResult);
+    cv_to_ffi(matches_vector, matches);
+}
+
 void* cv_bow_trainer_new(int clusterCount, const cv::TermCriteria& termcrit, int attempts, int flags) {
     return new cv::BOWKMeansTrainer(clusterCount, termcrit, attempts, flags);
 }
@@ -161,4 +386,198 @@ void* cv_bow_trainer_cluster(cv::BOWKMeansTrainer& trainer) {
     *mat = trainer.cluster();
     return (mat);
 }
+
+int cv_bow_trainer_descriptors_count(cv::BOWKMeansTrainer& trainer) {
+    return trainer.descriptorsCount();
+}
+
+void cv_bow_trainer_clear(cv::BOWKMeansTrainer& trainer) {
+    trainer.clear();
+}
+
+void cv_bow_trainer_get_descriptors(cv::BOWKMeansTrainer& trainer, CVec<cv::Mat*>* descriptors) {
+    const std::vector<cv::Mat>& descriptors_vector = trainer.getDescriptors();
+    std::vector<cv::Mat*> result;
+    for (const cv::Mat& mat : descriptors_vector) {
+        result.push_back(new cv::Mat(mat));
+    }
+    cv_to_ffi(result, descriptors);
+}
+
+void* cv_bow_extractor_new(cv::Ptr<cv::Feature2D>* extractor, cv::Ptr<cv::DescriptorMatcher>* matcher) {
+    return new cv::BOWImgDescriptorExtractor(*extractor, *matcher);
+}
+
+void cv_bow_extractor_drop(cv::BOWImgDescriptorExtractor* extractor) {
+    delete extractor;
+    extractor = nullptr;
+}
+
+void cv_bow_extractor_set_vocabulary(cv::BOWImgDescriptorExtractor* extractor, cv::Mat* vocabulary) {
+    extractor->setVocabulary(*vocabulary);
+}
+
+void cv_bow_extractor_get_vocabulary(cv::BOWImgDescriptorExtractor* extractor, cv::Mat* vocabulary) {
+    *vocabulary = extractor->getVocabulary();
+}
+
+int cv_bow_extractor_descriptor_size(cv::BOWImgDescriptorExtractor* extractor) {
+    return extractor->descriptorSize();
+}
+
+int cv_bow_extractor_descriptor_type(cv::BOWImgDescriptorExtractor* extractor) {
+    return extractor->descriptorType();
+}
+
+EmptyResult cv_bow_extractor_compute(cv::BOWImgDescriptorExtractor* extractor,
+                                     cv::Mat* image,
+                                     CVec<KeyPoint>* provided_keypoints,
+                                     CVec<KeyPoint>* keypoints,
+                                     cv::Mat* img_descriptor,
+                                     CVec<CVec<int>>* point_idxs_of_clusters) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::KeyPoint> keypoints_vector;
+        ffi_to_cv(*provided_keypoints, &keypoints_vector);
+        std::vector<std::vector<int>> point_idxs_vector;
+        extractor->compute(*image, keypoints_vector, *img_descriptor, &point_idxs_vector);
+        cv_to_ffi(keypoints_vector, keypoints);
+        cv_to_ffi(point_idxs_vector, point_idxs_of_clusters);
+    });
+}
+
+EmptyResult cv_bow_extractor_compute_descriptors(cv::BOWImgDescriptorExtractor* extractor,
+                                                 cv::Mat* keypoint_descriptors,
+                                                 cv::Mat* img_descriptor,
+                                                 CVec<CVec<int>>* point_idxs_of_clusters) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<std::vector<int>> point_idxs_vector;
+        extractor->compute(*keypoint_descriptors, *img_descriptor, &point_idxs_vector);
+        cv_to_ffi(point_idxs_vector, point_idxs_of_clusters);
+    });
+}
+
+EmptyResult
+cv_draw_keypoints(cv::Mat* image, CVec<KeyPoint>* keypoints, cv::Mat* out_image, Scalar color, int flags) {
+    return EmptyResult::FromFunction([image, keypoints, out_image, color, flags]() {
+        std::vector<cv::KeyPoint> keypoints_vector;
+        ffi_to_cv(*keypoints, &keypoints_vector);
+        cv::drawKeypoints(*image, keypoints_vector, *out_image, to_cv_scalar(color), flags);
+    });
+}
+
+EmptyResult cv_draw_matches(cv::Mat* img1,
+                            CVec<KeyPoint>* keypoints1,
+                            cv::Mat* img2,
+                            CVec<KeyPoint>* keypoints2,
+                            CVec<DMatch>* matches,
+                            cv::Mat* out_image,
+                            Scalar match_color,
+                            Scalar single_point_color,
+                            CVec<char>* matches_mask,
+                            int flags) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::KeyPoint> keypoints1_vector;
+        std::vector<cv::KeyPoint> keypoints2_vector;
+        std::vector<cv::DMatch> matches_vector;
+        ffi_to_cv(*keypoints1, &keypoints1_vector);
+        ffi_to_cv(*keypoints2, &keypoints2_vector);
+        ffi_to_cv(*matches, &matches_vector);
+        cv::drawMatches(*img1,
+                        keypoints1_vector,
+                        *img2,
+                        keypoints2_vector,
+                        matches_vector,
+                        *out_image,
+                        to_cv_scalar(match_color),
+                        to_cv_scalar(single_point_color),
+                        to_mask(*matches_mask),
+                        flags);
+    });
+}
+
+EmptyResult cv_draw_matches_knn(cv::Mat* img1,
+                                CVec<KeyPoint>* keypoints1,
+                                cv::Mat* img2,
+                                CVec<KeyPoint>* keypoints2,
+                                CVec<CVec<DMatch>>* matches,
+                                cv::Mat* out_image,
+                                Scalar match_color,
+                                Scalar single_point_color,
+                                CVec<CVec<char>>* matches_mask,
+                                int flags) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::KeyPoint> keypoints1_vector;
+        std::vector<cv::KeyPoint> keypoints2_vector;
+        std::vector<std::vector<cv::DMatch>> matches_vector;
+        std::vector<std::vector<char>> matches_mask_vector;
+        ffi_to_cv(*keypoints1, &keypoints1_vector);
+        ffi_to_cv(*keypoints2, &keypoints2_vector);
+        ffi_to_cv(*matches, &matches_vector);
+        for (size_t i = 0; i < matches_mask->size; i++) {
+            matches_mask_vector.push_back(to_mask(matches_mask->array[i]));
+        }
+        cv::drawMatches(*img1,
+                        keypoints1_vector,
+                        *img2,
+                        keypoints2_vector,
+                        matches_vector,
+                        *out_image,
+                        to_cv_scalar(match_color),
+                        to_cv_scalar(single_point_color),
+                        matches_mask_vector,
+                        flags);
+    });
+}
+
+float cv_keypoint_overlap(KeyPoint kp1, KeyPoint kp2) {
+    cv::KeyPoint cv_kp1;
+    cv::KeyPoint cv_kp2;
+    ffi_to_cv(kp1, &cv_kp1);
+    ffi_to_cv(kp2, &cv_kp2);
+    return cv::KeyPoint::overlap(cv_kp1, cv_kp2);
+}
+
+void cv_keypoints_filter_retain_best(CVec<KeyPoint>* provided_keypoints, CVec<KeyPoint>* keypoints, int n_points) {
+    std::vector<cv::KeyPoint> keypoints_vector;
+    ffi_to_cv(*provided_keypoints, &keypoints_vector);
+    cv::KeyPointsFilter::retainBest(keypoints_vector, n_points);
+    cv_to_ffi(keypoints_vector, keypoints);
+}
+
+void cv_keypoints_filter_remove_duplicated(CVec<KeyPoint>* provided_keypoints, CVec<KeyPoint>* keypoints) {
+    std::vector<cv::KeyPoint> keypoints_vector;
+    ffi_to_cv(*provided_keypoints, &keypoints_vector);
+    cv::KeyPointsFilter::removeDuplicated(keypoints_vector);
+    cv_to_ffi(keypoints_vector, keypoints);
+}
+
+void cv_keypoints_filter_run_by_pixels_mask(CVec<KeyPoint>* provided_keypoints,
+                                            CVec<KeyPoint>* keypoints,
+                                            cv::Mat* mask) {
+    std::vector<cv::KeyPoint> keypoints_vector;
+    ffi_to_cv(*provided_keypoints, &keypoints_vector);
+    cv::KeyPointsFilter::runByPixelsMask(keypoints_vector, *mask);
+    cv_to_ffi(keypoints_vector, keypoints);
+}
+
+void cv_keypoints_filter_run_by_keypoint_size(CVec<KeyPoint>* provided_keypoints,
+                                              CVec<KeyPoint>* keypoints,
+                                              float min_size,
+                                              float max_size) {
+    std::vector<cv::KeyPoint> keypoints_vector;
+    ffi_to_cv(*provided_keypoints, &keypoints_vector);
+    cv::KeyPointsFilter::runByKeypointSize(keypoints_vector, min_size, max_size);
+    cv_to_ffi(keypoints_vector, keypoints);
+}
+
+void cv_keypoints_filter_run_by_image_border(CVec<KeyPoint>* provided_keypoints,
+                                             CVec<KeyPoint>* keypoints,
+                                             Size2i image_size,
+                                             int border_size) {
+    std::vector<cv::KeyPoint> keypoints_vector;
+    ffi_to_cv(*provided_keypoints, &keypoints_vector);
+    cv::Size cv_image_size(image_size.width, image_size.height);
+    cv::KeyPointsFilter::runByImageBorder(keypoints_vector, cv_image_size, border_size);
+    cv_to_ffi(keypoints_vector, keypoints);
+}
 }
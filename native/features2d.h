@@ -4,10 +4,88 @@
 #include "common.h"
 #include <opencv2/core.hpp>
 #include <opencv2/features2d.hpp>
+#include <opencv2/flann.hpp>
 #include <opencv2/xfeatures2d.hpp>
 
+typedef struct {
+    float threshold_step;
+    float min_threshold;
+    float max_threshold;
+    size_t min_repeatability;
+    float min_dist_between_blobs;
+    bool filter_by_color;
+    unsigned char blob_color;
+    bool filter_by_area;
+    float min_area;
+    float max_area;
+    bool filter_by_circularity;
+    float min_circularity;
+    float max_circularity;
+    bool filter_by_inertia;
+    float min_inertia_ratio;
+    float max_inertia_ratio;
+    bool filter_by_convexity;
+    float min_convexity;
+    float max_convexity;
+} SimpleBlobDetectorParams;
+
 extern "C" {
 
+// =============================================================================
+//   FEATURE2D
+// =============================================================================
+void cv_feature2d_drop(cv::Ptr<cv::Feature2D>* detector);
+void cv_feature2d_detect(cv::Ptr<cv::Feature2D>* detector,
+                         cv::Mat* image,
+                         cv::Mat* mask,
+                         CVec<KeyPoint>* keypoints);
+void cv_feature2d_compute(cv::Ptr<cv::Feature2D>* detector,
+                          cv::Mat* image,
+                          CVec<KeyPoint>* provided_keypoints,
+                          CVec<KeyPoint>* keypoints,
+                          cv::Mat* descriptors);
+void cv_feature2d_detect_and_compute(cv::Ptr<cv::Feature2D>* detector,
+                                     cv::Mat* image,
+                                     cv::Mat* mask,
+                                     CVec<KeyPoint>* provided_keypoints,
+                                     CVec<KeyPoint>* keypoints,
+                                     cv::Mat* descriptors,
+                                     bool useProvidedKeypoints);
+int cv_feature2d_descriptor_size(cv::Ptr<cv::Feature2D>* detector);
+int cv_feature2d_descriptor_type(cv::Ptr<cv::Feature2D>* detector);
+int cv_feature2d_default_norm(cv::Ptr<cv::Feature2D>* detector);
+
+// =============================================================================
+//   ORB, AKAZE, KAZE, BRISK
+// =============================================================================
+void* cv_orb_new(int nfeatures,
+                 float scaleFactor,
+                 int nlevels,
+                 int edgeThreshold,
+                 int firstLevel,
+                 int WTA_K,
+                 int scoreType,
+                 int patchSize,
+                 int fastThreshold);
+void* cv_akaze_new(int descriptor_type,
+                   int descriptor_size,
+                   int descriptor_channels,
+                   float threshold,
+                   int nOctaves,
+                   int nOctaveLayers,
+                   int diffusivity);
+void* cv_kaze_new(bool extended, bool upright, float threshold, int nOctaves, int nOctaveLayers, int diffusivity);
+void* cv_brisk_new(int thresh, int octaves, float patternScale);
+
+// =============================================================================
+//   FAST, AGAST, GFTT, SIMPLE BLOB
+// =============================================================================
+void* cv_fast_new(int threshold, bool nonmaxSuppression, int type);
+void* cv_agast_new(int threshold, bool nonmaxSuppression, int type);
+void* cv_gftt_new(
+    int maxCorners, double qualityLevel, double minDistance, int blockSize, bool useHarrisDetector, double k);
+void* cv_simple_blob_detector_new(SimpleBlobDetectorParams params);
+
 // =============================================================================
 //   MSER
 // =============================================================================
@@ -20,43 +98,30 @@ void* cv_mser_new(int delta,
                   double area_threshold,
                   double min_margin,
                   int edge_blur_size);
-void cv_mser_drop(cv::Ptr<cv::MSER>* detector);
-void cv_mser_detect_regions(cv::Ptr<cv::MSER>* detector,
+void cv_mser_detect_regions(cv::Ptr<cv::Feature2D>* detector,
                             cv::Mat* image,
                             CVec<CVec<Point2i>>* msers,
                             CVec<Rect>* bboxes);
-void cv_mser_detect_and_compute(cv::Ptr<cv::MSER>* detector,
-                                cv::Mat* image,
-                                cv::Mat* mask,
-                                CVec<KeyPoint>* keypoints,
-                                cv::Mat* descriptors,
-                                bool useProvidedKeypoints);
+int cv_mser_get_delta(cv::Ptr<cv::Feature2D>* detector);
+void cv_mser_set_delta(cv::Ptr<cv::Feature2D>* detector, int delta);
+int cv_mser_get_min_area(cv::Ptr<cv::Feature2D>* detector);
+void cv_mser_set_min_area(cv::Ptr<cv::Feature2D>* detector, int min_area);
+int cv_mser_get_max_area(cv::Ptr<cv::Feature2D>* detector);
+void cv_mser_set_max_area(cv::Ptr<cv::Feature2D>* detector, int max_area);
+bool cv_mser_get_pass2only(cv::Ptr<cv::Feature2D>* detector);
+void cv_mser_set_pass2only(cv::Ptr<cv::Feature2D>* detector, bool pass2only);
 
 // =============================================================================
 //   SURF
 // =============================================================================
 
 void* cv_surf_new(double hessianThreshold, int nOctaves, int nOctaveLayers, bool extended, bool upright);
-void cv_surf_drop(cv::Ptr<cv::xfeatures2d::SURF>* detector);
-void cv_surf_detect_and_compute(cv::Ptr<cv::xfeatures2d::SURF>* detector,
-                                cv::Mat* image,
-                                cv::Mat* mask,
-                                CVec<KeyPoint>* keypoints,
-                                cv::Mat* descriptors,
-                                bool useProvidedKeypoints);
 
 // =============================================================================
 //   SIFT
 // =============================================================================
 
 void* cv_sift_new(int nfeatures, int nOctaveLayers, double contrastThreshold, double edgeThreshold, double sigma);
-void cv_sift_drop(cv::Ptr<cv::xfeatures2d::SIFT>* detector);
-void cv_sift_detect_and_compute(cv::Ptr<cv::xfeatures2d::SIFT>* detector,
-                                cv::Mat* image,
-                                cv::Mat* mask,
-                                CVec<KeyPoint>* keypoints,
-                                cv::Mat* descriptors,
-                                bool useProvidedKeypoints);
 
 // =============================================================================
 //   DESCRIPTOR MATCHER
@@ -78,11 +143,96 @@ void cv_matcher_knn_match(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
                           cv::Mat& queryDescriptors,
                           int k,
                           CVec<CVec<DMatch>>* matches);
+void* cv_matcher_new_flann(
+    int index_type, int index_param0, int index_param1, int index_param2, int checks, float eps, bool sorted);
+void cv_matcher_clear(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher);
+void cv_matcher_get_train_descriptors(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
+                                      CVec<cv::Mat*>* descriptors);
+void cv_matcher_match_masked(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
+                             cv::Mat& queryDescriptors,
+                             CVec<cv::Mat*>& masks,
+                             CVec<DMatch>* matches);
+void cv_matcher_knn_match_masked(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
+                                 cv::Mat& queryDescriptors,
+                                 int k,
+                                 CVec<cv::Mat*>& masks,
+                                 bool compactResult,
+                                 CVec<CVec<DMatch>>* matches);
+void cv_matcher_radius_match(cv::Ptr<cv::DescriptorMatcher>& descriptorMatcher,
+                             cv::Mat& queryDescriptors,
+                             float maxDistance,
+                             CVec<cv::Mat*>& masks,
+                             bool compactResult,
+                             CVec<CVec<DMatch>>* matches);
 
 void* cv_bow_trainer_new(int clusterCount, const cv::TermCriteria& termcrit, int attempts, int flags);
 void cv_bow_trainer_drop(cv::BOWKMeansTrainer* trainer);
 void cv_bow_trainer_add(cv::BOWKMeansTrainer& trainer, cv::Mat& descriptors);
 void* cv_bow_trainer_cluster(cv::BOWKMeansTrainer& trainer);
+int cv_bow_trainer_descriptors_count(cv::BOWKMeansTrainer& trainer);
+void cv_bow_trainer_clear(cv::BOWKMeansTrainer& trainer);
+void cv_bow_trainer_get_descriptors(cv::BOWKMeansTrainer& trainer, CVec<cv::Mat*>* descriptors);
+
+void* cv_bow_extractor_new(cv::Ptr<cv::Feature2D>* extractor, cv::Ptr<cv::DescriptorMatcher>* matcher);
+void cv_bow_extractor_drop(cv::BOWImgDescriptorExtractor* extractor);
+void cv_bow_extractor_set_vocabulary(cv::BOWImgDescriptorExtractor* extractor, cv::Mat* vocabulary);
+void cv_bow_extractor_get_vocabulary(cv::BOWImgDescriptorExtractor* extractor, cv::Mat* vocabulary);
+int cv_bow_extractor_descriptor_size(cv::BOWImgDescriptorExtractor* extractor);
+int cv_bow_extractor_descriptor_type(cv::BOWImgDescriptorExtractor* extractor);
+EmptyResult cv_bow_extractor_compute(cv::BOWImgDescriptorExtractor* extractor,
+                                     cv::Mat* image,
+                                     CVec<KeyPoint>* provided_keypoints,
+                                     CVec<KeyPoint>* keypoints,
+                                     cv::Mat* img_descriptor,
+                                     CVec<CVec<int>>* point_idxs_of_clusters);
+EmptyResult cv_bow_extractor_compute_descriptors(cv::BOWImgDescriptorExtractor* extractor,
+                                                 cv::Mat* keypoint_descriptors,
+                                                 cv::Mat* img_descriptor,
+                                                 CVec<CVec<int>>* point_idxs_of_clusters);
+
+// =============================================================================
+//   DRAWING
+// =============================================================================
+EmptyResult
+cv_draw_keypoints(cv::Mat* image, CVec<KeyPoint>* keypoints, cv::Mat* out_image, Scalar color, int flags);
+EmptyResult cv_draw_matches(cv::Mat* img1,
+                            CVec<KeyPoint>* keypoints1,
+                            cv::Mat* img2,
+                            CVec<KeyPoint>* keypoints2,
+                            CVec<DMatch>* matches,
+                            cv::Mat* out_image,
+                            Scalar match_color,
+                            Scalar single_point_color,
+                            CVec<char>* matches_mask,
+                            int flags);
+EmptyResult cv_draw_matches_knn(cv::Mat* img1,
+                                CVec<KeyPoint>* keypoints1,
+                                cv::Mat* img2,
+                                CVec<KeyPoint>* keypoints2,
+                                CVec<CVec<DMatch>>* matches,
+                                cv::Mat* out_image,
+                                Scalar match_color,
+                                Scalar single_point_color,
+                                CVec<CVec<char>>* matches_mask,
+                                int flags);
+
+// =============================================================================
+//   KEYPOINTS
+// =============================================================================
+float cv_keypoint_overlap(KeyPoint kp1, KeyPoint kp2);
+void cv_keypoints_filter_retain_best(CVec<KeyPoint>* provided_keypoints, CVec<KeyPoint>* keypoints, int n_points);
+void cv_keypoints_filter_remove_duplicated(CVec<KeyPoint>* provided_keypoints, CVec<KeyPoint>* keypoints);
+void cv_keypoints_filter_run_by_pixels_mask(CVec<KeyPoint>* provided_keypoints,
+                                            CVec<KeyPoint>* keypoints,
+                                            cv::Mat* mask);
+void cv_keypoints_filter_run_by_keypoint_size(CVec<KeyPoint>* provided_keypoints,
+                                              CVec<KeyPoint>* keypoints,
+                                              float min_size,
+                                              float max_size);
+void cv_keypoints_filter_run_by_image_border(CVec<KeyPoint>* provided_keypoints,
+                                             CVec<KeyPoint>* keypoints,
+                                             Size2i image_size,
+                                             int border_size);
 }
 
 #endif  // CV_RS_FEATURES2D_H
@@ -1,4 +1,27 @@
 #include "imgproc.h"
+#include "utils.h"
+
+namespace {
+
+std::vector<cv::Mat> to_mats(CVec<cv::Mat*>* images) {
+    std::vector<cv::Mat> mats;
+    mats.reserve(images->size);
+    for (size_t i = 0; i < images->size; i++) {
+        mats.push_back(*images->array[i]);
+    }
+    return mats;
+}
+
+std::vector<const float*> to_ranges(CVec<CVec<float>>* ranges) {
+    std::vector<const float*> result;
+    result.reserve(ranges->size);
+    for (size_t i = 0; i < ranges->size; i++) {
+        result.push_back(ranges->array[i].array);
+    }
+    return result;
+}
+
+}  // namespace
 
 extern "C" {
 
@@ -36,6 +59,10 @@ void cv_cvt_color(cv::Mat* mat, cv::Mat* out, int code) {
     cv::cvtColor(*mat, *out, code);
 }
 
+EmptyResult cv_demosaicing(cv::Mat* mat, cv::Mat* out, int code, int dst_cn) {
+    return EmptyResult::FromFunction([mat, out, code, dst_cn]() { cv::demosaicing(*mat, *out, code, dst_cn); });
+}
+
 void cv_pyr_down(cv::Mat* mat, cv::Mat* out) {
     cv::pyrDown(*mat, *out);
 }
@@ -93,6 +120,119 @@ void cv_compare_hist(cv::Mat* first_image, cv::Mat* second_image, int method, Re
         [first_image, second_image, method]() { return cv::compareHist(*first_image, *second_image, method); });
 }
 
+EmptyResult cv_calc_hist_images(CVec<cv::Mat*>* images,
+                                CVec<int>* channels,
+                                cv::Mat* mask,
+                                cv::Mat* hist,
+                                CVec<int>* hist_size,
+                                CVec<CVec<float>>* ranges,
+                                bool uniform,
+                                bool accumulate) {
+    return EmptyResult::FromFunction([images, channels, mask, hist, hist_size, ranges, uniform, accumulate]() {
+        std::vector<cv::Mat> mats = to_mats(images);
+        std::vector<const float*> cv_ranges = to_ranges(ranges);
+        cv::calcHist(mats.data(),
+                     static_cast<int>(mats.size()),
+                     channels->array,
+                     *mask,
+                     *hist,
+                     static_cast<int>(channels->size),
+                     hist_size->array,
+                     cv_ranges.data(),
+                     uniform,
+                     accumulate);
+    });
+}
+
+EmptyResult cv_calc_back_project_images(CVec<cv::Mat*>* images,
+                                        CVec<int>* channels,
+                                        cv::Mat* hist,
+                                        cv::Mat* back_project,
+                                        CVec<CVec<float>>* ranges,
+                                        double scale,
+                                        bool uniform) {
+    return EmptyResult::FromFunction([images, channels, hist, back_project, ranges, scale, uniform]() {
+        std::vector<cv::Mat> mats = to_mats(images);
+        std::vector<const float*> cv_ranges = to_ranges(ranges);
+        cv::calcBackProject(mats.data(),
+                            static_cast<int>(mats.size()),
+                            channels->array,
+                            *hist,
+                            *back_project,
+                            cv_ranges.data(),
+                            scale,
+                            uniform);
+    });
+}
+
+void cv_calc_sparse_hist_images(CVec<cv::Mat*>* images,
+                                CVec<int>* channels,
+                                cv::Mat* mask,
+                                CVec<int>* hist_size,
+                                CVec<CVec<float>>* ranges,
+                                bool uniform,
+                                Result<cv::SparseMat*>* result) {
+    *result = Result<cv::SparseMat*>::FromFunction([images, channels, mask, hist_size, ranges, uniform]() {
+        std::vector<cv::Mat> mats = to_mats(images);
+        std::vector<const float*> cv_ranges = to_ranges(ranges);
+        cv::SparseMat hist;
+        cv::calcHist(mats.data(),
+                     static_cast<int>(mats.size()),
+                     channels->array,
+                     *mask,
+                     hist,
+                     static_cast<int>(channels->size),
+                     hist_size->array,
+                     cv_ranges.data(),
+                     uniform);
+        return new cv::SparseMat(hist);
+    });
+}
+
+void cv_sparse_mat_drop(cv::SparseMat* mat) {
+    delete mat;
+    mat = nullptr;
+}
+
+size_t cv_sparse_mat_nz_count(cv::SparseMat* mat) {
+    return mat->nzcount();
+}
+
+float cv_sparse_mat_value(cv::SparseMat* mat, const int* idx) {
+    return mat->value<float>(idx);
+}
+
+void cv_sparse_mat_to_dense(cv::SparseMat* mat, cv::Mat* dst) {
+    mat->copyTo(*dst);
+}
+
+void cv_compare_sparse_hist(cv::SparseMat* first, cv::SparseMat* second, int method, Result<double>* result) {
+    *result =
+        Result<double>::FromFunction([first, second, method]() { return cv::compareHist(*first, *second, method); });
+}
+
+EmptyResult cv_equalize_hist(cv::Mat* src, cv::Mat* dst) {
+    return EmptyResult::FromFunction([src, dst]() { cv::equalizeHist(*src, *dst); });
+}
+
+EmptyResult cv_remap(cv::Mat* src,
+                     cv::Mat* dst,
+                     cv::Mat* map1,
+                     cv::Mat* map2,
+                     int interpolation,
+                     int border_type,
+                     Scalar border_value) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Scalar value(border_value.v0, border_value.v1, border_value.v2, border_value.v3);
+        cv::remap(*src, *dst, *map1, *map2, interpolation, border_type, value);
+    });
+}
+
+void cv_emd(cv::Mat* signature1, cv::Mat* signature2, int distance_type, Result<float>* result) {
+    *result = Result<float>::FromFunction(
+        [signature1, signature2, distance_type]() { return cv::EMD(*signature1, *signature2, distance_type); });
+}
+
 void cv_sobel(
     cv::Mat* src, cv::Mat* dst, int ddepth, int dx, int dy, int k_size, double scale, double delta, int borderType) {
     cv::Sobel(*src, *dst, ddepth, dx, dy, k_size, scale, delta, borderType);
@@ -108,4 +248,127 @@ cv_canny(cv::Mat* image, cv::Mat* edges, double threshold1, double threshold2, i
         cv::Canny(*image, *edges, threshold1, threshold2, aperture_size, l2_gradient);
     });
 }
+
+void cv_flood_fill(cv::Mat* image,
+                   cv::Mat* mask,
+                   Point2i seed_point,
+                   Scalar new_val,
+                   Scalar lo_diff,
+                   Scalar up_diff,
+                   int flags,
+                   Rect* rect,
+                   Result<int>* result) {
+    *result = Result<int>::FromFunction([image, mask, seed_point, new_val, lo_diff, up_diff, flags, rect]() {
+        cv::Point seed(seed_point.x, seed_point.y);
+        cv::Scalar cv_new_val(new_val.v0, new_val.v1, new_val.v2, new_val.v3);
+        cv::Scalar cv_lo_diff(lo_diff.v0, lo_diff.v1, lo_diff.v2, lo_diff.v3);
+        cv::Scalar cv_up_diff(up_diff.v0, up_diff.v1, up_diff.v2, up_diff.v3);
+        cv::Rect cv_rect;
+        int area;
+        if (mask->empty()) {
+            area = cv::floodFill(*image, seed, cv_new_val, &cv_rect, cv_lo_diff, cv_up_diff, flags);
+        } else {
+            area = cv::floodFill(*image, *mask, seed, cv_new_val, &cv_rect, cv_lo_diff, cv_up_diff, flags);
+        }
+        cv_to_ffi(cv_rect, rect);
+        return area;
+    });
+}
+
+EmptyResult cv_watershed(cv::Mat* image, cv::Mat* markers) {
+    return EmptyResult::FromFunction([image, markers]() { cv::watershed(*image, *markers); });
+}
+
+EmptyResult cv_grab_cut(cv::Mat* image,
+                        cv::Mat* mask,
+                        Rect rect,
+                        cv::Mat* bgd_model,
+                        cv::Mat* fgd_model,
+                        int iter_count,
+                        int mode) {
+    return EmptyResult::FromFunction([image, mask, rect, bgd_model, fgd_model, iter_count, mode]() {
+        cv::Rect cv_rect(rect.x, rect.y, rect.width, rect.height);
+        cv::grabCut(*image, *mask, cv_rect, *bgd_model, *fgd_model, iter_count, mode);
+    });
+}
+
+EmptyResult cv_distance_transform(cv::Mat* src, cv::Mat* dst, int distance_type, int mask_size) {
+    return EmptyResult::FromFunction(
+        [src, dst, distance_type, mask_size]() { cv::distanceTransform(*src, *dst, distance_type, mask_size); });
+}
+
+EmptyResult cv_distance_transform_with_labels(
+    cv::Mat* src, cv::Mat* dst, cv::Mat* labels, int distance_type, int mask_size, int label_type) {
+    return EmptyResult::FromFunction([src, dst, labels, distance_type, mask_size, label_type]() {
+        cv::distanceTransform(*src, *dst, *labels, distance_type, mask_size, label_type);
+    });
+}
+
+EmptyResult cv_integral(cv::Mat* src, cv::Mat* sum, int sdepth) {
+    return EmptyResult::FromFunction([src, sum, sdepth]() { cv::integral(*src, *sum, sdepth); });
+}
+
+EmptyResult cv_integral2(cv::Mat* src, cv::Mat* sum, cv::Mat* sqsum, int sdepth, int sqdepth) {
+    return EmptyResult::FromFunction(
+        [src, sum, sqsum, sdepth, sqdepth]() { cv::integral(*src, *sum, *sqsum, sdepth, sqdepth); });
+}
+
+EmptyResult cv_integral3(cv::Mat* src, cv::Mat* sum, cv::Mat* sqsum, cv::Mat* tilted, int sdepth, int sqdepth) {
+    return EmptyResult::FromFunction([src, sum, sqsum, tilted, sdepth, sqdepth]() {
+        cv::integral(*src, *sum, *sqsum, *tilted, sdepth, sqdepth);
+    });
+}
+
+EmptyResult cv_good_features_to_track(cv::Mat* image,
+                                      CVec<Point2f>* corners,
+                                      int max_corners,
+                                      double quality_level,
+                                      double min_distance,
+                                      cv::Mat* mask,
+                                      int block_size,
+                                      bool use_harris_detector,
+                                      double k) {
+    return EmptyResult::FromFunction(
+        [image, corners, max_corners, quality_level, min_distance, mask, block_size, use_harris_detector, k]() {
+            std::vector<cv::Point2f> corners_vector;
+            cv::goodFeaturesToTrack(*image,
+                                    corners_vector,
+                                    max_corners,
+                                    quality_level,
+                                    min_distance,
+                                    *mask,
+                                    block_size,
+                                    use_harris_detector,
+                                    k);
+            cv_to_ffi(corners_vector, corners);
+        });
+}
+
+EmptyResult cv_corner_harris(cv::Mat* src, cv::Mat* dst, int block_size, int ksize, double k, int border_type) {
+    return EmptyResult::FromFunction([src, dst, block_size, ksize, k, border_type]() {
+        cv::cornerHarris(*src, *dst, block_size, ksize, k, border_type);
+    });
+}
+
+EmptyResult cv_corner_min_eigen_val(cv::Mat* src, cv::Mat* dst, int block_size, int ksize, int border_type) {
+    return EmptyResult::FromFunction([src, dst, block_size, ksize, border_type]() {
+        cv::cornerMinEigenVal(*src, *dst, block_size, ksize, border_type);
+    });
+}
+
+EmptyResult cv_corner_sub_pix(cv::Mat* image,
+                              CVec<Point2f>* corners,
+                              CVec<Point2f>* refined,
+                              Size2i win_size,
+                              Size2i zero_zone,
+                              cv::TermCriteria* criteria) {
+    return EmptyResult::FromFunction([image, corners, refined, win_size, zero_zone, criteria]() {
+        std::vector<cv::Point2f> corners_vector;
+        ffi_to_cv(*corners, &corners_vector);
+        cv::Size cv_win_size(win_size.width, win_size.height);
+        cv::Size cv_zero_zone(zero_zone.width, zero_zone.height);
+        cv::cornerSubPix(*image, corners_vector, cv_win_size, cv_zero_zone, *criteria);
+        cv_to_ffi(corners_vector, refined);
+    });
+}
 }
@@ -21,6 +21,7 @@ void cv_ellipse(cv::Mat* mat,
                 int shift);
 
 void cv_cvt_color(cv::Mat* mat, cv::Mat* output, int code);
+EmptyResult cv_demosaicing(cv::Mat* mat, cv::Mat* output, int code, int dst_cn);
 void cv_pyr_down(cv::Mat* mat, cv::Mat* output);
 void cv_threshold(cv::Mat* mat, cv::Mat* out, double thresh, double maxval, int ttype);
 void cv_erode(
@@ -44,11 +45,87 @@ void cv_calc_back_project(const cv::Mat* images,
                           cv::Mat* back_project,
                           const float** ranges);
 void cv_compare_hist(cv::Mat* first_image, cv::Mat* second_image, int method, Result<double>* result);
+EmptyResult cv_calc_hist_images(CVec<cv::Mat*>* images,
+                                CVec<int>* channels,
+                                cv::Mat* mask,
+                                cv::Mat* hist,
+                                CVec<int>* hist_size,
+                                CVec<CVec<float>>* ranges,
+                                bool uniform,
+                                bool accumulate);
+EmptyResult cv_calc_back_project_images(CVec<cv::Mat*>* images,
+                                        CVec<int>* channels,
+                                        cv::Mat* hist,
+                                        cv::Mat* back_project,
+                                        CVec<CVec<float>>* ranges,
+                                        double scale,
+                                        bool uniform);
+void cv_calc_sparse_hist_images(CVec<cv::Mat*>* images,
+                                CVec<int>* channels,
+                                cv::Mat* mask,
+                                CVec<int>* hist_size,
+                                CVec<CVec<float>>* ranges,
+                                bool uniform,
+                                Result<cv::SparseMat*>* result);
+void cv_sparse_mat_drop(cv::SparseMat* mat);
+size_t cv_sparse_mat_nz_count(cv::SparseMat* mat);
+float cv_sparse_mat_value(cv::SparseMat* mat, const int* idx);
+void cv_sparse_mat_to_dense(cv::SparseMat* mat, cv::Mat* dst);
+void cv_compare_sparse_hist(cv::SparseMat* first, cv::SparseMat* second, int method, Result<double>* result);
+EmptyResult cv_equalize_hist(cv::Mat* src, cv::Mat* dst);
+EmptyResult cv_remap(cv::Mat* src,
+                     cv::Mat* dst,
+                     cv::Mat* map1,
+                     cv::Mat* map2,
+                     int interpolation,
+                     int border_type,
+                     Scalar border_value);
+void cv_emd(cv::Mat* signature1, cv::Mat* signature2, int distance_type, Result<float>* result);
 void cv_sobel(
     cv::Mat* src, cv::Mat* dst, int ddepth, int dx, int dy, int k_size, double scale, double delta, int borderType);
 void cv_scharr(cv::Mat* src, cv::Mat* dst, int ddepth, int dx, int dy, double scale, double delta, int borderType);
 EmptyResult
 cv_canny(cv::Mat* image, cv::Mat* edges, double threshold1, double threshold2, int aperture_size, bool l2_gradient);
+void cv_flood_fill(cv::Mat* image,
+                   cv::Mat* mask,
+                   Point2i seed_point,
+                   Scalar new_val,
+                   Scalar lo_diff,
+                   Scalar up_diff,
+                   int flags,
+                   Rect* rect,
+                   Result<int>* result);
+EmptyResult cv_watershed(cv::Mat* image, cv::Mat* markers);
+EmptyResult cv_grab_cut(cv::Mat* image,
+                        cv::Mat* mask,
+                        Rect rect,
+                        cv::Mat* bgd_model,
+                        cv::Mat* fgd_model,
+                        int iter_count,
+                        int mode);
+EmptyResult cv_distance_transform(cv::Mat* src, cv::Mat* dst, int distance_type, int mask_size);
+EmptyResult cv_distance_transform_with_labels(
+    cv::Mat* src, cv::Mat* dst, cv::Mat* labels, int distance_type, int mask_size, int label_type);
+EmptyResult cv_integral(cv::Mat* src, cv::Mat* sum, int sdepth);
+EmptyResult cv_integral2(cv::Mat* src, cv::Mat* sum, cv::Mat* sqsum, int sdepth, int sqdepth);
+EmptyResult cv_integral3(cv::Mat* src, cv::Mat* sum, cv::Mat* sqsum, cv::Mat* tilted, int sdepth, int sqdepth);
+EmptyResult cv_good_features_to_track(cv::Mat* image,
+                                      CVec<Point2f>* corners,
+                                      int max_corners,
+                                      double quality_level,
+                                      double min_distance,
+                                      cv::Mat* mask,
+                                      int block_size,
+                                      bool use_harris_detector,
+                                      double k);
+EmptyResult cv_corner_harris(cv::Mat* src, cv::Mat* dst, int block_size, int ksize, double k, int border_type);
+EmptyResult cv_corner_min_eigen_val(cv::Mat* src, cv::Mat* dst, int block_size, int ksize, int border_type);
+EmptyResult cv_corner_sub_pix(cv::Mat* image,
+                              CVec<Point2f>* corners,
+                              CVec<Point2f>* refined,
+                              Size2i win_size,
+                              Size2i zero_zone,
+                              cv::TermCriteria* criteria);
 }
 
 #endif  // CV_RS_IMGPROC_H
@@ -10,6 +10,17 @@ void* cv_mat_from_file_storage(const char* path, const char* section) {
     return result;
 }
 
+EmptyResult cv_mat_to_file_storage(cv::Mat* mat, const char* path, const char* section) {
+    return EmptyResult::FromFunction([mat, path, section]() {
+        cv::FileStorage fs(path, cv::FileStorage::WRITE);
+        if (!fs.isOpened()) {
+            CV_Error(cv::Error::StsError, std::string("Cannot open file storage ") + path);
+        }
+        fs << section << *mat;
+        fs.release();
+    });
+}
+
 void* cv_mat_new() {
     cv::Mat* image = new cv::Mat();
     return (image);
@@ -158,4 +169,21 @@ void cv_mat_copy_make_border(
     cv::Scalar c(color.v0, color.v1, color.v2, color.v3);
     copyMakeBorder(*src, *d, t, b, l, r, type, c);
 }
+
+void cv_kmeans(cv::Mat* data,
+               int k,
+               cv::Mat* initial_labels,
+               cv::Mat* labels,
+               cv::TermCriteria* criteria,
+               int attempts,
+               int flags,
+               cv::Mat* centers,
+               Result<double>* result) {
+    *result = Result<double>::FromFunction([=]() {
+        if (initial_labels != nullptr) {
+            initial_labels->copyTo(*labels);
+        }
+        return cv::kmeans(*data, k, *labels, *criteria, attempts, flags, *centers);
+    });
+}
 }
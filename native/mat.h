@@ -9,6 +9,7 @@
 extern "C" {
 
 void* cv_mat_from_file_storage(const char* path, const char* section);
+EmptyResult cv_mat_to_file_storage(cv::Mat* mat, const char* path, const char* section);
 void* cv_mat_new();
 void* cv_mat_new_with_size(int rows, int cols, int type);
 void* cv_mat_zeros(int rows, int cols, int type);
@@ -46,6 +47,15 @@ void cv_mat_copy_make_border(const cv::Mat* const src1,
                              int right,
                              int borderType,
                              Scalar value);
+void cv_kmeans(cv::Mat* data,
+               int k,
+               cv::Mat* initial_labels,
+               cv::Mat* labels,
+               cv::TermCriteria* criteria,
+               int attempts,
+               int flags,
+               cv::Mat* centers,
+               Result<double>* result);
 }
 
 #endif  // CV_RS_MAT_H
@@ -16,9 +16,26 @@ void cv_to_ffi(const cv::Point& source, Point2i* dest) {
     dest->y = source.y;
 };
 
+void cv_to_ffi(const cv::Point2f& source, Point2f* dest) {
+    dest->x = source.x;
+    dest->y = source.y;
+}
+
+void cv_to_ffi(const cv::Point3f& source, Point3f* dest) {
+    dest->x = source.x;
+    dest->y = source.y;
+    dest->z = source.z;
+}
+
+void cv_to_ffi(const cv::Point3d& source, Point3d* dest) {
+    dest->x = source.x;
+    dest->y = source.y;
+    dest->z = source.z;
+}
+
 void cv_to_ffi(const cv::KeyPoint& source, KeyPoint* dest) {
-    dest->pt.x = dest->pt.x;
-    dest->pt.y = dest->pt.y;
+    dest->pt.x = source.pt.x;
+    dest->pt.y = source.pt.y;
     dest->size = source.size;
     dest->angle = source.angle;
     dest->response = source.response;
@@ -27,8 +44,8 @@ void cv_to_ffi(const cv::KeyPoint& source, KeyPoint* dest) {
 }
 
 void cv_to_ffi(const cv::DMatch& source, DMatch* dest) {
-    dest->distance = dest->distance;
-    dest->imgIdx = dest->imgIdx;
+    dest->distance = source.distance;
+    dest->imgIdx = source.imgIdx;
     dest->queryIdx = source.queryIdx;
     dest->trainIdx = source.trainIdx;
 }
@@ -42,3 +59,37 @@ void cv_to_ffi(const std::string& source, CDisposableString* dest) {
 void ffi_to_cv(const cv::Mat& source, cv::Mat* dest) {
     *dest = source;
 }
+
+void ffi_to_cv(const Point2f& source, cv::Point2f* dest) {
+    dest->x = source.x;
+    dest->y = source.y;
+}
+
+void ffi_to_cv(const Point3f& source, cv::Point3f* dest) {
+    dest->x = source.x;
+    dest->y = source.y;
+    dest->z = source.z;
+}
+
+void ffi_to_cv(const Point3d& source, cv::Point3d* dest) {
+    dest->x = source.x;
+    dest->y = source.y;
+    dest->z = source.z;
+}
+
+void ffi_to_cv(const KeyPoint& source, cv::KeyPoint* dest) {
+    dest->pt.x = source.pt.x;
+    dest->pt.y = source.pt.y;
+    dest->size = source.size;
+    dest->angle = source.angle;
+    dest->response = source.response;
+    dest->octave = source.octave;
+    dest->class_id = source.class_id;
+}
+
+void ffi_to_cv(const DMatch& source, cv::DMatch* dest) {
+    dest->distance = source.distance;
+    dest->imgIdx = source.imgIdx;
+    dest->queryIdx = source.queryIdx;
+    dest->trainIdx = source.trainIdx;
+}
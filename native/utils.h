@@ -8,6 +8,9 @@
 
 void cv_to_ffi(const cv::Rect& source, Rect* dest);
 void cv_to_ffi(const cv::Point& source, Point2i* dest);
+void cv_to_ffi(const cv::Point2f& source, Point2f* dest);
+void cv_to_ffi(const cv::Point3f& source, Point3f* dest);
+void cv_to_ffi(const cv::Point3d& source, Point3d* dest);
 void cv_to_ffi(const cv::KeyPoint& source, KeyPoint* dest);
 void cv_to_ffi(const cv::DMatch& source, DMatch* dest);
 void cv_to_ffi(const std::string& source, CDisposableString* dest);
@@ -31,14 +34,27 @@ void cv_to_ffi(const std::vector<T>& source, CVec<U>* dest) {
 }
 
 void ffi_to_cv(const cv::Mat& source, cv::Mat* dest);
+void ffi_to_cv(const Point2f& source, cv::Point2f* dest);
+void ffi_to_cv(const Point3f& source, cv::Point3f* dest);
+void ffi_to_cv(const Point3d& source, cv::Point3d* dest);
+void ffi_to_cv(const KeyPoint& source, cv::KeyPoint* dest);
+void ffi_to_cv(const DMatch& source, cv::DMatch* dest);
+
+template <typename T, typename U>
+void ffi_to_cv(const CVec<U>& source, std::vector<T>* dest) {
+    dest->resize(source.size);
+    for (size_t i = 0; i < source.size; i++) {
+        ffi_to_cv(source.array[i], &(*dest)[i]);
+    }
+}
 
 template <typename T, typename U>
 void ffi_to_cv(const CVec<U*>& source, std::vector<T>* dest) {
     dest->reserve(source.size);
     for (size_t i = 0; i < source.size; i++) {
-        T* cell = new T();
-        ffi_to_cv(*source.array[i], cell);
-        dest->push_back(*cell);
+        T cell;
+        ffi_to_cv(*source.array[i], &cell);
+        dest->push_back(cell);
     }
 }
 #endif  // UTILS_H_
@@ -1,4 +1,94 @@
 #include "video.h"
+#include "utils.h"
+
+namespace {
+
+#if CV_VERSION_MAJOR >= 4
+cv::DISOpticalFlow* as_dis(cv::Ptr<cv::DenseOpticalFlow>* dis) {
+    return dynamic_cast<cv::DISOpticalFlow*>(dis->get());
+}
+#else
+// DISOpticalFlow is only in opencv_contrib before OpenCV 4, cv_dis_optical_flow_new
+// never returns an instance so the accessors are unreachable
+struct NoDISOpticalFlow {
+    int getFinestScale() const { return 0; }
+    void setFinestScale(int) {}
+    int getPatchSize() const { return 0; }
+    void setPatchSize(int) {}
+    int getPatchStride() const { return 0; }
+    void setPatchStride(int) {}
+    int getGradientDescentIterations() const { return 0; }
+    void setGradientDescentIterations(int) {}
+    int getVariationalRefinementIterations() const { return 0; }
+    void setVariationalRefinementIterations(int) {}
+    bool getUseSpatialPropagation() const { return false; }
+    void setUseSpatialPropagation(bool) {}
+};
+
+NoDISOpticalFlow* as_dis(cv::Ptr<cv::DenseOpticalFlow>*) {
+    static NoDISOpticalFlow instance;
+    return &instance;
+}
+#endif
+
+cv::Mat or_empty(cv::Mat* mat) {
+    return mat != nullptr ? *mat : cv::Mat();
+}
+
+cv::BackgroundSubtractorMOG2* as_mog2(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    return dynamic_cast<cv::BackgroundSubtractorMOG2*>(subtractor->get());
+}
+
+cv::BackgroundSubtractorKNN* as_knn(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    return dynamic_cast<cv::BackgroundSubtractorKNN*>(subtractor->get());
+}
+
+// Indexed by the Rust KalmanMatrix enum
+cv::Mat cv::KalmanFilter::*const KALMAN_MATRICES[] = {
+    &cv::KalmanFilter::statePre,
+    &cv::KalmanFilter::statePost,
+    &cv::KalmanFilter::transitionMatrix,
+    &cv::KalmanFilter::controlMatrix,
+    &cv::KalmanFilter::measurementMatrix,
+    &cv::KalmanFilter::processNoiseCov,
+    &cv::KalmanFilter::measurementNoiseCov,
+    &cv::KalmanFilter::errorCovPre,
+    &cv::KalmanFilter::errorCovPost,
+    &cv::KalmanFilter::gain,
+};
+
+template <typename T>
+void calc_optical_flow_pyr_lk(const T& prev,
+                              const T& next,
+                              CVec<Point2f>* prev_pts,
+                              Size2i win_size,
+                              int max_level,
+                              cv::TermCriteria* criteria,
+                              CVec<Point2f>* next_pts,
+                              CVec<unsigned char>* status,
+                              CVec<float>* err) {
+    std::vector<cv::Point2f> prev_pts_vector;
+    ffi_to_cv(*prev_pts, &prev_pts_vector);
+    std::vector<cv::Point2f> next_pts_vector;
+    std::vector<unsigned char> status_vector;
+    std::vector<float> err_vector;
+    if (!prev_pts_vector.empty()) {
+        cv::calcOpticalFlowPyrLK(prev,
+                                 next,
+                                 prev_pts_vector,
+                                 next_pts_vector,
+                                 status_vector,
+                                 err_vector,
+                                 cv::Size(win_size.width, win_size.height),
+                                 max_level,
+                                 *criteria);
+    }
+    cv_to_ffi(next_pts_vector, next_pts);
+    cv_to_ffi(status_vector, status);
+    cv_to_ffi(err_vector, err);
+}
+
+}  // namespace
 
 extern "C" {
 
@@ -22,4 +112,278 @@ RotatedRect cv_camshift(cv::Mat* bp_image, Rect crect, cv::TermCriteria* criteri
     c_rr.angle = rr.angle;
     return c_rr;
 }
+
+void cv_mean_shift(cv::Mat* back_project_image, Rect* window, cv::TermCriteria* criteria, Result<int>* result) {
+    *result = Result<int>::FromFunction([=]() {
+        cv::Rect rect(window->x, window->y, window->width, window->height);
+        int iterations = cv::meanShift(*back_project_image, rect, *criteria);
+        cv_to_ffi(rect, window);
+        return iterations;
+    });
+}
+
+void cv_cam_shift(cv::Mat* back_project_image, Rect* window, cv::TermCriteria* criteria, Result<RotatedRect>* result) {
+    *result = Result<RotatedRect>::FromFunction([=]() {
+        cv::Rect rect(window->x, window->y, window->width, window->height);
+        cv::RotatedRect rr = cv::CamShift(*back_project_image, rect, *criteria);
+        cv_to_ffi(rect, window);
+        RotatedRect c_rr;
+        c_rr.center.x = rr.center.x;
+        c_rr.center.y = rr.center.y;
+        c_rr.size.width = rr.size.width;
+        c_rr.size.height = rr.size.height;
+        c_rr.angle = rr.angle;
+        return c_rr;
+    });
+}
+
+void* cv_kalman_filter_new(int dynam_params, int measure_params, int control_params, int type) {
+    return new cv::KalmanFilter(dynam_params, measure_params, control_params, type);
+}
+
+void cv_kalman_filter_drop(cv::KalmanFilter* kf) {
+    delete kf;
+    kf = nullptr;
+}
+
+EmptyResult cv_kalman_filter_predict(cv::KalmanFilter* kf, cv::Mat* control, cv::Mat* state) {
+    return EmptyResult::FromFunction([=]() {
+        *state = kf->predict(or_empty(control)).clone();
+    });
+}
+
+EmptyResult cv_kalman_filter_correct(cv::KalmanFilter* kf, cv::Mat* measurement, cv::Mat* state) {
+    return EmptyResult::FromFunction([=]() {
+        *state = kf->correct(*measurement).clone();
+    });
+}
+
+void cv_kalman_filter_get_matrix(cv::KalmanFilter* kf, int which, cv::Mat* matrix) {
+    *matrix = (kf->*KALMAN_MATRICES[which]).clone();
+}
+
+EmptyResult cv_kalman_filter_set_matrix(cv::KalmanFilter* kf, int which, cv::Mat* matrix) {
+    return EmptyResult::FromFunction([=]() {
+        cv::Mat& target = kf->*KALMAN_MATRICES[which];
+        CV_Assert(matrix->size() == target.size() && matrix->type() == target.type());
+        matrix->copyTo(target);
+    });
+}
+
+EmptyResult cv_calc_optical_flow_farneback(cv::Mat* prev,
+                                           cv::Mat* next,
+                                           cv::Mat* flow,
+                                           cv::Mat* initial_flow,
+                                           double pyr_scale,
+                                           int levels,
+                                           int winsize,
+                                           int iterations,
+                                           int poly_n,
+                                           double poly_sigma,
+                                           bool gaussian) {
+    return EmptyResult::FromFunction([=]() {
+        int flags = gaussian ? cv::OPTFLOW_FARNEBACK_GAUSSIAN : 0;
+        if (initial_flow != nullptr) {
+            *flow = initial_flow->clone();
+            flags |= cv::OPTFLOW_USE_INITIAL_FLOW;
+        }
+        cv::calcOpticalFlowFarneback(
+            *prev, *next, *flow, pyr_scale, levels, winsize, iterations, poly_n, poly_sigma, flags);
+    });
+}
+
+void* cv_dis_optical_flow_new(int preset) {
+#if CV_VERSION_MAJOR >= 4
+    return new cv::Ptr<cv::DenseOpticalFlow>(cv::DISOpticalFlow::create(preset));
+#else
+    (void) preset;
+    return nullptr;
+#endif
+}
+
+void cv_dis_optical_flow_drop(cv::Ptr<cv::DenseOpticalFlow>* dis) {
+    delete dis;
+    dis = nullptr;
+}
+
+EmptyResult cv_dis_optical_flow_calc(
+    cv::Ptr<cv::DenseOpticalFlow>* dis, cv::Mat* i0, cv::Mat* i1, cv::Mat* flow, cv::Mat* initial_flow) {
+    return EmptyResult::FromFunction([=]() {
+        *flow = or_empty(initial_flow).clone();
+        (*dis)->calc(*i0, *i1, *flow);
+    });
+}
+
+int cv_dis_optical_flow_get_finest_scale(cv::Ptr<cv::DenseOpticalFlow>* dis) {
+    return as_dis(dis)->getFinestScale();
+}
+
+void cv_dis_optical_flow_set_finest_scale(cv::Ptr<cv::DenseOpticalFlow>* dis, int value) {
+    as_dis(dis)->setFinestScale(value);
+}
+
+int cv_dis_optical_flow_get_patch_size(cv::Ptr<cv::DenseOpticalFlow>* dis) {
+    return as_dis(dis)->getPatchSize();
+}
+
+void cv_dis_optical_flow_set_patch_size(cv::Ptr<cv::DenseOpticalFlow>* dis, int value) {
+    as_dis(dis)->setPatchSize(value);
+}
+
+int cv_dis_optical_flow_get_patch_stride(cv::Ptr<cv::DenseOpticalFlow>* dis) {
+    return as_dis(dis)->getPatchStride();
+}
+
+void cv_dis_optical_flow_set_patch_stride(cv::Ptr<cv::DenseOpticalFlow>* dis, int value) {
+    as_dis(dis)->setPatchStride(value);
+}
+
+int cv_dis_optical_flow_get_gradient_descent_iterations(cv::Ptr<cv::DenseOpticalFlow>* dis) {
+    return as_dis(dis)->getGradientDescentIterations();
+}
+
+void cv_dis_optical_flow_set_gradient_descent_iterations(cv::Ptr<cv::DenseOpticalFlow>* dis, int value) {
+    as_dis(dis)->setGradientDescentIterations(value);
+}
+
+int cv_dis_optical_flow_get_variational_refinement_iterations(cv::Ptr<cv::DenseOpticalFlow>* dis) {
+    return as_dis(dis)->getVariationalRefinementIterations();
+}
+
+void cv_dis_optical_flow_set_variational_refinement_iterations(cv::Ptr<cv::DenseOpticalFlow>* dis, int value) {
+    as_dis(dis)->setVariationalRefinementIterations(value);
+}
+
+bool cv_dis_optical_flow_get_use_spatial_propagation(cv::Ptr<cv::DenseOpticalFlow>* dis) {
+    return as_dis(dis)->getUseSpatialPropagation();
+}
+
+void cv_dis_optical_flow_set_use_spatial_propagation(cv::Ptr<cv::DenseOpticalFlow>* dis, bool value) {
+    as_dis(dis)->setUseSpatialPropagation(value);
+}
+
+EmptyResult cv_flow_to_color(cv::Mat* flow, float max_magnitude, cv::Mat* color) {
+    return EmptyResult::FromFunction([=]() {
+        CV_Assert(flow->type() == CV_32FC2);
+        cv::Mat components[2];
+        cv::split(*flow, components);
+        cv::Mat magnitude;
+        cv::Mat angle;
+        cv::cartToPolar(components[0], components[1], magnitude, angle, true);
+        if (max_magnitude <= 0) {
+            double max_value;
+            cv::minMaxLoc(magnitude, nullptr, &max_value);
+            max_magnitude = static_cast<float>(max_value);
+        }
+        cv::Mat hsv[3];
+        angle.convertTo(hsv[0], CV_8U, 0.5);
+        hsv[1] = cv::Mat(flow->size(), CV_8U, cv::Scalar(255));
+        magnitude.convertTo(hsv[2], CV_8U, max_magnitude > 0 ? 255.0 / max_magnitude : 0.0);
+        cv::Mat hsv_image;
+        cv::merge(hsv, 3, hsv_image);
+        cv::cvtColor(hsv_image, *color, cv::COLOR_HSV2BGR);
+    });
+}
+
+EmptyResult cv_calc_optical_flow_pyr_lk(cv::Mat* prev,
+                                        cv::Mat* next,
+                                        CVec<Point2f>* prev_pts,
+                                        Size2i win_size,
+                                        int max_level,
+                                        cv::TermCriteria* criteria,
+                                        CVec<Point2f>* next_pts,
+                                        CVec<unsigned char>* status,
+                                        CVec<float>* err) {
+    return EmptyResult::FromFunction([=]() {
+        calc_optical_flow_pyr_lk(*prev, *next, prev_pts, win_size, max_level, criteria, next_pts, status, err);
+    });
+}
+
+EmptyResult cv_calc_optical_flow_pyr_lk_pyramids(CVec<cv::Mat*>* prev,
+                                                 CVec<cv::Mat*>* next,
+                                                 CVec<Point2f>* prev_pts,
+                                                 Size2i win_size,
+                                                 int max_level,
+                                                 cv::TermCriteria* criteria,
+                                                 CVec<Point2f>* next_pts,
+                                                 CVec<unsigned char>* status,
+                                                 CVec<float>* err) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::Mat> prev_pyramid;
+        std::vector<cv::Mat> next_pyramid;
+        ffi_to_cv(*prev, &prev_pyramid);
+        ffi_to_cv(*next, &next_pyramid);
+        calc_optical_flow_pyr_lk(
+            prev_pyramid, next_pyramid, prev_pts, win_size, max_level, criteria, next_pts, status, err);
+    });
+}
+
+EmptyResult cv_build_optical_flow_pyramid(
+    cv::Mat* image, Size2i win_size, int max_level, bool with_derivatives, CVec<cv::Mat*>* pyramid) {
+    return EmptyResult::FromFunction([=]() {
+        std::vector<cv::Mat> pyramid_vector;
+        cv::buildOpticalFlowPyramid(
+            *image, pyramid_vector, cv::Size(win_size.width, win_size.height), max_level, with_derivatives);
+        std::vector<cv::Mat*> result;
+        for (const cv::Mat& level : pyramid_vector) {
+            result.push_back(new cv::Mat(level));
+        }
+        cv_to_ffi(result, pyramid);
+    });
+}
+
+void cv_background_subtractor_drop(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    delete subtractor;
+    subtractor = nullptr;
+}
+
+EmptyResult cv_background_subtractor_apply(cv::Ptr<cv::BackgroundSubtractor>* subtractor,
+                                           cv::Mat* image,
+                                           cv::Mat* fg_mask,
+                                           double learning_rate) {
+    return EmptyResult::FromFunction([=]() {
+        (*subtractor)->apply(*image, *fg_mask, learning_rate);
+    });
+}
+
+EmptyResult cv_background_subtractor_get_background_image(cv::Ptr<cv::BackgroundSubtractor>* subtractor,
+                                                          cv::Mat* background) {
+    return EmptyResult::FromFunction([=]() {
+        (*subtractor)->getBackgroundImage(*background);
+    });
+}
+
+void* cv_background_subtractor_mog2_new(int history, double var_threshold, bool detect_shadows) {
+    return new cv::Ptr<cv::BackgroundSubtractor>(
+        cv::createBackgroundSubtractorMOG2(history, var_threshold, detect_shadows));
+}
+
+int cv_background_subtractor_mog2_get_history(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    return as_mog2(subtractor)->getHistory();
+}
+
+double cv_background_subtractor_mog2_get_var_threshold(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    return as_mog2(subtractor)->getVarThreshold();
+}
+
+bool cv_background_subtractor_mog2_get_detect_shadows(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    return as_mog2(subtractor)->getDetectShadows();
+}
+
+void* cv_background_subtractor_knn_new(int history, double dist2_threshold, bool detect_shadows) {
+    return new cv::Ptr<cv::BackgroundSubtractor>(
+        cv::createBackgroundSubtractorKNN(history, dist2_threshold, detect_shadows));
+}
+
+int cv_background_subtractor_knn_get_history(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    return as_knn(subtractor)->getHistory();
+}
+
+double cv_background_subtractor_knn_get_dist2_threshold(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    return as_knn(subtractor)->getDist2Threshold();
+}
+
+bool cv_background_subtractor_knn_get_detect_shadows(cv::Ptr<cv::BackgroundSubtractor>* subtractor) {
+    return as_knn(subtractor)->getDetectShadows();
+}
 }
@@ -2,6 +2,8 @@
 #define CV_RS_VIDEO_H
 
 #include "common.h"
+#include <opencv2/imgproc.hpp>
+#include <opencv2/video/background_segm.hpp>
 #include <opencv2/video/tracking.hpp>
 
 extern "C" {
@@ -9,5 +11,88 @@ extern "C" {
 void* cv_term_criteria_new(int type, int count, double epsilon);
 void cv_term_criteria_drop(cv::TermCriteria* criteria);
 RotatedRect cv_camshift(cv::Mat* back_project_image, Rect window, cv::TermCriteria* criteria);
+void cv_mean_shift(cv::Mat* back_project_image, Rect* window, cv::TermCriteria* criteria, Result<int>* result);
+void cv_cam_shift(cv::Mat* back_project_image, Rect* window, cv::TermCriteria* criteria, Result<RotatedRect>* result);
+
+// =============================================================================
+//   Kalman Filter
+// =============================================================================
+void* cv_kalman_filter_new(int dynam_params, int measure_params, int control_params, int type);
+void cv_kalman_filter_drop(cv::KalmanFilter* kf);
+EmptyResult cv_kalman_filter_predict(cv::KalmanFilter* kf, cv::Mat* control, cv::Mat* state);
+EmptyResult cv_kalman_filter_correct(cv::KalmanFilter* kf, cv::Mat* measurement, cv::Mat* state);
+void cv_kalman_filter_get_matrix(cv::KalmanFilter* kf, int which, cv::Mat* matrix);
+EmptyResult cv_kalman_filter_set_matrix(cv::KalmanFilter* kf, int which, cv::Mat* matrix);
+
+// =============================================================================
+//   Optical Flow
+// =============================================================================
+EmptyResult cv_calc_optical_flow_farneback(cv::Mat* prev,
+                                           cv::Mat* next,
+                                           cv::Mat* flow,
+                                           cv::Mat* initial_flow,
+                                           double pyr_scale,
+                                           int levels,
+                                           int winsize,
+                                           int iterations,
+                                           int poly_n,
+                                           double poly_sigma,
+                                           bool gaussian);
+void* cv_dis_optical_flow_new(int preset);
+void cv_dis_optical_flow_drop(cv::Ptr<cv::DenseOpticalFlow>* dis);
+EmptyResult cv_dis_optical_flow_calc(
+    cv::Ptr<cv::DenseOpticalFlow>* dis, cv::Mat* i0, cv::Mat* i1, cv::Mat* flow, cv::Mat* initial_flow);
+int cv_dis_optical_flow_get_finest_scale(cv::Ptr<cv::DenseOpticalFlow>* dis);
+void cv_dis_optical_flow_set_finest_scale(cv::Ptr<cv::DenseOpticalFlow>* dis, int value);
+int cv_dis_optical_flow_get_patch_size(cv::Ptr<cv::DenseOpticalFlow>* dis);
+void cv_dis_optical_flow_set_patch_size(cv::Ptr<cv::DenseOpticalFlow>* dis, int value);
+int cv_dis_optical_flow_get_patch_stride(cv::Ptr<cv::DenseOpticalFlow>* dis);
+void cv_dis_optical_flow_set_patch_stride(cv::Ptr<cv::DenseOpticalFlow>* dis, int value);
+int cv_dis_optical_flow_get_gradient_descent_iterations(cv::Ptr<cv::DenseOpticalFlow>* dis);
+void cv_dis_optical_flow_set_gradient_descent_iterations(cv::Ptr<cv::DenseOpticalFlow>* dis, int value);
+int cv_dis_optical_flow_get_variational_refinement_iterations(cv::Ptr<cv::DenseOpticalFlow>* dis);
+void cv_dis_optical_flow_set_variational_refinement_iterations(cv::Ptr<cv::DenseOpticalFlow>* dis, int value);
+bool cv_dis_optical_flow_get_use_spatial_propagation(cv::Ptr<cv::DenseOpticalFlow>* dis);
+void cv_dis_optical_flow_set_use_spatial_propagation(cv::Ptr<cv::DenseOpticalFlow>* dis, bool value);
+EmptyResult cv_flow_to_color(cv::Mat* flow, float max_magnitude, cv::Mat* color);
+EmptyResult cv_calc_optical_flow_pyr_lk(cv::Mat* prev,
+                                        cv::Mat* next,
+                                        CVec<Point2f>* prev_pts,
+                                        Size2i win_size,
+                                        int max_level,
+                                        cv::TermCriteria* criteria,
+                                        CVec<Point2f>* next_pts,
+                                        CVec<unsigned char>* status,
+                                        CVec<float>* err);
+EmptyResult cv_calc_optical_flow_pyr_lk_pyramids(CVec<cv::Mat*>* prev,
+                                                 CVec<cv::Mat*>* next,
+                                                 CVec<Point2f>* prev_pts,
+                                                 Size2i win_size,
+                                                 int max_level,
+                                                 cv::TermCriteria* criteria,
+                                                 CVec<Point2f>* next_pts,
+                                                 CVec<unsigned char>* status,
+                                                 CVec<float>* err);
+EmptyResult cv_build_optical_flow_pyramid(
+    cv::Mat* image, Size2i win_size, int max_level, bool with_derivatives, CVec<cv::Mat*>* pyramid);
+
+// =============================================================================
+//   Background Subtraction
+// =============================================================================
+void cv_background_subtractor_drop(cv::Ptr<cv::BackgroundSubtractor>* subtractor);
+EmptyResult cv_background_subtractor_apply(cv::Ptr<cv::BackgroundSubtractor>* subtractor,
+                                           cv::Mat* image,
+                                           cv::Mat* fg_mask,
+                                           double learning_rate);
+EmptyResult cv_background_subtractor_get_background_image(cv::Ptr<cv::BackgroundSubtractor>* subtractor,
+                                                          cv::Mat* background);
+void* cv_background_subtractor_mog2_new(int history, double var_threshold, bool detect_shadows);
+int cv_background_subtractor_mog2_get_history(cv::Ptr<cv::BackgroundSubtractor>* subtractor);
+double cv_background_subtractor_mog2_get_var_threshold(cv::Ptr<cv::BackgroundSubtractor>* subtractor);
+bool cv_background_subtractor_mog2_get_detect_shadows(cv::Ptr<cv::BackgroundSubtractor>* subtractor);
+void* cv_background_subtractor_knn_new(int history, double dist2_threshold, bool detect_shadows);
+int cv_background_subtractor_knn_get_history(cv::Ptr<cv::BackgroundSubtractor>* subtractor);
+double cv_background_subtractor_knn_get_dist2_threshold(cv::Ptr<cv::BackgroundSubtractor>* subtractor);
+bool cv_background_subtractor_knn_get_detect_shadows(cv::Ptr<cv::BackgroundSubtractor>* subtractor);
 }
 #endif  // CV_RS_VIDEO_H
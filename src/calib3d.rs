@@ -0,0 +1,2178 @@
+//! Camera calibration and 3D reconstruction, see [OpenCV
+//! calib3d](https://docs.opencv.org/3.4.1/d9/d0c/group__calib3d.html).
+
+use super::core::*;
+use super::errors::*;
+use super::*;
+use failure::Error;
+use std::os::raw::{c_char, c_double, c_float, c_int};
+use std::path::Path;
+use std::ptr;
+
+extern "C" {
+    fn cv_find_homography(
+        src_points: *const CVecView<Point2f>,
+        dst_points: *const CVecView<Point2f>,
+        method: Method,
+        ransac_reproj_threshold: c_double,
+        homography: *mut CMat,
+        mask: *mut CVec<u8>,
+    ) -> CEmptyResult;
+    fn cv_find_fundamental_mat(
+        points1: *const CVecView<Point2f>,
+        points2: *const CVecView<Point2f>,
+        method: FundamentalMethod,
+        ransac_reproj_threshold: c_double,
+        confidence: c_double,
+        fundamental: *mut CMat,
+        mask: *mut CVec<u8>,
+    ) -> CEmptyResult;
+    fn cv_find_essential_mat(
+        points1: *const CVecView<Point2f>,
+        points2: *const CVecView<Point2f>,
+        camera_matrix: *const CMat,
+        method: Method,
+        prob: c_double,
+        threshold: c_double,
+        essential: *mut CMat,
+        mask: *mut CVec<u8>,
+    ) -> CEmptyResult;
+    fn cv_recover_pose(
+        essential: *const CMat,
+        points1: *const CVecView<Point2f>,
+        points2: *const CVecView<Point2f>,
+        camera_matrix: *const CMat,
+        rotation: *mut CMat,
+        translation: *mut CMat,
+        provided_mask: *const CVecView<u8>,
+        mask: *mut CVec<u8>,
+        result: *mut CResult<c_int>,
+    );
+    fn cv_estimate_affine_2d(
+        from: *const CVecView<Point2f>,
+        to: *const CVecView<Point2f>,
+        method: Method,
+        ransac_reproj_threshold: c_double,
+        partial: bool,
+        affine: *mut CMat,
+        mask: *mut CVec<u8>,
+    ) -> CEmptyResult;
+
+    fn cv_find_chessboard_corners(
+        image: *const CMat,
+        pattern_size: Size2i,
+        flags: c_int,
+        corners: *mut CVec<Point2f>,
+        result: *mut CResult<bool>,
+    );
+    fn cv_find_chessboard_corners_sb(
+        image: *const CMat,
+        pattern_size: Size2i,
+        flags: c_int,
+        corners: *mut CVec<Point2f>,
+        result: *mut CResult<bool>,
+    );
+    fn cv_find_circles_grid(
+        image: *const CMat,
+        pattern_size: Size2i,
+        flags: c_int,
+        centers: *mut CVec<Point2f>,
+        result: *mut CResult<bool>,
+    );
+    fn cv_draw_chessboard_corners(
+        image: *mut CMat,
+        pattern_size: Size2i,
+        corners: *const CVecView<Point2f>,
+        pattern_was_found: bool,
+    ) -> CEmptyResult;
+    fn cv_calibrate_camera(
+        object_points: *const CVecView<CVecView<Point3f>>,
+        image_points: *const CVecView<CVecView<Point2f>>,
+        image_size: Size2i,
+        flags: c_int,
+        criteria: *const CTermCriteria,
+        camera_matrix: *mut CMat,
+        dist_coeffs: *mut CMat,
+        rvecs: *mut CVec<*mut CMat>,
+        tvecs: *mut CVec<*mut CMat>,
+        result: *mut CResult<c_double>,
+    );
+    fn cv_calibration_write(
+        path: *const c_char,
+        camera_matrix: *const CMat,
+        dist_coeffs: *const CMat,
+        rvecs: *const CVecView<*mut CMat>,
+        tvecs: *const CVecView<*mut CMat>,
+        rms: c_double,
+    ) -> CEmptyResult;
+    fn cv_calibration_read(
+        path: *const c_char,
+        camera_matrix: *mut CMat,
+        dist_coeffs: *mut CMat,
+        rvecs: *mut CVec<*mut CMat>,
+        tvecs: *mut CVec<*mut CMat>,
+        rms: *mut CResult<c_double>,
+    );
+
+    fn cv_undistort(
+        src: *const CMat,
+        camera_matrix: *const CMat,
+        dist_coeffs: *const CMat,
+        new_camera_matrix: *const CMat,
+        dst: *mut CMat,
+    ) -> CEmptyResult;
+    fn cv_undistort_points(
+        points: *const CVecView<Point2f>,
+        camera_matrix: *const CMat,
+        dist_coeffs: *const CMat,
+        r: *const CMat,
+        p: *const CMat,
+        undistorted: *mut CVec<Point2f>,
+    ) -> CEmptyResult;
+    fn cv_get_optimal_new_camera_matrix(
+        camera_matrix: *const CMat,
+        dist_coeffs: *const CMat,
+        image_size: Size2i,
+        alpha: c_double,
+        new_image_size: Size2i,
+        center_principal_point: bool,
+        new_camera_matrix: *mut CMat,
+        valid_roi: *mut Rect,
+    ) -> CEmptyResult;
+    fn cv_init_undistort_rectify_map(
+        camera_matrix: *const CMat,
+        dist_coeffs: *const CMat,
+        r: *const CMat,
+        new_camera_matrix: *const CMat,
+        size: Size2i,
+        map_type: MapType,
+        map1: *mut CMat,
+        map2: *mut CMat,
+    ) -> CEmptyResult;
+
+    fn cv_solve_pnp(
+        object_points: *const CVecView<Point3f>,
+        image_points: *const CVecView<Point2f>,
+        camera_matrix: *const CMat,
+        dist_coeffs: *const CMat,
+        method: PnPMethod,
+        rvec: *mut c_double,
+        tvec: *mut c_double,
+        result: *mut CResult<bool>,
+    );
+    fn cv_solve_pnp_ransac(
+        object_points: *const CVecView<Point3f>,
+        image_points: *const CVecView<Point2f>,
+        camera_matrix: *const CMat,
+        dist_coeffs: *const CMat,
+        method: PnPMethod,
+        iterations_count: c_int,
+        reprojection_error: c_float,
+        confidence: c_double,
+        rvec: *mut c_double,
+        tvec: *mut c_double,
+        inliers: *mut CVec<c_int>,
+        result: *mut CResult<bool>,
+    );
+    fn cv_rodrigues_to_matrix(rvec: *const c_double, matrix: *mut c_double) -> CEmptyResult;
+    fn cv_rodrigues_to_vector(matrix: *const c_double, rvec: *mut c_double) -> CEmptyResult;
+    fn cv_project_points(
+        object_points: *const CVecView<Point3f>,
+        rvec: *const c_double,
+        tvec: *const c_double,
+        camera_matrix: *const CMat,
+        dist_coeffs: *const CMat,
+        image_points: *mut CVec<Point2f>,
+    ) -> CEmptyResult;
+    fn cv_triangulate_points(
+        projection1: *const CMat,
+        projection2: *const CMat,
+        points1: *const CVecView<Point2f>,
+        points2: *const CVecView<Point2f>,
+        points: *mut CVec<Point3d>,
+    ) -> CEmptyResult;
+    fn cv_convert_points_from_homogeneous(
+        points: *const CVecView<Point3f>,
+        converted: *mut CVec<Point2f>,
+    ) -> CEmptyResult;
+    fn cv_convert_points_to_homogeneous(
+        points: *const CVecView<Point2f>,
+        converted: *mut CVec<Point3f>,
+    ) -> CEmptyResult;
+    fn cv_decompose_projection_matrix(
+        projection: *const CMat,
+        camera_matrix: *mut c_double,
+        rotation: *mut c_double,
+        camera_position: *mut c_double,
+        euler_angles: *mut c_double,
+    ) -> CEmptyResult;
+    fn cv_decompose_homography_mat(
+        homography: *const CMat,
+        camera_matrix: *const CMat,
+        rotations: *mut CVec<*mut CMat>,
+        translations: *mut CVec<*mut CMat>,
+        normals: *mut CVec<*mut CMat>,
+        result: *mut CResult<c_int>,
+    );
+
+    fn cv_stereo_bm_new(
+        min_disparity: c_int,
+        num_disparities: c_int,
+        block_size: c_int,
+        pre_filter_size: c_int,
+        pre_filter_cap: c_int,
+        texture_threshold: c_int,
+        uniqueness_ratio: c_int,
+        speckle_window_size: c_int,
+        speckle_range: c_int,
+        disp12_max_diff: c_int,
+    ) -> *mut CStereoMatcher;
+    fn cv_stereo_sgbm_new(
+        min_disparity: c_int,
+        num_disparities: c_int,
+        block_size: c_int,
+        p1: c_int,
+        p2: c_int,
+        disp12_max_diff: c_int,
+        pre_filter_cap: c_int,
+        uniqueness_ratio: c_int,
+        speckle_window_size: c_int,
+        speckle_range: c_int,
+        mode: SGBMMode,
+    ) -> *mut CStereoMatcher;
+    fn cv_stereo_matcher_drop(matcher: *mut CStereoMatcher);
+    fn cv_stereo_matcher_compute(
+        matcher: *mut CStereoMatcher,
+        left: *const CMat,
+        right: *const CMat,
+        disparity: *mut CMat,
+    ) -> CEmptyResult;
+    fn cv_stereo_matcher_get_min_disparity(matcher: *const CStereoMatcher) -> c_int;
+    fn cv_stereo_matcher_get_num_disparities(matcher: *const CStereoMatcher) -> c_int;
+    fn cv_stereo_matcher_get_block_size(matcher: *const CStereoMatcher) -> c_int;
+    fn cv_stereo_calibrate(
+        object_points: *const CVecView<CVecView<Point3f>>,
+        image_points1: *const CVecView<CVecView<Point2f>>,
+        image_points2: *const CVecView<CVecView<Point2f>>,
+        camera_matrix1: *const CMat,
+        dist_coeffs1: *const CMat,
+        camera_matrix2: *const CMat,
+        dist_coeffs2: *const CMat,
+        image_size: Size2i,
+        flags: c_int,
+        criteria: *const CTermCriteria,
+        out_camera_matrix1: *mut CMat,
+        out_dist_coeffs1: *mut CMat,
+        out_camera_matrix2: *mut CMat,
+        out_dist_coeffs2: *mut CMat,
+        rotation: *mut CMat,
+        translation: *mut CMat,
+        essential: *mut CMat,
+        fundamental: *mut CMat,
+        result: *mut CResult<c_double>,
+    );
+    fn cv_stereo_rectify(
+        camera_matrix1: *const CMat,
+        dist_coeffs1: *const CMat,
+        camera_matrix2: *const CMat,
+        dist_coeffs2: *const CMat,
+        image_size: Size2i,
+        rotation: *const CMat,
+        translation: *const CMat,
+        zero_disparity: bool,
+        alpha: c_double,
+        r1: *mut CMat,
+        r2: *mut CMat,
+        p1: *mut CMat,
+        p2: *mut CMat,
+        q: *mut CMat,
+        valid_roi1: *mut Rect,
+        valid_roi2: *mut Rect,
+    ) -> CEmptyResult;
+    fn cv_reproject_image_to_3d(
+        disparity: *const CMat,
+        q: *const CMat,
+        handle_missing_values: bool,
+        points: *mut CMat,
+    ) -> CEmptyResult;
+    fn cv_filter_speckles(
+        image: *mut CMat,
+        new_value: c_double,
+        max_speckle_size: c_int,
+        max_diff: c_double,
+    ) -> CEmptyResult;
+}
+
+/// Method used to robustly estimate a transformation from point pairs that
+/// may contain outliers.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Method {
+    /// Least-squares fit on all the points. Only valid for
+    /// [find_homography](fn.find_homography.html).
+    LeastSquares = 0,
+    /// Least-median robust method
+    Lmeds = 4,
+    /// RANSAC-based robust method
+    Ransac = 8,
+    /// PROSAC-based robust method. Only valid for
+    /// [find_homography](fn.find_homography.html).
+    Rho = 16,
+}
+
+/// Method used to compute the fundamental matrix
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FundamentalMethod {
+    /// 7-point algorithm, requires exactly 7 point pairs
+    SevenPoint = 1,
+    /// 8-point algorithm, requires at least 8 point pairs
+    EightPoint = 2,
+    /// Least-median algorithm, requires at least 8 point pairs
+    Lmeds = 4,
+    /// RANSAC algorithm, requires at least 8 point pairs
+    Ransac = 8,
+}
+
+fn check_point_pairs(points1: &[Point2f], points2: &[Point2f]) -> Result<(), Error> {
+    if points1.len() != points2.len() {
+        return Err(CvError::InvalidArgument(format!(
+            "point sets have different sizes: {} and {}",
+            points1.len(),
+            points2.len()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+fn unpack_mask(mask: CVec<u8>) -> Vec<bool> {
+    mask.unpack().into_iter().map(|m| m != 0).collect()
+}
+
+/// Finds the perspective transformation mapping `src_points` onto
+/// `dst_points`, e.g. the matched keypoints of two views of a planar object.
+///
+/// `ransac_reproj_threshold` is the maximum reprojection error, in pixels, for
+/// a point pair to be counted as an inlier by `Ransac` and `Rho`. Returns the
+/// 3x3 homography (empty if it cannot be estimated) and the inlier mask, or
+/// an error if there are less than 4 point pairs.
+pub fn find_homography(
+    src_points: &[Point2f],
+    dst_points: &[Point2f],
+    method: Method,
+    ransac_reproj_threshold: f64,
+) -> Result<(Mat, Vec<bool>), Error> {
+    check_point_pairs(src_points, dst_points)?;
+    if src_points.len() < 4 {
+        return Err(CvError::InvalidArgument(format!(
+            "a homography requires at least 4 point pairs, got {}",
+            src_points.len()
+        ))
+        .into());
+    }
+    let src_points = CVecView::pack(&src_points.to_vec());
+    let dst_points = CVecView::pack(&dst_points.to_vec());
+    let homography = CMat::new();
+    let mut mask = CVec::<u8>::default();
+    let result = unsafe {
+        cv_find_homography(
+            &src_points,
+            &dst_points,
+            method,
+            ransac_reproj_threshold,
+            homography,
+            &mut mask,
+        )
+    };
+    let homography = Mat::from_raw(homography);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok((homography, unpack_mask(mask)))
+}
+
+/// Computes the fundamental matrix from corresponding points of two images.
+///
+/// `ransac_reproj_threshold` is the maximum distance, in pixels, from a point
+/// to its epipolar line for it to be counted as an inlier, and `confidence`
+/// the desired probability that the estimated matrix is correct; both are
+/// only used by `Ransac` and `Lmeds`. Returns the 3x3 fundamental matrix
+/// (empty if it cannot be estimated) and the inlier mask.
+pub fn find_fundamental_mat(
+    points1: &[Point2f],
+    points2: &[Point2f],
+    method: FundamentalMethod,
+    ransac_reproj_threshold: f64,
+    confidence: f64,
+) -> Result<(Mat, Vec<bool>), Error> {
+    check_point_pairs(points1, points2)?;
+    let points1 = CVecView::pack(&points1.to_vec());
+    let points2 = CVecView::pack(&points2.to_vec());
+    let fundamental = CMat::new();
+    let mut mask = CVec::<u8>::default();
+    let result = unsafe {
+        cv_find_fundamental_mat(
+            &points1,
+            &points2,
+            method,
+            ransac_reproj_threshold,
+            confidence,
+            fundamental,
+            &mut mask,
+        )
+    };
+    let fundamental = Mat::from_raw(fundamental);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok((fundamental, unpack_mask(mask)))
+}
+
+/// Computes the essential matrix from corresponding points of two images
+/// taken by the same camera, with the 3x3 `camera_matrix` intrinsics.
+///
+/// `method` must be `Ransac` or `Lmeds`. `prob` is the desired probability
+/// that the estimated matrix is correct and `threshold` the maximum distance,
+/// in pixels, from a point to its epipolar line for it to be counted as an
+/// inlier. Returns the essential matrix and the inlier mask.
+pub fn find_essential_mat(
+    points1: &[Point2f],
+    points2: &[Point2f],
+    camera_matrix: &Mat,
+    method: Method,
+    prob: f64,
+    threshold: f64,
+) -> Result<(Mat, Vec<bool>), Error> {
+    check_point_pairs(points1, points2)?;
+    let points1 = CVecView::pack(&points1.to_vec());
+    let points2 = CVecView::pack(&points2.to_vec());
+    let essential = CMat::new();
+    let mut mask = CVec::<u8>::default();
+    let result = unsafe {
+        cv_find_essential_mat(
+            &points1,
+            &points2,
+            camera_matrix.inner,
+            method,
+            prob,
+            threshold,
+            essential,
+            &mut mask,
+        )
+    };
+    let essential = Mat::from_raw(essential);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok((essential, unpack_mask(mask)))
+}
+
+/// Recovers the relative rotation and translation of the second camera from
+/// the essential matrix, keeping the solution that puts the most points in
+/// front of both cameras. The translation is only known up to scale.
+///
+/// `mask`, if given, selects the point pairs to use (usually the inlier mask
+/// of [find_essential_mat](fn.find_essential_mat.html)). Returns the number
+/// of inliers passing the check, the 3x3 rotation, the 3x1 unit translation
+/// and the updated inlier mask.
+pub fn recover_pose(
+    essential: &Mat,
+    points1: &[Point2f],
+    points2: &[Point2f],
+    camera_matrix: &Mat,
+    mask: Option<&[bool]>,
+) -> Result<(c_int, Mat, Mat, Vec<bool>), Error> {
+    check_point_pairs(points1, points2)?;
+    let provided_mask = match mask {
+        Some(mask) if mask.len() != points1.len() => {
+            return Err(CvError::InvalidArgument(format!(
+                "mask has {} elements, expected {}",
+                mask.len(),
+                points1.len()
+            ))
+            .into())
+        }
+        Some(mask) => mask.iter().map(|&m| m as u8).collect(),
+        None => Vec::new(),
+    };
+    let provided_mask = CVecView::pack(&provided_mask);
+    let points1 = CVecView::pack(&points1.to_vec());
+    let points2 = CVecView::pack(&points2.to_vec());
+    let rotation = CMat::new();
+    let translation = CMat::new();
+    let mut mask = CVec::<u8>::default();
+    let result = CResult::<c_int>::from_callback(|r| unsafe {
+        cv_recover_pose(
+            essential.inner,
+            &points1,
+            &points2,
+            camera_matrix.inner,
+            rotation,
+            translation,
+            &provided_mask,
+            &mut mask,
+            r,
+        )
+    });
+    let rotation = Mat::from_raw(rotation);
+    let translation = Mat::from_raw(translation);
+    let result: Result<c_int, String> = result.into();
+    let inliers = result.map_err(CvError::UnknownError)?;
+    Ok((inliers, rotation, translation, unpack_mask(mask)))
+}
+
+/// Computes the optimal affine transformation (6 degrees of freedom) mapping
+/// `from` onto `to`.
+///
+/// `method` must be `Ransac` or `Lmeds`. `ransac_reproj_threshold` is the
+/// maximum reprojection error, in pixels, for a point pair to be counted as an
+/// inlier. Returns the 2x3 transformation (empty if it cannot be estimated)
+/// and the inlier mask.
+pub fn estimate_affine_2d(
+    from: &[Point2f],
+    to: &[Point2f],
+    method: Method,
+    ransac_reproj_threshold: f64,
+) -> Result<(Mat, Vec<bool>), Error> {
+    estimate_affine(from, to, method, ransac_reproj_threshold, false)
+}
+
+/// Same as [estimate_affine_2d](fn.estimate_affine_2d.html), but limited to
+/// rotation, uniform scaling and translation (4 degrees of freedom).
+pub fn estimate_affine_partial_2d(
+    from: &[Point2f],
+    to: &[Point2f],
+    method: Method,
+    ransac_reproj_threshold: f64,
+) -> Result<(Mat, Vec<bool>), Error> {
+    estimate_affine(from, to, method, ransac_reproj_threshold, true)
+}
+
+fn estimate_affine(
+    from: &[Point2f],
+    to: &[Point2f],
+    method: Method,
+    ransac_reproj_threshold: f64,
+    partial: bool,
+) -> Result<(Mat, Vec<bool>), Error> {
+    check_point_pairs(from, to)?;
+    let from = CVecView::pack(&from.to_vec());
+    let to = CVecView::pack(&to.to_vec());
+    let affine = CMat::new();
+    let mut mask = CVec::<u8>::default();
+    let result =
+        unsafe { cv_estimate_affine_2d(&from, &to, method, ransac_reproj_threshold, partial, affine, &mut mask) };
+    let affine = Mat::from_raw(affine);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok((affine, unpack_mask(mask)))
+}
+
+/// Operation flags for
+/// [find_chessboard_corners](fn.find_chessboard_corners.html). The default
+/// uses adaptive thresholding and normalizes the image.
+#[derive(Debug, Clone, Copy)]
+pub struct ChessboardFlags {
+    /// Uses adaptive thresholding to convert the image to black and white,
+    /// rather than a fixed threshold level
+    pub adaptive_thresh: bool,
+    /// Normalizes the image gamma with `equalize_hist` before thresholding
+    pub normalize_image: bool,
+    /// Uses additional criteria to filter out false quads extracted at the
+    /// contour retrieval stage
+    pub filter_quads: bool,
+    /// Runs a fast check looking for chessboard corners first, and shortcuts
+    /// the call if none is found. Speeds up the call a lot on images without
+    /// chessboard.
+    pub fast_check: bool,
+}
+
+impl Default for ChessboardFlags {
+    fn default() -> Self {
+        ChessboardFlags {
+            adaptive_thresh: true,
+            normalize_image: true,
+            filter_quads: false,
+            fast_check: false,
+        }
+    }
+}
+
+impl ChessboardFlags {
+    fn bits(&self) -> c_int {
+        let mut flags = 0;
+        if self.adaptive_thresh {
+            flags |= 1;
+        }
+        if self.normalize_image {
+            flags |= 2;
+        }
+        if self.filter_quads {
+            flags |= 4;
+        }
+        if self.fast_check {
+            flags |= 8;
+        }
+        flags
+    }
+}
+
+/// Operation flags for
+/// [find_chessboard_corners_sb](fn.find_chessboard_corners_sb.html)
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ChessboardSbFlags {
+    /// Normalizes the image gamma with `equalize_hist` before detection
+    pub normalize_image: bool,
+    /// Runs an exhaustive search to improve the detection rate
+    pub exhaustive: bool,
+    /// Upsamples the image to improve the sub-pixel accuracy
+    pub accuracy: bool,
+}
+
+impl ChessboardSbFlags {
+    fn bits(&self) -> c_int {
+        let mut flags = 0;
+        if self.normalize_image {
+            flags |= 2;
+        }
+        if self.exhaustive {
+            flags |= 16;
+        }
+        if self.accuracy {
+            flags |= 32;
+        }
+        flags
+    }
+}
+
+/// Layout of the circles of a grid pattern
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CirclesGridType {
+    /// Circles in rows and columns
+    Symmetric = 1,
+    /// Every other row is shifted by half the distance between the circles
+    Asymmetric = 2,
+}
+
+const CALIB_CB_CLUSTERING: c_int = 4;
+
+fn found_pattern(result: CResult<bool>, points: CVec<Point2f>) -> Result<Option<Vec<Point2f>>, Error> {
+    let result: Result<bool, String> = result.into();
+    let found = result.map_err(CvError::UnknownError)?;
+    Ok(if found { Some(points.unpack()) } else { None })
+}
+
+/// Finds the inner corners of a chessboard with `pattern_size` inner corners
+/// per row and per column. Returns the corners row by row, left to right, or
+/// `None` if the whole pattern was not found. The corners are only
+/// approximate; refine them with [corner_sub_pix](../mat/struct.Mat.html#method.corner_sub_pix)
+/// before calibrating.
+pub fn find_chessboard_corners(
+    image: &Mat,
+    pattern_size: Size2i,
+    flags: ChessboardFlags,
+) -> Result<Option<Vec<Point2f>>, Error> {
+    let mut corners = CVec::<Point2f>::default();
+    let result = CResult::<bool>::from_callback(|r| unsafe {
+        cv_find_chessboard_corners(image.inner, pattern_size, flags.bits(), &mut corners, r)
+    });
+    found_pattern(result, corners)
+}
+
+/// Same as [find_chessboard_corners](fn.find_chessboard_corners.html), but
+/// uses the sector-based approach, which is more robust to noise and gives
+/// accurate corners without refinement. Requires OpenCV 4; returns an error
+/// with older versions.
+pub fn find_chessboard_corners_sb(
+    image: &Mat,
+    pattern_size: Size2i,
+    flags: ChessboardSbFlags,
+) -> Result<Option<Vec<Point2f>>, Error> {
+    let mut corners = CVec::<Point2f>::default();
+    let result = CResult::<bool>::from_callback(|r| unsafe {
+        cv_find_chessboard_corners_sb(image.inner, pattern_size, flags.bits(), &mut corners, r)
+    });
+    found_pattern(result, corners)
+}
+
+/// Finds the centers of a grid of circles with `pattern_size` circles per row
+/// and per column, or `None` if the whole pattern was not found. `clustering`
+/// uses a clustering algorithm to find the grid, which is more robust to
+/// perspective distortions but more sensitive to background clutter.
+pub fn find_circles_grid(
+    image: &Mat,
+    pattern_size: Size2i,
+    grid_type: CirclesGridType,
+    clustering: bool,
+) -> Result<Option<Vec<Point2f>>, Error> {
+    let mut flags = grid_type as c_int;
+    if clustering {
+        flags |= CALIB_CB_CLUSTERING;
+    }
+    let mut centers = CVec::<Point2f>::default();
+    let result = CResult::<bool>::from_callback(|r| unsafe {
+        cv_find_circles_grid(image.inner, pattern_size, flags, &mut centers, r)
+    });
+    found_pattern(result, centers)
+}
+
+/// Draws the detected chessboard corners on `image`, connected if the whole
+/// pattern was found or as individual red circles otherwise.
+pub fn draw_chessboard_corners(
+    image: &mut Mat,
+    pattern_size: Size2i,
+    corners: &[Point2f],
+    pattern_was_found: bool,
+) -> Result<(), Error> {
+    let corners = CVecView::pack(&corners.to_vec());
+    let result = unsafe { cv_draw_chessboard_corners(image.inner, pattern_size, &corners, pattern_was_found) };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(())
+}
+
+/// Operation flags for [calibrate_camera](fn.calibrate_camera.html)
+#[derive(Default, Debug, Clone, Copy)]
+pub struct CalibrationFlags {
+    /// Keeps the ratio `fx / fy` to 1
+    pub fix_aspect_ratio: bool,
+    /// Keeps the principal point at the center of the image
+    pub fix_principal_point: bool,
+    /// Sets the tangential distortion coefficients to zero
+    pub zero_tangent_dist: bool,
+    /// Keeps the radial distortion coefficient `k1` to zero
+    pub fix_k1: bool,
+    /// Keeps the radial distortion coefficient `k2` to zero
+    pub fix_k2: bool,
+    /// Keeps the radial distortion coefficient `k3` to zero
+    pub fix_k3: bool,
+    /// Keeps the radial distortion coefficient `k4` to zero
+    pub fix_k4: bool,
+    /// Keeps the radial distortion coefficient `k5` to zero
+    pub fix_k5: bool,
+    /// Keeps the radial distortion coefficient `k6` to zero
+    pub fix_k6: bool,
+    /// Enables the coefficients `k4`, `k5` and `k6`, returning 8 or more
+    /// distortion coefficients
+    pub rational_model: bool,
+    /// Enables the thin prism coefficients `s1` to `s4`, returning 12 or
+    /// more distortion coefficients
+    pub thin_prism_model: bool,
+    /// Keeps the thin prism coefficients to zero
+    pub fix_s1_s2_s3_s4: bool,
+    /// Enables the tilted sensor coefficients `taux` and `tauy`, returning
+    /// 14 distortion coefficients
+    pub tilted_model: bool,
+    /// Keeps the tilted sensor coefficients to zero
+    pub fix_taux_tauy: bool,
+}
+
+impl CalibrationFlags {
+    fn bits(&self) -> c_int {
+        let flags = [
+            (self.fix_aspect_ratio, 0x00002),
+            (self.fix_principal_point, 0x00004),
+            (self.zero_tangent_dist, 0x00008),
+            (self.fix_k1, 0x00020),
+            (self.fix_k2, 0x00040),
+            (self.fix_k3, 0x00080),
+            (self.fix_k4, 0x00800),
+            (self.fix_k5, 0x01000),
+            (self.fix_k6, 0x02000),
+            (self.rational_model, 0x04000),
+            (self.thin_prism_model, 0x08000),
+            (self.fix_s1_s2_s3_s4, 0x10000),
+            (self.tilted_model, 0x40000),
+            (self.fix_taux_tauy, 0x80000),
+        ];
+        flags.iter().filter(|f| f.0).fold(0, |bits, f| bits | f.1)
+    }
+}
+
+/// Result of [calibrate_camera](fn.calibrate_camera.html)
+#[derive(Debug)]
+pub struct Calibration {
+    /// 3x3 intrinsic matrix `[fx 0 cx; 0 fy cy; 0 0 1]`
+    pub camera_matrix: Mat,
+    /// Distortion coefficients `(k1, k2, p1, p2[, k3[, k4, k5, k6[, s1, s2,
+    /// s3, s4[, taux, tauy]]]])`, as a single row
+    pub dist_coeffs: Mat,
+    /// Rotation vector of each view (see `rodrigues`), bringing the object
+    /// points to the camera coordinate system
+    pub rvecs: Vec<Mat>,
+    /// Translation vector of each view
+    pub tvecs: Vec<Mat>,
+    /// Overall RMS reprojection error, in pixels
+    pub rms: f64,
+}
+
+impl Calibration {
+    /// Saves the calibration to file storage (XML, YAML or JSON, depending on
+    /// the file extension), replacing the file if it exists.
+    pub fn to_file_storage<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        let path = path_to_cstring(path)?;
+        let rvecs = CVecView::pack(&self.rvecs.iter().map(|m| m.inner).collect());
+        let tvecs = CVecView::pack(&self.tvecs.iter().map(|m| m.inner).collect());
+        let result = unsafe {
+            cv_calibration_write(
+                path.as_ptr(),
+                self.camera_matrix.inner,
+                self.dist_coeffs.inner,
+                &rvecs,
+                &tvecs,
+                self.rms,
+            )
+        };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(())
+    }
+
+    /// Loads a calibration saved by [to_file_storage](#method.to_file_storage)
+    pub fn from_file_storage<P: AsRef<Path>>(path: P) -> Result<Calibration, Error> {
+        let path = path_to_cstring(path)?;
+        let camera_matrix = CMat::new();
+        let dist_coeffs = CMat::new();
+        let mut rvecs = CVec::<*mut CMat>::default();
+        let mut tvecs = CVec::<*mut CMat>::default();
+        let result = CResult::<c_double>::from_callback(|r| unsafe {
+            cv_calibration_read(path.as_ptr(), camera_matrix, dist_coeffs, &mut rvecs, &mut tvecs, r)
+        });
+        let camera_matrix = Mat::from_raw(camera_matrix);
+        let dist_coeffs = Mat::from_raw(dist_coeffs);
+        let rvecs = rvecs.unpack().into_iter().map(Mat::from_raw).collect();
+        let tvecs = tvecs.unpack().into_iter().map(Mat::from_raw).collect();
+        let result: Result<c_double, String> = result.into();
+        let rms = result.map_err(CvError::UnknownError)?;
+        Ok(Calibration {
+            camera_matrix,
+            dist_coeffs,
+            rvecs,
+            tvecs,
+            rms,
+        })
+    }
+}
+
+/// Finds the camera intrinsic and extrinsic parameters from several views of
+/// a calibration pattern.
+///
+/// `object_points` contains, for each view, the coordinates of the pattern
+/// points in the pattern coordinate system (usually with `z = 0`), and
+/// `image_points` their projections, e.g. found with
+/// [find_chessboard_corners](fn.find_chessboard_corners.html).
+pub fn calibrate_camera(
+    object_points: &[Vec<Point3f>],
+    image_points: &[Vec<Point2f>],
+    image_size: Size2i,
+    flags: CalibrationFlags,
+    criteria: &TermCriteria,
+) -> Result<Calibration, Error> {
+    check_views(object_points, image_points)?;
+    let object_points = CVecView::pack(&object_points.to_vec());
+    let image_points = CVecView::pack(&image_points.to_vec());
+    let camera_matrix = CMat::new();
+    let dist_coeffs = CMat::new();
+    let mut rvecs = CVec::<*mut CMat>::default();
+    let mut tvecs = CVec::<*mut CMat>::default();
+    let result = CResult::<c_double>::from_callback(|r| unsafe {
+        cv_calibrate_camera(
+            &object_points,
+            &image_points,
+            image_size,
+            flags.bits(),
+            criteria.c_criteria,
+            camera_matrix,
+            dist_coeffs,
+            &mut rvecs,
+            &mut tvecs,
+            r,
+        )
+    });
+    let camera_matrix = Mat::from_raw(camera_matrix);
+    let dist_coeffs = Mat::from_raw(dist_coeffs);
+    let rvecs = rvecs.unpack().into_iter().map(Mat::from_raw).collect();
+    let tvecs = tvecs.unpack().into_iter().map(Mat::from_raw).collect();
+    let result: Result<c_double, String> = result.into();
+    let rms = result.map_err(CvError::UnknownError)?;
+    Ok(Calibration {
+        camera_matrix,
+        dist_coeffs,
+        rvecs,
+        tvecs,
+        rms,
+    })
+}
+
+fn check_views(object_points: &[Vec<Point3f>], image_points: &[Vec<Point2f>]) -> Result<(), Error> {
+    if object_points.is_empty() || object_points.len() != image_points.len() {
+        return Err(CvError::InvalidArgument(format!(
+            "expected the same non-zero number of views, got {} object and {} image views",
+            object_points.len(),
+            image_points.len()
+        ))
+        .into());
+    }
+    for (i, (object, image)) in object_points.iter().zip(image_points).enumerate() {
+        if object.len() != image.len() {
+            return Err(CvError::InvalidArgument(format!(
+                "view {} has {} object points but {} image points",
+                i,
+                object.len(),
+                image.len()
+            ))
+            .into());
+        }
+    }
+    Ok(())
+}
+
+fn inner_or_null(mat: Option<&Mat>) -> *const CMat {
+    mat.map_or(ptr::null(), |m| m.inner)
+}
+
+/// Type of the maps computed by
+/// [init_undistort_rectify_map](fn.init_undistort_rectify_map.html)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapType {
+    /// `Cv32FC1` maps of the x and y coordinates
+    Float = 5,
+    /// Fixed-point maps (`CV_16SC2` and `Cv16UC1`), smaller and faster to
+    /// remap but less accurate
+    FixedPoint = 11,
+}
+
+/// Transforms `src` to compensate for the lens distortion. `new_camera_matrix`
+/// (e.g. from [get_optimal_new_camera_matrix](fn.get_optimal_new_camera_matrix.html))
+/// defaults to `camera_matrix`.
+///
+/// To correct many frames from the same camera, compute the maps once with
+/// [init_undistort_rectify_map](fn.init_undistort_rectify_map.html) and
+/// apply them with [remap](../mat/struct.Mat.html#method.remap) instead.
+pub fn undistort(
+    src: &Mat,
+    camera_matrix: &Mat,
+    dist_coeffs: &Mat,
+    new_camera_matrix: Option<&Mat>,
+) -> Result<Mat, Error> {
+    let dst = CMat::new();
+    let result = unsafe {
+        cv_undistort(
+            src.inner,
+            camera_matrix.inner,
+            dist_coeffs.inner,
+            inner_or_null(new_camera_matrix),
+            dst,
+        )
+    };
+    let dst = Mat::from_raw(dst);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(dst)
+}
+
+/// Computes the ideal point coordinates from the observed ones. Without `p`,
+/// the result is in normalized coordinates; pass the new camera matrix (or
+/// the projection matrix from a stereo rectification) to get pixels. `r` is
+/// an optional rectification transformation.
+pub fn undistort_points(
+    points: &[Point2f],
+    camera_matrix: &Mat,
+    dist_coeffs: &Mat,
+    r: Option<&Mat>,
+    p: Option<&Mat>,
+) -> Result<Vec<Point2f>, Error> {
+    let points = CVecView::pack(&points.to_vec());
+    let mut undistorted = CVec::<Point2f>::default();
+    let result = unsafe {
+        cv_undistort_points(
+            &points,
+            camera_matrix.inner,
+            dist_coeffs.inner,
+            inner_or_null(r),
+            inner_or_null(p),
+            &mut undistorted,
+        )
+    };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(undistorted.unpack())
+}
+
+/// Returns the new camera matrix for undistorted images of `new_image_size`
+/// (defaults to `image_size`), and the region of the undistorted image that
+/// only contains valid pixels.
+///
+/// `alpha` is the free scaling parameter between 0 (all the pixels are valid)
+/// and 1 (all the source pixels are kept, with black borders).
+pub fn get_optimal_new_camera_matrix(
+    camera_matrix: &Mat,
+    dist_coeffs: &Mat,
+    image_size: Size2i,
+    alpha: f64,
+    new_image_size: Option<Size2i>,
+    center_principal_point: bool,
+) -> Result<(Mat, Rect), Error> {
+    let new_camera_matrix = CMat::new();
+    let mut valid_roi = Rect::default();
+    let result = unsafe {
+        cv_get_optimal_new_camera_matrix(
+            camera_matrix.inner,
+            dist_coeffs.inner,
+            image_size,
+            alpha,
+            new_image_size.unwrap_or_default(),
+            center_principal_point,
+            new_camera_matrix,
+            &mut valid_roi,
+        )
+    };
+    let new_camera_matrix = Mat::from_raw(new_camera_matrix);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok((new_camera_matrix, valid_roi))
+}
+
+/// Computes the maps transforming distorted images of `size` into undistorted
+/// (and, with `r`, rectified) images with the `new_camera_matrix`
+/// intrinsics. Apply them with [remap](../mat/struct.Mat.html#method.remap).
+pub fn init_undistort_rectify_map(
+    camera_matrix: &Mat,
+    dist_coeffs: &Mat,
+    r: Option<&Mat>,
+    new_camera_matrix: &Mat,
+    size: Size2i,
+    map_type: MapType,
+) -> Result<(Mat, Mat), Error> {
+    let map1 = CMat::new();
+    let map2 = CMat::new();
+    let result = unsafe {
+        cv_init_undistort_rectify_map(
+            camera_matrix.inner,
+            dist_coeffs.inner,
+            inner_or_null(r),
+            new_camera_matrix.inner,
+            size,
+            map_type,
+            map1,
+            map2,
+        )
+    };
+    let map1 = Mat::from_raw(map1);
+    let map2 = Mat::from_raw(map2);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok((map1, map2))
+}
+
+/// 3-element vector, e.g. a rotation vector (see
+/// [rodrigues_to_matrix](fn.rodrigues_to_matrix.html)) or a translation
+pub type Vec3 = [f64; 3];
+
+/// 3x3 matrix, row by row
+pub type Mat3 = [[f64; 3]; 3];
+
+/// Method used by [solve_pnp](fn.solve_pnp.html)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PnPMethod {
+    /// Levenberg-Marquardt optimization minimizing the reprojection error
+    Iterative = 0,
+    /// Efficient PnP, requires at least 4 points
+    EPnP = 1,
+    /// P3P, requires exactly 4 points
+    P3P = 2,
+    /// Direct least-squares, requires at least 4 points
+    Dls = 3,
+    /// Uncalibrated PnP, also estimates the focal length
+    UPnP = 4,
+    /// Algebraic P3P, requires exactly 4 points
+    AP3P = 5,
+    /// Infinitesimal plane-based pose estimation for planar objects, requires
+    /// at least 4 points. Requires OpenCV 3.4.6 or 4.1.
+    Ippe = 6,
+    /// Same as `Ippe`, for a square marker of side `s` with the 4 object points
+    /// `(-s/2, s/2, 0)`, `(s/2, s/2, 0)`, `(s/2, -s/2, 0)` and `(-s/2, -s/2, 0)`
+    /// in this order. Requires OpenCV 3.4.6 or 4.1.
+    IppeSquare = 7,
+    /// Sequential quadratic programming PnP, requires at least 3 points.
+    /// Requires OpenCV 3.4.15 or 4.5.3.
+    SqPnP = 8,
+}
+
+fn check_object_points(object_points: &[Point3f], image_points: &[Point2f]) -> Result<(), Error> {
+    if object_points.len() != image_points.len() {
+        return Err(CvError::InvalidArgument(format!(
+            "got {} object points but {} image points",
+            object_points.len(),
+            image_points.len()
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+/// Finds the object pose from 3D-2D point correspondences. Returns the
+/// rotation and translation vectors bringing the object points to the camera
+/// coordinate system, or `None` if no solution was found.
+pub fn solve_pnp(
+    object_points: &[Point3f],
+    image_points: &[Point2f],
+    camera_matrix: &Mat,
+    dist_coeffs: &Mat,
+    method: PnPMethod,
+) -> Result<Option<(Vec3, Vec3)>, Error> {
+    check_object_points(object_points, image_points)?;
+    let object_points = CVecView::pack(&object_points.to_vec());
+    let image_points = CVecView::pack(&image_points.to_vec());
+    let mut rvec = Vec3::default();
+    let mut tvec = Vec3::default();
+    let result = CResult::<bool>::from_callback(|r| unsafe {
+        cv_solve_pnp(
+            &object_points,
+            &image_points,
+            camera_matrix.inner,
+            dist_coeffs.inner,
+            method,
+            rvec.as_mut_ptr(),
+            tvec.as_mut_ptr(),
+            r,
+        )
+    });
+    let result: Result<bool, String> = result.into();
+    let found = result.map_err(CvError::UnknownError)?;
+    Ok(if found { Some((rvec, tvec)) } else { None })
+}
+
+/// Same as [solve_pnp](fn.solve_pnp.html), but robust to outliers using
+/// RANSAC. A correspondence is an inlier if its reprojection error is below
+/// `reprojection_error` pixels. Also returns the indices of the inliers.
+pub fn solve_pnp_ransac(
+    object_points: &[Point3f],
+    image_points: &[Point2f],
+    camera_matrix: &Mat,
+    dist_coeffs: &Mat,
+    method: PnPMethod,
+    iterations_count: c_int,
+    reprojection_error: f32,
+    confidence: f64,
+) -> Result<Option<(Vec3, Vec3, Vec<c_int>)>, Error> {
+    check_object_points(object_points, image_points)?;
+    let object_points = CVecView::pack(&object_points.to_vec());
+    let image_points = CVecView::pack(&image_points.to_vec());
+    let mut rvec = Vec3::default();
+    let mut tvec = Vec3::default();
+    let mut inliers = CVec::<c_int>::default();
+    let result = CResult::<bool>::from_callback(|r| unsafe {
+        cv_solve_pnp_ransac(
+            &object_points,
+            &image_points,
+            camera_matrix.inner,
+            dist_coeffs.inner,
+            method,
+            iterations_count,
+            reprojection_error,
+            confidence,
+            rvec.as_mut_ptr(),
+            tvec.as_mut_ptr(),
+            &mut inliers,
+            r,
+        )
+    });
+    let result: Result<bool, String> = result.into();
+    let found = result.map_err(CvError::UnknownError)?;
+    Ok(if found {
+        Some((rvec, tvec, inliers.unpack()))
+    } else {
+        None
+    })
+}
+
+/// Converts a rotation vector (the rotation axis scaled by the angle in
+/// radians) to a rotation matrix.
+pub fn rodrigues_to_matrix(rvec: &Vec3) -> Result<Mat3, Error> {
+    let mut matrix = Mat3::default();
+    let result = unsafe { cv_rodrigues_to_matrix(rvec.as_ptr(), matrix.as_mut_ptr() as *mut c_double) };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(matrix)
+}
+
+/// Converts a rotation matrix to a rotation vector, the inverse of
+/// [rodrigues_to_matrix](fn.rodrigues_to_matrix.html).
+pub fn rodrigues_to_vector(matrix: &Mat3) -> Result<Vec3, Error> {
+    let mut rvec = Vec3::default();
+    let result = unsafe { cv_rodrigues_to_vector(matrix.as_ptr() as *const c_double, rvec.as_mut_ptr()) };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(rvec)
+}
+
+/// Projects 3D points to the image plane of a camera with the given pose
+/// (e.g. from [solve_pnp](fn.solve_pnp.html)), intrinsics and distortion.
+pub fn project_points(
+    object_points: &[Point3f],
+    rvec: &Vec3,
+    tvec: &Vec3,
+    camera_matrix: &Mat,
+    dist_coeffs: &Mat,
+) -> Result<Vec<Point2f>, Error> {
+    let object_points = CVecView::pack(&object_points.to_vec());
+    let mut image_points = CVec::<Point2f>::default();
+    let result = unsafe {
+        cv_project_points(
+            &object_points,
+            rvec.as_ptr(),
+            tvec.as_ptr(),
+            camera_matrix.inner,
+            dist_coeffs.inner,
+            &mut image_points,
+        )
+    };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(image_points.unpack())
+}
+
+/// Reconstructs 3D points from their projections in two views with the given
+/// 3x4 projection matrices (e.g. `p1` and `p2` of
+/// [StereoRectification](struct.StereoRectification.html), or `K [R | t]`).
+pub fn triangulate_points(
+    projection1: &Mat,
+    projection2: &Mat,
+    points1: &[Point2f],
+    points2: &[Point2f],
+) -> Result<Vec<Point3d>, Error> {
+    check_point_pairs(points1, points2)?;
+    let points1 = CVecView::pack(&points1.to_vec());
+    let points2 = CVecView::pack(&points2.to_vec());
+    let mut points = CVec::<Point3d>::default();
+    let result =
+        unsafe { cv_triangulate_points(projection1.inner, projection2.inner, &points1, &points2, &mut points) };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(points.unpack())
+}
+
+/// Converts points from homogeneous coordinates by dividing by the last
+/// coordinate. Points at infinity (`z = 0`) are left undivided.
+pub fn convert_points_from_homogeneous(points: &[Point3f]) -> Result<Vec<Point2f>, Error> {
+    let points = CVecView::pack(&points.to_vec());
+    let mut converted = CVec::<Point2f>::default();
+    let result = unsafe { cv_convert_points_from_homogeneous(&points, &mut converted) };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(converted.unpack())
+}
+
+/// Converts points to homogeneous coordinates by appending a coordinate equal
+/// to 1.
+pub fn convert_points_to_homogeneous(points: &[Point2f]) -> Result<Vec<Point3f>, Error> {
+    let points = CVecView::pack(&points.to_vec());
+    let mut converted = CVec::<Point3f>::default();
+    let result = unsafe { cv_convert_points_to_homogeneous(&points, &mut converted) };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(converted.unpack())
+}
+
+/// Result of [decompose_projection_matrix](fn.decompose_projection_matrix.html)
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectionDecomposition {
+    /// Intrinsic matrix
+    pub camera_matrix: Mat3,
+    /// Rotation from the world to the camera coordinate system
+    pub rotation: Mat3,
+    /// Position of the camera center in the world coordinate system
+    pub camera_position: Vec3,
+    /// Euler angles of the rotation around the x, y and z axes, in degrees
+    pub euler_angles: Vec3,
+}
+
+/// Decomposes a 3x4 projection matrix `K [R | t]` into the intrinsic matrix,
+/// the rotation and the position of the camera.
+pub fn decompose_projection_matrix(projection: &Mat) -> Result<ProjectionDecomposition, Error> {
+    let mut decomposition = ProjectionDecomposition {
+        camera_matrix: Mat3::default(),
+        rotation: Mat3::default(),
+        camera_position: Vec3::default(),
+        euler_angles: Vec3::default(),
+    };
+    let result = unsafe {
+        cv_decompose_projection_matrix(
+            projection.inner,
+            decomposition.camera_matrix.as_mut_ptr() as *mut c_double,
+            decomposition.rotation.as_mut_ptr() as *mut c_double,
+            decomposition.camera_position.as_mut_ptr(),
+            decomposition.euler_angles.as_mut_ptr(),
+        )
+    };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(decomposition)
+}
+
+/// One of the solutions of
+/// [decompose_homography_mat](fn.decompose_homography_mat.html)
+#[derive(Debug, Clone, Copy)]
+pub struct HomographyDecomposition {
+    /// Rotation between the two views
+    pub rotation: Mat3,
+    /// Translation between the two views, divided by the distance to the
+    /// plane
+    pub translation: Vec3,
+    /// Normal of the plane in the first view
+    pub normal: Vec3,
+}
+
+fn to_mat3(mat: &Mat) -> Mat3 {
+    let mut result = Mat3::default();
+    for (i, row) in result.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = mat.at2::<f64>(i as c_int, j as c_int);
+        }
+    }
+    result
+}
+
+fn to_vec3(mat: &Mat) -> Vec3 {
+    [mat.at2::<f64>(0, 0), mat.at2::<f64>(1, 0), mat.at2::<f64>(2, 0)]
+}
+
+/// Decomposes a homography between two views of a plane, given the intrinsic
+/// matrix, into up to 4 possible rotations, translations and plane normals.
+pub fn decompose_homography_mat(homography: &Mat, camera_matrix: &Mat) -> Result<Vec<HomographyDecomposition>, Error> {
+    let mut rotations = CVec::<*mut CMat>::default();
+    let mut translations = CVec::<*mut CMat>::default();
+    let mut normals = CVec::<*mut CMat>::default();
+    let result = CResult::<c_int>::from_callback(|r| unsafe {
+        cv_decompose_homography_mat(
+            homography.inner,
+            camera_matrix.inner,
+            &mut rotations,
+            &mut translations,
+            &mut normals,
+            r,
+        )
+    });
+    let rotations: Vec<Mat> = rotations.unpack().into_iter().map(Mat::from_raw).collect();
+    let translations: Vec<Mat> = translations.unpack().into_iter().map(Mat::from_raw).collect();
+    let normals: Vec<Mat> = normals.unpack().into_iter().map(Mat::from_raw).collect();
+    let result: Result<c_int, String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(rotations
+        .iter()
+        .zip(&translations)
+        .zip(&normals)
+        .map(|((rotation, translation), normal)| HomographyDecomposition {
+            rotation: to_mat3(rotation),
+            translation: to_vec3(translation),
+            normal: to_vec3(normal),
+        })
+        .collect())
+}
+
+enum CStereoMatcher {}
+
+/// Common interface of the block matching stereo correspondence algorithms
+pub trait StereoMatcher {
+    /// Computes the disparity map of a rectified stereo pair of single channel
+    /// 8-bit images (e.g. loaded with `Mat::from_path` and
+    /// `ImreadModes::ImreadGrayscale`). The disparity map is `Cv16SC1`, with
+    /// the disparities scaled by 16, and invalid pixels are set to
+    /// `(min_disparity - 1) * 16`.
+    fn compute(&self, left: &Mat, right: &Mat) -> Result<Mat, Error>;
+
+    /// Returns the minimum possible disparity
+    fn min_disparity(&self) -> c_int;
+
+    /// Returns the size of the disparity search range, a multiple of 16
+    fn num_disparities(&self) -> c_int;
+
+    /// Returns the size of the matched blocks
+    fn block_size(&self) -> c_int;
+}
+
+macro_rules! impl_stereo_matcher {
+    ($x:ident) => {
+        impl Drop for $x {
+            fn drop(&mut self) {
+                unsafe {
+                    cv_stereo_matcher_drop(self.value);
+                }
+            }
+        }
+
+        impl StereoMatcher for $x {
+            fn compute(&self, left: &Mat, right: &Mat) -> Result<Mat, Error> {
+                let disparity = CMat::new();
+                let result = unsafe { cv_stereo_matcher_compute(self.value, left.inner, right.inner, disparity) };
+                let disparity = Mat::from_raw(disparity);
+                let result: Result<(), String> = result.into();
+                result.map_err(CvError::UnknownError)?;
+                Ok(disparity)
+            }
+
+            fn min_disparity(&self) -> c_int {
+                unsafe { cv_stereo_matcher_get_min_disparity(self.value) }
+            }
+
+            fn num_disparities(&self) -> c_int {
+                unsafe { cv_stereo_matcher_get_num_disparities(self.value) }
+            }
+
+            fn block_size(&self) -> c_int {
+                unsafe { cv_stereo_matcher_get_block_size(self.value) }
+            }
+        }
+    };
+}
+
+/// Stereo correspondence using the block matching algorithm
+#[derive(Debug)]
+pub struct StereoBM {
+    value: *mut CStereoMatcher,
+}
+
+impl StereoBM {
+    /// Creates a new block matcher. Prefer
+    /// [StereoBMBuilder](struct.StereoBMBuilder.html), which provides the
+    /// defaults.
+    pub fn new(
+        min_disparity: c_int,
+        num_disparities: c_int,
+        block_size: c_int,
+        pre_filter_size: c_int,
+        pre_filter_cap: c_int,
+        texture_threshold: c_int,
+        uniqueness_ratio: c_int,
+        speckle_window_size: c_int,
+        speckle_range: c_int,
+        disp12_max_diff: c_int,
+    ) -> Self {
+        let value = unsafe {
+            cv_stereo_bm_new(
+                min_disparity,
+                num_disparities,
+                block_size,
+                pre_filter_size,
+                pre_filter_cap,
+                texture_threshold,
+                uniqueness_ratio,
+                speckle_window_size,
+                speckle_range,
+                disp12_max_diff,
+            )
+        };
+        StereoBM { value }
+    }
+}
+
+impl_stereo_matcher!(StereoBM);
+
+/// Builder that provides defaults for StereoBM
+#[derive(Debug, Copy, Clone, Default)]
+pub struct StereoBMBuilder {
+    min_disparity: Option<c_int>,
+    num_disparities: Option<c_int>,
+    block_size: Option<c_int>,
+    pre_filter_size: Option<c_int>,
+    pre_filter_cap: Option<c_int>,
+    texture_threshold: Option<c_int>,
+    uniqueness_ratio: Option<c_int>,
+    speckle_window_size: Option<c_int>,
+    speckle_range: Option<c_int>,
+    disp12_max_diff: Option<c_int>,
+}
+
+impl StereoBMBuilder {
+    /// Replace current min_disparity with specified value
+    pub fn min_disparity(mut self, value: c_int) -> Self {
+        self.min_disparity = Some(value);
+        self
+    }
+
+    /// Replace current num_disparities with specified value
+    pub fn num_disparities(mut self, value: c_int) -> Self {
+        self.num_disparities = Some(value);
+        self
+    }
+
+    /// Replace current block_size with specified value
+    pub fn block_size(mut self, value: c_int) -> Self {
+        self.block_size = Some(value);
+        self
+    }
+
+    /// Replace current pre_filter_size with specified value
+    pub fn pre_filter_size(mut self, value: c_int) -> Self {
+        self.pre_filter_size = Some(value);
+        self
+    }
+
+    /// Replace current pre_filter_cap with specified value
+    pub fn pre_filter_cap(mut self, value: c_int) -> Self {
+        self.pre_filter_cap = Some(value);
+        self
+    }
+
+    /// Replace current texture_threshold with specified value
+    pub fn texture_threshold(mut self, value: c_int) -> Self {
+        self.texture_threshold = Some(value);
+        self
+    }
+
+    /// Replace current uniqueness_ratio with specified value
+    pub fn uniqueness_ratio(mut self, value: c_int) -> Self {
+        self.uniqueness_ratio = Some(value);
+        self
+    }
+
+    /// Replace current speckle_window_size with specified value
+    pub fn speckle_window_size(mut self, value: c_int) -> Self {
+        self.speckle_window_size = Some(value);
+        self
+    }
+
+    /// Replace current speckle_range with specified value
+    pub fn speckle_range(mut self, value: c_int) -> Self {
+        self.speckle_range = Some(value);
+        self
+    }
+
+    /// Replace current disp12_max_diff with specified value
+    pub fn disp12_max_diff(mut self, value: c_int) -> Self {
+        self.disp12_max_diff = Some(value);
+        self
+    }
+}
+
+impl Into<StereoBM> for StereoBMBuilder {
+    fn into(self) -> StereoBM {
+        StereoBM::new(
+            self.min_disparity.unwrap_or(0),
+            self.num_disparities.unwrap_or(64),
+            self.block_size.unwrap_or(21),
+            self.pre_filter_size.unwrap_or(9),
+            self.pre_filter_cap.unwrap_or(31),
+            self.texture_threshold.unwrap_or(10),
+            self.uniqueness_ratio.unwrap_or(15),
+            self.speckle_window_size.unwrap_or(0),
+            self.speckle_range.unwrap_or(0),
+            self.disp12_max_diff.unwrap_or(-1),
+        )
+    }
+}
+
+/// Variant of the semi-global block matching algorithm
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SGBMMode {
+    /// Single pass, 5 directions
+    SGBM = 0,
+    /// Full-scale two-pass dynamic programming, 8 directions. Slow and
+    /// memory hungry.
+    HH = 1,
+    /// Faster 3-way variant of `SGBM`
+    SGBM3Way = 2,
+}
+
+/// Stereo correspondence using the semi-global block matching algorithm
+#[derive(Debug)]
+pub struct StereoSGBM {
+    value: *mut CStereoMatcher,
+}
+
+impl StereoSGBM {
+    /// Creates a new semi-global block matcher. Prefer
+    /// [StereoSGBMBuilder](struct.StereoSGBMBuilder.html), which provides
+    /// the defaults.
+    pub fn new(
+        min_disparity: c_int,
+        num_disparities: c_int,
+        block_size: c_int,
+        p1: c_int,
+        p2: c_int,
+        disp12_max_diff: c_int,
+        pre_filter_cap: c_int,
+        uniqueness_ratio: c_int,
+        speckle_window_size: c_int,
+        speckle_range: c_int,
+        mode: SGBMMode,
+    ) -> Self {
+        let value = unsafe {
+            cv_stereo_sgbm_new(
+                min_disparity,
+                num_disparities,
+                block_size,
+                p1,
+                p2,
+                disp12_max_diff,
+                pre_filter_cap,
+                uniqueness_ratio,
+                speckle_window_size,
+                speckle_range,
+                mode,
+            )
+        };
+        StereoSGBM { value }
+    }
+}
+
+impl_stereo_matcher!(StereoSGBM);
+
+/// Builder that provides defaults for StereoSGBM. `p1` and `p2` control the
+/// disparity smoothness, `8 * channels * block_size^2` and
+/// `32 * channels * block_size^2` are reasonable values.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct StereoSGBMBuilder {
+    min_disparity: Option<c_int>,
+    num_disparities: Option<c_int>,
+    block_size: Option<c_int>,
+    p1: Option<c_int>,
+    p2: Option<c_int>,
+    disp12_max_diff: Option<c_int>,
+    pre_filter_cap: Option<c_int>,
+    uniqueness_ratio: Option<c_int>,
+    speckle_window_size: Option<c_int>,
+    speckle_range: Option<c_int>,
+    mode: Option<SGBMMode>,
+}
+
+impl StereoSGBMBuilder {
+    /// Replace current min_disparity with specified value
+    pub fn min_disparity(mut self, value: c_int) -> Self {
+        self.min_disparity = Some(value);
+        self
+    }
+
+    /// Replace current num_disparities with specified value
+    pub fn num_disparities(mut self, value: c_int) -> Self {
+        self.num_disparities = Some(value);
+        self
+    }
+
+    /// Replace current block_size with specified value
+    pub fn block_size(mut self, value: c_int) -> Self {
+        self.block_size = Some(value);
+        self
+    }
+
+    /// Replace current p1 with specified value
+    pub fn p1(mut self, value: c_int) -> Self {
+        self.p1 = Some(value);
+        self
+    }
+
+    /// Replace current p2 with specified value
+    pub fn p2(mut self, value: c_int) -> Self {
+        self.p2 = Some(value);
+        self
+    }
+
+    /// Replace current disp12_max_diff with specified value
+    pub fn disp12_max_diff(mut self, value: c_int) -> Self {
+        self.disp12_max_diff = Some(value);
+        self
+    }
+
+    /// Replace current pre_filter_cap with specified value
+    pub fn pre_filter_cap(mut self, value: c_int) -> Self {
+        self.pre_filter_cap = Some(value);
+        self
+    }
+
+    /// Replace current uniqueness_ratio with specified value
+    pub fn uniqueness_ratio(mut self, value: c_int) -> Self {
+        self.uniqueness_ratio = Some(value);
+        self
+    }
+
+    /// Replace current speckle_window_size with specified value
+    pub fn speckle_window_size(mut self, value: c_int) -> Self {
+        self.speckle_window_size = Some(value);
+        self
+    }
+
+    /// Replace current speckle_range with specified value
+    pub fn speckle_range(mut self, value: c_int) -> Self {
+        self.speckle_range = Some(value);
+        self
+    }
+
+    /// Replace current mode with specified value
+    pub fn mode(mut self, value: SGBMMode) -> Self {
+        self.mode = Some(value);
+        self
+    }
+}
+
+impl Into<StereoSGBM> for StereoSGBMBuilder {
+    fn into(self) -> StereoSGBM {
+        StereoSGBM::new(
+            self.min_disparity.unwrap_or(0),
+            self.num_disparities.unwrap_or(16),
+            self.block_size.unwrap_or(3),
+            self.p1.unwrap_or(0),
+            self.p2.unwrap_or(0),
+            self.disp12_max_diff.unwrap_or(0),
+            self.pre_filter_cap.unwrap_or(0),
+            self.uniqueness_ratio.unwrap_or(0),
+            self.speckle_window_size.unwrap_or(0),
+            self.speckle_range.unwrap_or(0),
+            self.mode.unwrap_or(SGBMMode::SGBM),
+        )
+    }
+}
+
+/// Operation flags for [stereo_calibrate](fn.stereo_calibrate.html)
+#[derive(Debug, Clone, Copy)]
+pub struct StereoCalibrationFlags {
+    /// Keeps the given camera matrices and distortion coefficients, only
+    /// estimating the transformation between the cameras
+    pub fix_intrinsic: bool,
+    /// Optimizes the given camera matrices and distortion coefficients
+    /// instead of initializing them from scratch
+    pub use_intrinsic_guess: bool,
+    /// Keeps `fx` and `fy` equal in both cameras
+    pub same_focal_length: bool,
+    /// Flags applied to both cameras when their intrinsics are optimized
+    pub calibration: CalibrationFlags,
+}
+
+impl Default for StereoCalibrationFlags {
+    fn default() -> Self {
+        StereoCalibrationFlags {
+            fix_intrinsic: true,
+            use_intrinsic_guess: false,
+            same_focal_length: false,
+            calibration: CalibrationFlags::default(),
+        }
+    }
+}
+
+impl StereoCalibrationFlags {
+    fn bits(&self) -> c_int {
+        let flags = [
+            (self.use_intrinsic_guess, 0x00001),
+            (self.fix_intrinsic, 0x00100),
+            (self.same_focal_length, 0x00200),
+        ];
+        flags
+            .iter()
+            .filter(|f| f.0)
+            .fold(self.calibration.bits(), |bits, f| bits | f.1)
+    }
+}
+
+/// Result of [stereo_calibrate](fn.stereo_calibrate.html)
+#[derive(Debug)]
+pub struct StereoCalibration {
+    /// Intrinsic matrix of the first camera
+    pub camera_matrix1: Mat,
+    /// Distortion coefficients of the first camera
+    pub dist_coeffs1: Mat,
+    /// Intrinsic matrix of the second camera
+    pub camera_matrix2: Mat,
+    /// Distortion coefficients of the second camera
+    pub dist_coeffs2: Mat,
+    /// 3x3 rotation matrix from the first to the second camera coordinate
+    /// system
+    pub rotation: Mat,
+    /// Translation vector from the first to the second camera coordinate
+    /// system
+    pub translation: Mat,
+    /// Essential matrix
+    pub essential: Mat,
+    /// Fundamental matrix
+    pub fundamental: Mat,
+    /// Overall RMS reprojection error, in pixels
+    pub rms: f64,
+}
+
+/// Finds the transformation between two cameras from several views of a
+/// calibration pattern seen by both, and optionally refines their
+/// intrinsics (see [StereoCalibrationFlags](struct.StereoCalibrationFlags.html)).
+/// The initial intrinsics usually come from
+/// [calibrate_camera](fn.calibrate_camera.html).
+pub fn stereo_calibrate(
+    object_points: &[Vec<Point3f>],
+    image_points1: &[Vec<Point2f>],
+    image_points2: &[Vec<Point2f>],
+    camera_matrix1: &Mat,
+    dist_coeffs1: &Mat,
+    camera_matrix2: &Mat,
+    dist_coeffs2: &Mat,
+    image_size: Size2i,
+    flags: StereoCalibrationFlags,
+    criteria: &TermCriteria,
+) -> Result<StereoCalibration, Error> {
+    check_views(object_points, image_points1)?;
+    check_views(object_points, image_points2)?;
+    let object_points = CVecView::pack(&object_points.to_vec());
+    let image_points1 = CVecView::pack(&image_points1.to_vec());
+    let image_points2 = CVecView::pack(&image_points2.to_vec());
+    let out_camera_matrix1 = CMat::new();
+    let out_dist_coeffs1 = CMat::new();
+    let out_camera_matrix2 = CMat::new();
+    let out_dist_coeffs2 = CMat::new();
+    let rotation = CMat::new();
+    let translation = CMat::new();
+    let essential = CMat::new();
+    let fundamental = CMat::new();
+    let result = CResult::<c_double>::from_callback(|r| unsafe {
+        cv_stereo_calibrate(
+            &object_points,
+            &image_points1,
+            &image_points2,
+            camera_matrix1.inner,
+            dist_coeffs1.inner,
+            camera_matrix2.inner,
+            dist_coeffs2.inner,
+            image_size,
+            flags.bits(),
+            criteria.c_criteria,
+            out_camera_matrix1,
+            out_dist_coeffs1,
+            out_camera_matrix2,
+            out_dist_coeffs2,
+            rotation,
+            translation,
+            essential,
+            fundamental,
+            r,
+        )
+    });
+    let calibration = StereoCalibration {
+        camera_matrix1: Mat::from_raw(out_camera_matrix1),
+        dist_coeffs1: Mat::from_raw(out_dist_coeffs1),
+        camera_matrix2: Mat::from_raw(out_camera_matrix2),
+        dist_coeffs2: Mat::from_raw(out_dist_coeffs2),
+        rotation: Mat::from_raw(rotation),
+        translation: Mat::from_raw(translation),
+        essential: Mat::from_raw(essential),
+        fundamental: Mat::from_raw(fundamental),
+        rms: 0.0,
+    };
+    let result: Result<c_double, String> = result.into();
+    let rms = result.map_err(CvError::UnknownError)?;
+    Ok(StereoCalibration { rms, ..calibration })
+}
+
+/// Result of [stereo_rectify](fn.stereo_rectify.html). The rectification
+/// maps of each camera are given by
+/// [init_undistort_rectify_map](fn.init_undistort_rectify_map.html) with
+/// `r1` and `p1` (resp. `r2` and `p2`).
+#[derive(Debug)]
+pub struct StereoRectification {
+    /// 3x3 rectification rotation of the first camera
+    pub r1: Mat,
+    /// 3x3 rectification rotation of the second camera
+    pub r2: Mat,
+    /// 3x4 projection matrix of the first camera in the rectified coordinate
+    /// system
+    pub p1: Mat,
+    /// 3x4 projection matrix of the second camera in the rectified coordinate
+    /// system
+    pub p2: Mat,
+    /// 4x4 disparity-to-depth mapping matrix, see
+    /// [reproject_image_to_3d](fn.reproject_image_to_3d.html)
+    pub q: Mat,
+    /// Region of the first rectified image where all the pixels are valid
+    pub valid_roi1: Rect,
+    /// Region of the second rectified image where all the pixels are valid
+    pub valid_roi2: Rect,
+}
+
+/// Computes the rectification transforms making the epipolar lines of a
+/// calibrated stereo pair horizontal. If `zero_disparity` is set, the
+/// principal points of both rectified views are the same. `alpha` is
+/// between 0 (only valid pixels are visible) and 1 (all the source pixels are
+/// kept), or negative for the default scaling.
+pub fn stereo_rectify(
+    calibration: &StereoCalibration,
+    image_size: Size2i,
+    zero_disparity: bool,
+    alpha: f64,
+) -> Result<StereoRectification, Error> {
+    let r1 = CMat::new();
+    let r2 = CMat::new();
+    let p1 = CMat::new();
+    let p2 = CMat::new();
+    let q = CMat::new();
+    let mut valid_roi1 = Rect::default();
+    let mut valid_roi2 = Rect::default();
+    let result = unsafe {
+        cv_stereo_rectify(
+            calibration.camera_matrix1.inner,
+            calibration.dist_coeffs1.inner,
+            calibration.camera_matrix2.inner,
+            calibration.dist_coeffs2.inner,
+            image_size,
+            calibration.rotation.inner,
+            calibration.translation.inner,
+            zero_disparity,
+            alpha,
+            r1,
+            r2,
+            p1,
+            p2,
+            q,
+            &mut valid_roi1,
+            &mut valid_roi2,
+        )
+    };
+    let rectification = StereoRectification {
+        r1: Mat::from_raw(r1),
+        r2: Mat::from_raw(r2),
+        p1: Mat::from_raw(p1),
+        p2: Mat::from_raw(p2),
+        q: Mat::from_raw(q),
+        valid_roi1,
+        valid_roi2,
+    };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(rectification)
+}
+
+/// Reprojects a disparity map (e.g. from
+/// [StereoMatcher::compute](trait.StereoMatcher.html#tymethod.compute)) to a
+/// `Cv32FC3` point cloud, using the `q` matrix from
+/// [stereo_rectify](fn.stereo_rectify.html). Fixed-point `Cv16SC1`
+/// disparities must be divided by 16 first. If `handle_missing_values` is
+/// set, the pixels with the minimal disparity get a very large `z` (10000).
+pub fn reproject_image_to_3d(disparity: &Mat, q: &Mat, handle_missing_values: bool) -> Result<Mat, Error> {
+    let points = CMat::new();
+    let result = unsafe { cv_reproject_image_to_3d(disparity.inner, q.inner, handle_missing_values, points) };
+    let points = Mat::from_raw(points);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(points)
+}
+
+/// Replaces, in place, the small blobs of a disparity map by `new_value`.
+/// Neighbouring pixels belong to the same blob when their disparities differ
+/// by at most `max_diff`, and blobs of at most `max_speckle_size` pixels are
+/// filtered out.
+pub fn filter_speckles(image: &mut Mat, new_value: f64, max_speckle_size: c_int, max_diff: f64) -> Result<(), Error> {
+    let result = unsafe { cv_filter_speckles(image.inner, new_value, max_speckle_size, max_diff) };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(())
+}
+
+pub mod fisheye {
+    //! Fisheye camera model, see [OpenCV
+    //! fisheye](https://docs.opencv.org/3.4.1/db/d58/group__calib3d__fisheye.html).
+    //!
+    //! The distortion coefficients are `(k1, k2, k3, k4)`.
+
+    use super::*;
+
+    extern "C" {
+        fn cv_fisheye_undistort_image(
+            distorted: *const CMat,
+            k: *const CMat,
+            d: *const CMat,
+            knew: *const CMat,
+            new_size: Size2i,
+            undistorted: *mut CMat,
+        ) -> CEmptyResult;
+        fn cv_fisheye_undistort_points(
+            points: *const CVecView<Point2f>,
+            k: *const CMat,
+            d: *const CMat,
+            r: *const CMat,
+            p: *const CMat,
+            undistorted: *mut CVec<Point2f>,
+        ) -> CEmptyResult;
+        fn cv_fisheye_init_undistort_rectify_map(
+            k: *const CMat,
+            d: *const CMat,
+            r: *const CMat,
+            p: *const CMat,
+            size: Size2i,
+            map_type: MapType,
+            map1: *mut CMat,
+            map2: *mut CMat,
+        ) -> CEmptyResult;
+        fn cv_fisheye_calibrate(
+            object_points: *const CVecView<CVecView<Point3f>>,
+            image_points: *const CVecView<CVecView<Point2f>>,
+            image_size: Size2i,
+            flags: c_int,
+            criteria: *const CTermCriteria,
+            k: *mut CMat,
+            d: *mut CMat,
+            rvecs: *mut CVec<*mut CMat>,
+            tvecs: *mut CVec<*mut CMat>,
+            result: *mut CResult<c_double>,
+        );
+    }
+
+    /// Operation flags for [calibrate](fn.calibrate.html)
+    #[derive(Default, Debug, Clone, Copy)]
+    pub struct CalibrationFlags {
+        /// Recomputes the extrinsic parameters after each iteration of the
+        /// intrinsic optimization
+        pub recompute_extrinsic: bool,
+        /// Checks the validity of the condition number
+        pub check_cond: bool,
+        /// Keeps the skew coefficient to zero
+        pub fix_skew: bool,
+        /// Keeps the distortion coefficient `k1` to zero
+        pub fix_k1: bool,
+        /// Keeps the distortion coefficient `k2` to zero
+        pub fix_k2: bool,
+        /// Keeps the distortion coefficient `k3` to zero
+        pub fix_k3: bool,
+        /// Keeps the distortion coefficient `k4` to zero
+        pub fix_k4: bool,
+        /// Keeps the principal point at the center of the image
+        pub fix_principal_point: bool,
+    }
+
+    impl CalibrationFlags {
+        fn bits(&self) -> c_int {
+            let flags = [
+                (self.recompute_extrinsic, 2),
+                (self.check_cond, 4),
+                (self.fix_skew, 8),
+                (self.fix_k1, 16),
+                (self.fix_k2, 32),
+                (self.fix_k3, 64),
+                (self.fix_k4, 128),
+                (self.fix_principal_point, 512),
+            ];
+            flags.iter().filter(|f| f.0).fold(0, |bits, f| bits | f.1)
+        }
+    }
+
+    /// Transforms `distorted` to compensate for the fisheye lens distortion.
+    /// `knew` defaults to `k`, and `new_size` to the size of `distorted`.
+    pub fn undistort_image(
+        distorted: &Mat,
+        k: &Mat,
+        d: &Mat,
+        knew: Option<&Mat>,
+        new_size: Option<Size2i>,
+    ) -> Result<Mat, Error> {
+        let undistorted = CMat::new();
+        let result = unsafe {
+            cv_fisheye_undistort_image(
+                distorted.inner,
+                k.inner,
+                d.inner,
+                inner_or_null(knew),
+                new_size.unwrap_or_default(),
+                undistorted,
+            )
+        };
+        let undistorted = Mat::from_raw(undistorted);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(undistorted)
+    }
+
+    /// Same as [undistort_points](../fn.undistort_points.html) for the
+    /// fisheye model.
+    pub fn undistort_points(
+        points: &[Point2f],
+        k: &Mat,
+        d: &Mat,
+        r: Option<&Mat>,
+        p: Option<&Mat>,
+    ) -> Result<Vec<Point2f>, Error> {
+        let points = CVecView::pack(&points.to_vec());
+        let mut undistorted = CVec::<Point2f>::default();
+        let result = unsafe {
+            cv_fisheye_undistort_points(
+                &points,
+                k.inner,
+                d.inner,
+                inner_or_null(r),
+                inner_or_null(p),
+                &mut undistorted,
+            )
+        };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(undistorted.unpack())
+    }
+
+    /// Same as [init_undistort_rectify_map](../fn.init_undistort_rectify_map.html)
+    /// for the fisheye model. `p` is the new camera matrix.
+    pub fn init_undistort_rectify_map(
+        k: &Mat,
+        d: &Mat,
+        r: Option<&Mat>,
+        p: &Mat,
+        size: Size2i,
+        map_type: MapType,
+    ) -> Result<(Mat, Mat), Error> {
+        let map1 = CMat::new();
+        let map2 = CMat::new();
+        let result = unsafe {
+            cv_fisheye_init_undistort_rectify_map(
+                k.inner,
+                d.inner,
+                inner_or_null(r),
+                p.inner,
+                size,
+                map_type,
+                map1,
+                map2,
+            )
+        };
+        let map1 = Mat::from_raw(map1);
+        let map2 = Mat::from_raw(map2);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok((map1, map2))
+    }
+
+    /// Same as [calibrate_camera](../fn.calibrate_camera.html) for the
+    /// fisheye model. The returned `dist_coeffs` are `(k1, k2, k3, k4)`.
+    pub fn calibrate(
+        object_points: &[Vec<Point3f>],
+        image_points: &[Vec<Point2f>],
+        image_size: Size2i,
+        flags: CalibrationFlags,
+        criteria: &TermCriteria,
+    ) -> Result<Calibration, Error> {
+        check_views(object_points, image_points)?;
+        let object_points = CVecView::pack(&object_points.to_vec());
+        let image_points = CVecView::pack(&image_points.to_vec());
+        let k = CMat::new();
+        let d = CMat::new();
+        let mut rvecs = CVec::<*mut CMat>::default();
+        let mut tvecs = CVec::<*mut CMat>::default();
+        let result = CResult::<c_double>::from_callback(|r| unsafe {
+            cv_fisheye_calibrate(
+                &object_points,
+                &image_points,
+                image_size,
+                flags.bits(),
+                criteria.c_criteria,
+                k,
+                d,
+                &mut rvecs,
+                &mut tvecs,
+                r,
+            )
+        });
+        let camera_matrix = Mat::from_raw(k);
+        let dist_coeffs = Mat::from_raw(d);
+        let rvecs = rvecs.unpack().into_iter().map(Mat::from_raw).collect();
+        let tvecs = tvecs.unpack().into_iter().map(Mat::from_raw).collect();
+        let result: Result<c_double, String> = result.into();
+        let rms = result.map_err(CvError::UnknownError)?;
+        Ok(Calibration {
+            camera_matrix,
+            dist_coeffs,
+            rvecs,
+            tvecs,
+            rms,
+        })
+    }
+}
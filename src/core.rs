@@ -1,15 +1,30 @@
 //! Core data structures in OpenCV
 
 use bytes::{self, ByteOrder};
+use errors::*;
+use failure::Error;
 use mat::*;
 use std::mem;
 use std::os::raw::c_int;
+use std::ptr;
+use CResult;
 
 pub(crate) enum CTermCriteria {}
 
 extern "C" {
     fn cv_term_criteria_new(t: TermType, count: c_int, epsilon: f64) -> *mut CTermCriteria;
     fn cv_term_criteria_drop(criteria: *mut CTermCriteria);
+    fn cv_kmeans(
+        data: *const CMat,
+        k: c_int,
+        initial_labels: *const CMat,
+        labels: *mut CMat,
+        criteria: *const CTermCriteria,
+        attempts: c_int,
+        flags: KMeansCenters,
+        centers: *mut CMat,
+        result: *mut CResult<f64>,
+    );
 }
 
 /// Data structure for salient point detectors
@@ -30,6 +45,46 @@ pub struct KeyPoint {
     pub class_id: c_int,
 }
 
+impl KeyPoint {
+    /// Creates a new `KeyPoint`, without orientation (`angle` is -1),
+    /// response, octave and class (`class_id` is -1).
+    pub fn new(point: Point2f, size: f32) -> Self {
+        KeyPoint {
+            point: point,
+            size: size,
+            angle: -1.0,
+            response: 0.0,
+            octave: 0,
+            class_id: -1,
+        }
+    }
+
+    /// Converts keypoints to their coordinates.
+    pub fn convert(keypoints: &[KeyPoint]) -> Vec<Point2f> {
+        keypoints.iter().map(|k| k.point).collect()
+    }
+
+    /// Converts points to keypoints of the given size, response, octave and
+    /// class. The keypoints have no orientation.
+    pub fn convert_points(
+        points: &[Point2f],
+        size: f32,
+        response: f32,
+        octave: c_int,
+        class_id: c_int,
+    ) -> Vec<KeyPoint> {
+        points
+            .iter()
+            .map(|p| KeyPoint {
+                response: response,
+                octave: octave,
+                class_id: class_id,
+                ..KeyPoint::new(*p, size)
+            })
+            .collect()
+    }
+}
+
 /// A 4-element struct that is widely used to pass pixel values.
 #[derive(Default, Debug, Clone, Copy)]
 #[repr(C)]
@@ -98,6 +153,48 @@ impl Point2f {
     }
 }
 
+/// 3D floating points specified by its coordinates `x`, `y` and `z`.
+#[derive(Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Point3f {
+    /// x coordinate
+    pub x: f32,
+
+    /// y coordinate
+    pub y: f32,
+
+    /// z coordinate
+    pub z: f32,
+}
+
+impl Point3f {
+    /// Creats a new `Point3f`.
+    pub fn new(x: f32, y: f32, z: f32) -> Self {
+        Point3f { x: x, y: y, z: z }
+    }
+}
+
+/// 3D double precision points specified by its coordinates `x`, `y` and `z`.
+#[derive(Default, Debug, Clone, Copy)]
+#[repr(C)]
+pub struct Point3d {
+    /// x coordinate
+    pub x: f64,
+
+    /// y coordinate
+    pub y: f64,
+
+    /// z coordinate
+    pub z: f64,
+}
+
+impl Point3d {
+    /// Creats a new `Point3d`.
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Point3d { x: x, y: y, z: z }
+    }
+}
+
 /// `Size2i` struct is used for specifying the size of an image or rectangle with integer dimensions.
 #[derive(Default, Debug, Clone, Copy)]
 #[repr(C)]
@@ -352,6 +449,10 @@ pub enum CvType {
     Cv64FC1 = 6,
     /// 8 bit, two channel (rarelly seen)
     Cv8UC2 = 8,
+    /// 16 bit signed, two channels (e.g. image gradients)
+    Cv16SC2 = 11,
+    /// 32 bit float, two channels (e.g. optical flow)
+    Cv32FC2 = 13,
     /// 8 bit unsigned, three channels (RGB image)
     Cv8UC3 = 16,
     /// 8 bit signed, three channels (RGB image)
@@ -368,6 +469,32 @@ pub enum CvType {
     Cv64FC3 = 22,
 }
 
+impl CvType {
+    /// Converts a raw OpenCV type, returning `None` if it has no variant.
+    pub(crate) fn from_raw(value: c_int) -> Option<CvType> {
+        match value {
+            0 => Some(CvType::Cv8UC1),
+            1 => Some(CvType::Cv8SC1),
+            2 => Some(CvType::Cv16UC1),
+            3 => Some(CvType::Cv16SC1),
+            4 => Some(CvType::Cv32SC1),
+            5 => Some(CvType::Cv32FC1),
+            6 => Some(CvType::Cv64FC1),
+            8 => Some(CvType::Cv8UC2),
+            11 => Some(CvType::Cv16SC2),
+            13 => Some(CvType::Cv32FC2),
+            16 => Some(CvType::Cv8UC3),
+            17 => Some(CvType::Cv8SC3),
+            18 => Some(CvType::Cv16UC3),
+            19 => Some(CvType::Cv16SC3),
+            20 => Some(CvType::Cv32SC3),
+            21 => Some(CvType::Cv32FC3),
+            22 => Some(CvType::Cv64FC3),
+            _ => None,
+        }
+    }
+}
+
 /// This struct represents a rotated (i.e. not up-right) rectangle. Each
 /// rectangle is specified by the center point (mass center), length of each
 /// side (represented by `Size2f`) and the rotation angle in degrees.
@@ -435,6 +562,23 @@ pub enum NormType {
     MinMax = 32,
 }
 
+impl NormType {
+    /// Converts a raw OpenCV norm type, returning `None` if it has no variant.
+    pub(crate) fn from_raw(value: c_int) -> Option<NormType> {
+        match value {
+            1 => Some(NormType::Inf),
+            2 => Some(NormType::L1),
+            4 => Some(NormType::L2),
+            5 => Some(NormType::L2Sqr),
+            6 => Some(NormType::Hamming),
+            7 => Some(NormType::Hamming2),
+            8 => Some(NormType::Relative),
+            32 => Some(NormType::MinMax),
+            _ => None,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 /// Term criteria type, can be one of: Count, Eps or Count + Eps
@@ -445,6 +589,10 @@ pub enum TermType {
     /// the desired accuracy or change in parameters at which the iterative
     /// algorithm stops.
     EPS = 2,
+
+    /// Stops when either the maximum number of iterations or the desired
+    /// accuracy is reached, whichever comes first.
+    CountEps = 3,
 }
 
 /// Termination criteria for iterative algorithms.
@@ -468,3 +616,64 @@ impl Drop for TermCriteria {
         }
     }
 }
+
+/// k-Means centers
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum KMeansCenters {
+    /// Select random initial centers in each attempt.
+    Random = 0,
+    /// Use the user-supplied labels for the first attempt instead of
+    /// computing them from the initial centers. The next attempts use random
+    /// centers.
+    UseInitialLabels = 1,
+    /// Use kmeans++ center initialization by Arthur and Vassilvitskii (Arthur2007).
+    Pp = 2,
+}
+
+/// Finds the centers of `k` clusters and groups the samples around them.
+///
+/// `data` is a `Cv32FC1` matrix with one sample per row. The algorithm is run
+/// `attempts` times and the best labelling is kept. `initial_labels` is
+/// required with `KMeansCenters::UseInitialLabels` and ignored otherwise; it
+/// is a `Cv32SC1` column with the cluster index of each sample.
+///
+/// Returns the compactness (the sum of squared distances from each sample to
+/// its center), the cluster index of each sample and the centers, one per row.
+pub fn kmeans(
+    data: &Mat,
+    k: c_int,
+    criteria: &TermCriteria,
+    attempts: c_int,
+    flags: KMeansCenters,
+    initial_labels: Option<&Mat>,
+) -> Result<(f64, Mat, Mat), Error> {
+    let initial_labels = match (flags, initial_labels) {
+        (KMeansCenters::UseInitialLabels, None) => {
+            return Err(CvError::InvalidArgument("initial labels are required with UseInitialLabels".into()).into())
+        }
+        (KMeansCenters::UseInitialLabels, Some(labels)) => labels.inner as *const CMat,
+        _ => ptr::null(),
+    };
+
+    let labels = CMat::new();
+    let centers = CMat::new();
+    let result = CResult::<f64>::from_callback(|r| unsafe {
+        cv_kmeans(
+            data.inner,
+            k,
+            initial_labels,
+            labels,
+            criteria.c_criteria,
+            attempts,
+            flags,
+            centers,
+            r,
+        )
+    });
+    let labels = Mat::from_raw(labels);
+    let centers = Mat::from_raw(centers);
+    let result: Result<f64, String> = result.into();
+    let compactness = result.map_err(CvError::UnknownError)?;
+    Ok((compactness, labels, centers))
+}
@@ -16,4 +16,7 @@ pub enum CvError {
     #[fail(display = "Non ascii characters found in string: {:?}", _0)]
     /// Indicates that string contains non ascii characters
     UnicodeChars(String),
+    #[fail(display = "Invalid argument: {:?}", _0)]
+    /// Indicates that the arguments passed to a function are inconsistent
+    InvalidArgument(String),
 }
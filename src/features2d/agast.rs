@@ -0,0 +1,79 @@
+//! Provide the type that encapsulates all the parameters of the AGAST detection algorithm
+use super::private::*;
+use std::os::raw::*;
+
+extern "C" {
+    fn cv_agast_new(threshold: c_int, nonmax_suppression: bool, detector_type: AgastDetectorType) -> *mut CFeature2D;
+}
+
+/// Mask used by the AGAST detector.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum AgastDetectorType {
+    /// AGAST with a 5/8 pixels mask
+    Agast5_8 = 0,
+    /// AGAST with a diamond-shaped 7/12 pixels mask
+    Agast7_12d = 1,
+    /// AGAST with a square-shaped 7/12 pixels mask
+    Agast7_12s = 2,
+    /// Optimal AGAST with a 9/16 pixels mask
+    Oast9_16 = 3,
+}
+
+/// Adaptive and generic accelerated segment test corner detector. It only
+/// detects keypoints, the descriptors computed by
+/// [detect_and_compute](trait.Feature2D.html#tymethod.detect_and_compute)
+/// are empty.
+#[derive(Debug)]
+pub struct AgastFeatureDetector {
+    value: *mut CFeature2D,
+}
+
+impl AgastFeatureDetector {
+    /// Creates a new AGAST detector.
+    pub fn new(threshold: c_int, nonmax_suppression: bool, detector_type: AgastDetectorType) -> Self {
+        let agast = unsafe { cv_agast_new(threshold, nonmax_suppression, detector_type) };
+        AgastFeatureDetector { value: agast }
+    }
+}
+
+impl_feature2d!(AgastFeatureDetector);
+
+/// Builder that provides defaults for AgastFeatureDetector
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AgastFeatureDetectorBuilder {
+    threshold: Option<c_int>,
+    nonmax_suppression: Option<bool>,
+    detector_type: Option<AgastDetectorType>,
+}
+
+impl AgastFeatureDetectorBuilder {
+    /// Replace current threshold with specified value
+    pub fn threshold(mut self, value: c_int) -> Self {
+        self.threshold = Some(value);
+        self
+    }
+
+    /// Replace current nonmax_suppression with specified value
+    pub fn nonmax_suppression(mut self, value: bool) -> Self {
+        self.nonmax_suppression = Some(value);
+        self
+    }
+
+    /// Replace current detector_type with specified value
+    pub fn detector_type(mut self, value: AgastDetectorType) -> Self {
+        self.detector_type = Some(value);
+        self
+    }
+}
+
+impl Into<AgastFeatureDetector> for AgastFeatureDetectorBuilder {
+    fn into(self) -> AgastFeatureDetector {
+        AgastFeatureDetector::new(
+            self.threshold.unwrap_or(10),
+            self.nonmax_suppression.unwrap_or(true),
+            self.detector_type.unwrap_or(AgastDetectorType::Oast9_16),
+        )
+    }
+}
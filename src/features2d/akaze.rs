@@ -0,0 +1,135 @@
+//! Provide the type that encapsulates all the parameters of the AKAZE extraction algorithm
+use super::private::*;
+use super::KAZEDiffusivity;
+use std::os::raw::*;
+
+extern "C" {
+    fn cv_akaze_new(
+        descriptor_type: AKAZEDescriptorType,
+        descriptor_size: c_int,
+        descriptor_channels: c_int,
+        threshold: c_float,
+        octaves: c_int,
+        octave_layers: c_int,
+        diffusivity: KAZEDiffusivity,
+    ) -> *mut CFeature2D;
+}
+
+/// Type of the descriptor extracted by AKAZE.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum AKAZEDescriptorType {
+    /// Upright (not rotation invariant) KAZE float descriptor
+    KazeUpright = 2,
+    /// KAZE float descriptor
+    Kaze = 3,
+    /// Upright (not rotation invariant) binary descriptor
+    MldbUpright = 4,
+    /// Modified-Local Difference Binary descriptor
+    Mldb = 5,
+}
+
+/// Accelerated KAZE features detector and descriptor extractor.
+#[derive(Debug)]
+pub struct AKAZE {
+    value: *mut CFeature2D,
+}
+
+impl AKAZE {
+    /// Creates a new AKAZE detector and descriptor extractor.
+    pub fn new(
+        descriptor_type: AKAZEDescriptorType,
+        descriptor_size: c_int,
+        descriptor_channels: c_int,
+        threshold: f32,
+        octaves: c_int,
+        octave_layers: c_int,
+        diffusivity: KAZEDiffusivity,
+    ) -> Self {
+        let akaze = unsafe {
+            cv_akaze_new(
+                descriptor_type,
+                descriptor_size,
+                descriptor_channels,
+                threshold,
+                octaves,
+                octave_layers,
+                diffusivity,
+            )
+        };
+        AKAZE { value: akaze }
+    }
+}
+
+impl_feature2d!(AKAZE);
+
+/// Builder that provides defaults for AKAZE
+#[derive(Debug, Copy, Clone, Default)]
+pub struct AKAZEBuilder {
+    descriptor_type: Option<AKAZEDescriptorType>,
+    descriptor_size: Option<c_int>,
+    descriptor_channels: Option<c_int>,
+    threshold: Option<f32>,
+    octaves: Option<c_int>,
+    octave_layers: Option<c_int>,
+    diffusivity: Option<KAZEDiffusivity>,
+}
+
+impl AKAZEBuilder {
+    /// Replace current descriptor_type with specified value
+    pub fn descriptor_type(mut self, value: AKAZEDescriptorType) -> Self {
+        self.descriptor_type = Some(value);
+        self
+    }
+
+    /// Replace current descriptor_size with specified value (0 for the full
+    /// size)
+    pub fn descriptor_size(mut self, value: c_int) -> Self {
+        self.descriptor_size = Some(value);
+        self
+    }
+
+    /// Replace current descriptor_channels with specified value
+    pub fn descriptor_channels(mut self, value: c_int) -> Self {
+        self.descriptor_channels = Some(value);
+        self
+    }
+
+    /// Replace current threshold with specified value
+    pub fn threshold(mut self, value: f32) -> Self {
+        self.threshold = Some(value);
+        self
+    }
+
+    /// Replace current octaves with specified value
+    pub fn octaves(mut self, value: c_int) -> Self {
+        self.octaves = Some(value);
+        self
+    }
+
+    /// Replace current octave_layers with specified value
+    pub fn octave_layers(mut self, value: c_int) -> Self {
+        self.octave_layers = Some(value);
+        self
+    }
+
+    /// Replace current diffusivity with specified value
+    pub fn diffusivity(mut self, value: KAZEDiffusivity) -> Self {
+        self.diffusivity = Some(value);
+        self
+    }
+}
+
+impl Into<AKAZE> for AKAZEBuilder {
+    fn into(self) -> AKAZE {
+        AKAZE::new(
+            self.descriptor_type.unwrap_or(AKAZEDescriptorType::Mldb),
+            self.descriptor_size.unwrap_or(0),
+            self.descriptor_channels.unwrap_or(3),
+            self.threshold.unwrap_or(0.001),
+            self.octaves.unwrap_or(4),
+            self.octave_layers.unwrap_or(4),
+            self.diffusivity.unwrap_or(KAZEDiffusivity::PmG2),
+        )
+    }
+}
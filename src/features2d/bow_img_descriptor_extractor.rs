@@ -0,0 +1,162 @@
+//! Provide the type that computes image descriptors using the bag of visual words approach
+use super::private::*;
+use super::{CDescriptorMatcher, DescriptorMatcher, Feature2DImplInterface};
+use failure::Error;
+use std::os::raw::c_int;
+use std::path::Path;
+use *;
+
+enum CBOWImgDescriptorExtractor {}
+
+extern "C" {
+    fn cv_bow_extractor_new(
+        extractor: *mut CFeature2D,
+        matcher: *mut CDescriptorMatcher,
+    ) -> *mut CBOWImgDescriptorExtractor;
+    fn cv_bow_extractor_drop(extractor: *mut CBOWImgDescriptorExtractor);
+    fn cv_bow_extractor_set_vocabulary(extractor: *mut CBOWImgDescriptorExtractor, vocabulary: *const CMat);
+    fn cv_bow_extractor_get_vocabulary(extractor: *mut CBOWImgDescriptorExtractor, vocabulary: *mut CMat);
+    fn cv_bow_extractor_descriptor_size(extractor: *mut CBOWImgDescriptorExtractor) -> c_int;
+    fn cv_bow_extractor_descriptor_type(extractor: *mut CBOWImgDescriptorExtractor) -> c_int;
+    fn cv_bow_extractor_compute(
+        extractor: *mut CBOWImgDescriptorExtractor,
+        image: *const CMat,
+        provided_keypoints: *const CVecView<KeyPoint>,
+        keypoints: *mut CVec<KeyPoint>,
+        img_descriptor: *mut CMat,
+        point_idxs_of_clusters: *mut CVec<CVec<c_int>>,
+    ) -> CEmptyResult;
+    fn cv_bow_extractor_compute_descriptors(
+        extractor: *mut CBOWImgDescriptorExtractor,
+        keypoint_descriptors: *const CMat,
+        img_descriptor: *mut CMat,
+        point_idxs_of_clusters: *mut CVec<CVec<c_int>>,
+    ) -> CEmptyResult;
+}
+
+/// Section of the file storage used by
+/// [save_vocabulary](struct.BOWImgDescriptorExtractor.html#method.save_vocabulary)
+/// and [load_vocabulary](struct.BOWImgDescriptorExtractor.html#method.load_vocabulary).
+pub const BOW_VOCABULARY_SECTION: &str = "vocabulary";
+
+/// Computes an image descriptor using the bag of visual words: the keypoint
+/// descriptors of the image are matched against the vocabulary (usually the
+/// output of [BOWKMeansTrainer::cluster](struct.BOWKMeansTrainer.html#method.cluster)),
+/// and the image descriptor is the normalized histogram of the visual words.
+#[derive(Debug)]
+pub struct BOWImgDescriptorExtractor {
+    value: *mut CBOWImgDescriptorExtractor,
+}
+
+impl Drop for BOWImgDescriptorExtractor {
+    fn drop(&mut self) {
+        unsafe {
+            cv_bow_extractor_drop(self.value);
+        }
+    }
+}
+
+impl BOWImgDescriptorExtractor {
+    /// Creates a new extractor computing the keypoint descriptors with
+    /// `extractor` and finding the nearest visual word with `matcher`. Both
+    /// are shared with the extractor, so they can be dropped or reused
+    /// afterwards.
+    pub fn new<T: Feature2DImplInterface>(extractor: &T, matcher: &DescriptorMatcher) -> Self {
+        let value = unsafe { cv_bow_extractor_new(extractor.get_value(), matcher.value) };
+        Self { value }
+    }
+
+    /// Sets the visual vocabulary, one row per visual word
+    pub fn set_vocabulary(&mut self, vocabulary: &Mat) {
+        unsafe {
+            cv_bow_extractor_set_vocabulary(self.value, vocabulary.inner);
+        }
+    }
+
+    /// Returns the visual vocabulary, or an empty `Mat` if it is not set yet
+    pub fn get_vocabulary(&self) -> Mat {
+        let vocabulary = CMat::new();
+        unsafe {
+            cv_bow_extractor_get_vocabulary(self.value, vocabulary);
+        }
+        Mat::from_raw(vocabulary)
+    }
+
+    /// Saves the visual vocabulary to file storage under
+    /// [BOW_VOCABULARY_SECTION](constant.BOW_VOCABULARY_SECTION.html).
+    pub fn save_vocabulary<P: AsRef<Path>>(&self, path: P) -> Result<(), Error> {
+        self.get_vocabulary().to_file_storage(path, BOW_VOCABULARY_SECTION)
+    }
+
+    /// Loads the visual vocabulary saved by
+    /// [save_vocabulary](#method.save_vocabulary) and sets it.
+    pub fn load_vocabulary<P: AsRef<Path>>(&mut self, path: P) -> Result<(), Error> {
+        let vocabulary = Mat::from_file_storage(path.as_ref(), BOW_VOCABULARY_SECTION)?;
+        if vocabulary.rows == 0 {
+            return Err(CvError::InvalidArgument(format!("no vocabulary found in {}", path.as_ref().display())).into());
+        }
+        self.set_vocabulary(&vocabulary);
+        Ok(())
+    }
+
+    /// Returns the size of the image descriptor, i.e. the number of visual
+    /// words, or 0 if the vocabulary is not set.
+    pub fn descriptor_size(&self) -> c_int {
+        unsafe { cv_bow_extractor_descriptor_size(self.value) }
+    }
+
+    /// Returns the type of the image descriptor (`Cv32FC1`), or `None` if
+    /// `CvType` has no matching variant
+    pub fn descriptor_type(&self) -> Option<CvType> {
+        CvType::from_raw(unsafe { cv_bow_extractor_descriptor_type(self.value) })
+    }
+
+    /// Computes the image descriptor of the given keypoints. `keypoints` is
+    /// updated like in [Feature2D::compute](trait.Feature2D.html#tymethod.compute).
+    pub fn compute(&self, image: &Mat, keypoints: &mut Vec<KeyPoint>) -> Result<Mat, Error> {
+        let (descriptor, _) = self.compute_with_clusters(image, keypoints)?;
+        Ok(descriptor)
+    }
+
+    /// Same as [compute](#method.compute), but also returns, for each visual
+    /// word, the indices of the keypoints assigned to it.
+    pub fn compute_with_clusters(
+        &self,
+        image: &Mat,
+        keypoints: &mut Vec<KeyPoint>,
+    ) -> Result<(Mat, Vec<Vec<c_int>>), Error> {
+        let provided_keypoints = CVecView::pack(keypoints);
+        let mut computed_keypoints = CVec::<KeyPoint>::default();
+        let mut point_idxs = CVec::<CVec<c_int>>::default();
+        let descriptor = CMat::new();
+        let result = unsafe {
+            cv_bow_extractor_compute(
+                self.value,
+                image.inner,
+                &provided_keypoints,
+                &mut computed_keypoints,
+                descriptor,
+                &mut point_idxs,
+            )
+        };
+        let descriptor = Mat::from_raw(descriptor);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        *keypoints = computed_keypoints.unpack();
+        Ok((descriptor, point_idxs.unpack()))
+    }
+
+    /// Computes the image descriptor from already computed keypoint
+    /// descriptors, one row per keypoint.
+    pub fn compute_from_descriptors(&self, keypoint_descriptors: &Mat) -> Result<Mat, Error> {
+        let mut point_idxs = CVec::<CVec<c_int>>::default();
+        let descriptor = CMat::new();
+        let result = unsafe {
+            cv_bow_extractor_compute_descriptors(self.value, keypoint_descriptors.inner, descriptor, &mut point_idxs)
+        };
+        let descriptor = Mat::from_raw(descriptor);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(descriptor)
+    }
+}
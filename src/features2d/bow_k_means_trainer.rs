@@ -1,4 +1,5 @@
 //! Provide types for matching keypoint descriptors
+use std::os::raw::c_int;
 use *;
 
 enum CBOWKMeansTrainer {}
@@ -13,6 +14,9 @@ extern "C" {
     fn cv_bow_trainer_drop(bow_trainer: *mut CBOWKMeansTrainer);
     fn cv_bow_trainer_add(bow_trainer: *mut CBOWKMeansTrainer, descriptors: *mut CMat);
     fn cv_bow_trainer_cluster(bow_trainer: *mut CBOWKMeansTrainer) -> *mut CMat;
+    fn cv_bow_trainer_descriptors_count(bow_trainer: *mut CBOWKMeansTrainer) -> c_int;
+    fn cv_bow_trainer_clear(bow_trainer: *mut CBOWKMeansTrainer);
+    fn cv_bow_trainer_get_descriptors(bow_trainer: *mut CBOWKMeansTrainer, descriptors: *mut CVec<*mut CMat>);
 }
 
 /// K-means - based class to train visual vocabulary using the bag of visual words approach
@@ -21,16 +25,6 @@ pub struct BOWKMeansTrainer {
     value: *mut CBOWKMeansTrainer,
 }
 
-/// k-Means centers
-#[repr(C)]
-#[derive(Debug, Copy, Clone)]
-pub enum KMeansCenters {
-    /// Select random initial centers in each attempt.
-    Random = 0,
-    /// Use kmeans++ center initialization by Arthur and Vassilvitskii (Arthur2007).
-    Pp = 2,
-}
-
 impl Drop for BOWKMeansTrainer {
     fn drop(&mut self) {
         unsafe {
@@ -58,4 +52,27 @@ impl BOWKMeansTrainer {
         let cmat = unsafe { cv_bow_trainer_cluster(self.value) };
         Mat::from_raw(cmat)
     }
+
+    /// Returns the total number of descriptors added so far, summed over all
+    /// calls to [add](#method.add).
+    pub fn descriptors_count(&self) -> c_int {
+        unsafe { cv_bow_trainer_descriptors_count(self.value) }
+    }
+
+    /// Returns the descriptors added so far, one `Mat` per call to
+    /// [add](#method.add).
+    pub fn get_descriptors(&self) -> Vec<Mat> {
+        let mut descriptors = CVec::<*mut CMat>::default();
+        unsafe {
+            cv_bow_trainer_get_descriptors(self.value, &mut descriptors);
+        }
+        descriptors.unpack().into_iter().map(Mat::from_raw).collect()
+    }
+
+    /// Removes all the descriptors from the training set
+    pub fn clear(&mut self) {
+        unsafe {
+            cv_bow_trainer_clear(self.value);
+        }
+    }
 }
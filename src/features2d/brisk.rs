@@ -0,0 +1,62 @@
+//! Provide the type that encapsulates all the parameters of the BRISK extraction algorithm
+use super::private::*;
+use std::os::raw::*;
+
+extern "C" {
+    fn cv_brisk_new(threshold: c_int, octaves: c_int, pattern_scale: c_float) -> *mut CFeature2D;
+}
+
+/// Binary Robust Invariant Scalable Keypoints detector and descriptor
+/// extractor.
+#[derive(Debug)]
+pub struct BRISK {
+    value: *mut CFeature2D,
+}
+
+impl BRISK {
+    /// Creates a new BRISK detector and descriptor extractor.
+    pub fn new(threshold: c_int, octaves: c_int, pattern_scale: f32) -> Self {
+        let brisk = unsafe { cv_brisk_new(threshold, octaves, pattern_scale) };
+        BRISK { value: brisk }
+    }
+}
+
+impl_feature2d!(BRISK);
+
+/// Builder that provides defaults for BRISK
+#[derive(Debug, Copy, Clone, Default)]
+pub struct BRISKBuilder {
+    threshold: Option<c_int>,
+    octaves: Option<c_int>,
+    pattern_scale: Option<f32>,
+}
+
+impl BRISKBuilder {
+    /// Replace current threshold with specified value
+    pub fn threshold(mut self, value: c_int) -> Self {
+        self.threshold = Some(value);
+        self
+    }
+
+    /// Replace current octaves with specified value
+    pub fn octaves(mut self, value: c_int) -> Self {
+        self.octaves = Some(value);
+        self
+    }
+
+    /// Replace current pattern_scale with specified value
+    pub fn pattern_scale(mut self, value: f32) -> Self {
+        self.pattern_scale = Some(value);
+        self
+    }
+}
+
+impl Into<BRISK> for BRISKBuilder {
+    fn into(self) -> BRISK {
+        BRISK::new(
+            self.threshold.unwrap_or(30),
+            self.octaves.unwrap_or(3),
+            self.pattern_scale.unwrap_or(1.0),
+        )
+    }
+}
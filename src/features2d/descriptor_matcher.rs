@@ -1,8 +1,9 @@
 //! Provide types for matching keypoint descriptors
-use std::os::raw::{c_char, c_int};
+use std::collections::HashMap;
+use std::os::raw::{c_char, c_float, c_int};
 use *;
 
-enum CDescriptorMatcher {}
+pub(crate) enum CDescriptorMatcher {}
 
 extern "C" {
     fn cv_matcher_new(descriptor_matcher_type: *const c_char) -> *mut CDescriptorMatcher;
@@ -27,16 +28,86 @@ extern "C" {
         k: c_int,
         matches: *mut CVec<CVec<DMatch>>,
     );
+    fn cv_matcher_new_flann(
+        index_type: c_int,
+        index_param0: c_int,
+        index_param1: c_int,
+        index_param2: c_int,
+        checks: c_int,
+        eps: c_float,
+        sorted: bool,
+    ) -> *mut CDescriptorMatcher;
+    fn cv_matcher_clear(descriptor_matcher: *mut CDescriptorMatcher);
+    fn cv_matcher_get_train_descriptors(descriptor_matcher: *mut CDescriptorMatcher, descriptors: *mut CVec<*mut CMat>);
+    fn cv_matcher_match_masked(
+        descriptor_matcher: *mut CDescriptorMatcher,
+        query_descriptors: *mut CMat,
+        masks: *const CVecView<*mut CMat>,
+        matches: *mut CVec<DMatch>,
+    );
+    fn cv_matcher_knn_match_masked(
+        descriptor_matcher: *mut CDescriptorMatcher,
+        query_descriptors: *mut CMat,
+        k: c_int,
+        masks: *const CVecView<*mut CMat>,
+        compact_result: bool,
+        matches: *mut CVec<CVec<DMatch>>,
+    );
+    fn cv_matcher_radius_match(
+        descriptor_matcher: *mut CDescriptorMatcher,
+        query_descriptors: *mut CMat,
+        max_distance: c_float,
+        masks: *const CVecView<*mut CMat>,
+        compact_result: bool,
+        matches: *mut CVec<CVec<DMatch>>,
+    );
 }
 
 /// Type for matching keypoint descriptors
 #[repr(C)]
 #[derive(Default, Debug, Clone, Copy)]
 pub struct DMatch {
-    distance: f32,
-    img_idx: i32,
-    query_idx: i32,
-    train_idx: i32,
+    /// Distance between the descriptors, lower is better
+    pub distance: f32,
+    /// Index of the train image (for matchers trained on several images)
+    pub img_idx: i32,
+    /// Index of the query descriptor
+    pub query_idx: i32,
+    /// Index of the train descriptor
+    pub train_idx: i32,
+}
+
+impl DMatch {
+    /// Creates a new match between a query and a train descriptor.
+    pub fn new(query_idx: i32, train_idx: i32, img_idx: i32, distance: f32) -> Self {
+        DMatch {
+            distance: distance,
+            img_idx: img_idx,
+            query_idx: query_idx,
+            train_idx: train_idx,
+        }
+    }
+
+    /// Returns the coordinates of the matched query and train keypoints, in
+    /// the order of `matches`, e.g. to estimate a homography with
+    /// [find_homography](../calib3d/fn.find_homography.html).
+    ///
+    /// Panics if an index of a match is out of bounds of the keypoints.
+    pub fn matched_points(
+        matches: &[DMatch],
+        query_keypoints: &[KeyPoint],
+        train_keypoints: &[KeyPoint],
+    ) -> (Vec<Point2f>, Vec<Point2f>) {
+        matches
+            .iter()
+            .map(|m| {
+                (
+                    query_keypoints[m.query_idx as usize].point,
+                    train_keypoints[m.train_idx as usize].point,
+                )
+            })
+            .unzip()
+    }
 }
 
 /// Descriptor matcher type
@@ -62,10 +133,99 @@ impl DescriptorMatcherType {
     }
 }
 
+/// Index built by a FLANN based matcher.
+#[derive(Debug, Clone, Copy)]
+pub enum FlannIndexParams {
+    /// Brute force search
+    Linear,
+    /// Randomized kd-trees searched in parallel, for float descriptors
+    KdTree {
+        /// Number of parallel kd-trees, usually between 1 and 16
+        trees: c_int,
+    },
+    /// Locality-sensitive hashing, for binary descriptors (ORB, BRISK, ...)
+    Lsh {
+        /// Number of hash tables, usually between 10 and 30
+        table_number: c_int,
+        /// Size of the hash key in bits, usually between 10 and 20
+        key_size: c_int,
+        /// Number of bits to shift to check neighboring buckets, 0 for
+        /// regular LSH, usually 2
+        multi_probe_level: c_int,
+    },
+}
+
+impl Default for FlannIndexParams {
+    fn default() -> Self {
+        FlannIndexParams::KdTree { trees: 4 }
+    }
+}
+
+/// Search parameters of a FLANN based matcher.
+#[derive(Debug, Clone, Copy)]
+pub struct FlannSearchParams {
+    /// Number of times the trees are recursively traversed, higher values
+    /// give better precision but take more time
+    pub checks: c_int,
+    /// Search for eps-approximate neighbors
+    pub eps: f32,
+    /// Sorts the results of radius searches by distance
+    pub sorted: bool,
+}
+
+impl Default for FlannSearchParams {
+    fn default() -> Self {
+        FlannSearchParams {
+            checks: 32,
+            eps: 0.0,
+            sorted: true,
+        }
+    }
+}
+
+/// Keeps the best match of each query descriptor when it is clearly better
+/// than the second best one, i.e. when `best.distance < ratio * second.distance`
+/// (Lowe's ratio test, 0.7 to 0.8 is usually a good `ratio`).
+///
+/// `knn_matches` are the results of
+/// [knn_match](struct.DescriptorMatcher.html#method.knn_match) with `k >= 2`.
+/// Query descriptors with a single match are kept, those without a match are
+/// skipped.
+pub fn ratio_test(knn_matches: &[Vec<DMatch>], ratio: f32) -> Vec<DMatch> {
+    knn_matches
+        .iter()
+        .filter_map(|m| match m.len() {
+            0 => None,
+            1 => Some(m[0]),
+            _ if m[0].distance < ratio * m[1].distance => Some(m[0]),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Keeps the matches that are the best in both directions: `forward` are the
+/// matches from the query to the train descriptors and `backward` the matches
+/// from the train to the query descriptors, as returned by
+/// [match_two](struct.DescriptorMatcher.html#method.match_two).
+///
+/// When the forward matches come from several train images, the backward
+/// matches of the descriptors of train image `i` must have `img_idx == i`.
+pub fn cross_check(forward: &[DMatch], backward: &[DMatch]) -> Vec<DMatch> {
+    let mut best = HashMap::with_capacity(backward.len());
+    for b in backward {
+        best.entry((b.img_idx, b.query_idx)).or_insert(b.train_idx);
+    }
+    forward
+        .iter()
+        .filter(|m| best.get(&(m.img_idx, m.train_idx)) == Some(&m.query_idx))
+        .cloned()
+        .collect()
+}
+
 /// Type for matching keypoint descriptors
 #[derive(Debug)]
 pub struct DescriptorMatcher {
-    value: *mut CDescriptorMatcher,
+    pub(crate) value: *mut CDescriptorMatcher,
 }
 
 impl Drop for DescriptorMatcher {
@@ -84,6 +244,32 @@ impl DescriptorMatcher {
         DescriptorMatcher { value: value }
     }
 
+    /// Creates a FLANN based matcher with the specified index and search
+    /// parameters. Use `FlannIndexParams::Lsh` to match binary descriptors.
+    pub fn flann(index_params: FlannIndexParams, search_params: FlannSearchParams) -> DescriptorMatcher {
+        let (index_type, p0, p1, p2) = match index_params {
+            FlannIndexParams::Linear => (0, 0, 0, 0),
+            FlannIndexParams::KdTree { trees } => (1, trees, 0, 0),
+            FlannIndexParams::Lsh {
+                table_number,
+                key_size,
+                multi_probe_level,
+            } => (2, table_number, key_size, multi_probe_level),
+        };
+        let value = unsafe {
+            cv_matcher_new_flann(
+                index_type,
+                p0,
+                p1,
+                p2,
+                search_params.checks,
+                search_params.eps,
+                search_params.sorted,
+            )
+        };
+        DescriptorMatcher { value: value }
+    }
+
     /// Adds descriptors to train a CPU or GPU descriptor collection
     pub fn add(&mut self, descriptors: &Vec<&Mat>) {
         let descriptors = descriptors.iter().map(|x| x.inner).collect();
@@ -135,4 +321,104 @@ impl DescriptorMatcher {
         }
         matches.unpack()
     }
+
+    /// Clears the train descriptors collection
+    pub fn clear(&mut self) {
+        unsafe { cv_matcher_clear(self.value) }
+    }
+
+    /// Returns the train descriptors collection
+    pub fn get_train_descriptors(&self) -> Vec<Mat> {
+        let mut descriptors = CVec::<*mut CMat>::default();
+        unsafe {
+            cv_matcher_get_train_descriptors(self.value, &mut descriptors);
+        }
+        descriptors.unpack().into_iter().map(Mat::from_raw).collect()
+    }
+
+    /// Same as [match_](#method.match_), but only matches the train
+    /// descriptors allowed by `masks`. There is one mask per train image,
+    /// with one row per query descriptor and one column per train descriptor.
+    pub fn match_masked(&self, query_descriptors: &Mat, masks: &[&Mat]) -> Vec<DMatch> {
+        let masks = masks.iter().map(|x| x.inner).collect();
+        let masks = CVecView::pack(&masks);
+        let mut matches = CVec::<DMatch>::default();
+        unsafe {
+            cv_matcher_match_masked(self.value, query_descriptors.inner, &masks, &mut matches);
+        }
+        matches.unpack()
+    }
+
+    /// Same as [knn_match](#method.knn_match), but only matches the train
+    /// descriptors allowed by `masks` (see [match_masked](#method.match_masked)).
+    /// If `compact_result` is true, the query descriptors with no allowed
+    /// match are omitted from the result.
+    pub fn knn_match_masked(
+        &self,
+        query_descriptors: &Mat,
+        k: usize,
+        masks: &[&Mat],
+        compact_result: bool,
+    ) -> Vec<Vec<DMatch>> {
+        let masks = masks.iter().map(|x| x.inner).collect();
+        let masks = CVecView::pack(&masks);
+        let mut matches = CVec::<CVec<DMatch>>::default();
+        unsafe {
+            cv_matcher_knn_match_masked(
+                self.value,
+                query_descriptors.inner,
+                k as c_int,
+                &masks,
+                compact_result,
+                &mut matches,
+            );
+        }
+        matches.unpack()
+    }
+
+    /// Finds, for each descriptor from a query set, all the train descriptors
+    /// closer than `max_distance`.
+    pub fn radius_match(&self, query_descriptors: &Mat, max_distance: f32) -> Vec<Vec<DMatch>> {
+        self.radius_match_masked(query_descriptors, max_distance, &[], false)
+    }
+
+    /// Same as [radius_match](#method.radius_match), but only matches the
+    /// train descriptors allowed by `masks` (see
+    /// [match_masked](#method.match_masked)).
+    pub fn radius_match_masked(
+        &self,
+        query_descriptors: &Mat,
+        max_distance: f32,
+        masks: &[&Mat],
+        compact_result: bool,
+    ) -> Vec<Vec<DMatch>> {
+        let masks = masks.iter().map(|x| x.inner).collect();
+        let masks = CVecView::pack(&masks);
+        let mut matches = CVec::<CVec<DMatch>>::default();
+        unsafe {
+            cv_matcher_radius_match(
+                self.value,
+                query_descriptors.inner,
+                max_distance,
+                &masks,
+                compact_result,
+                &mut matches,
+            );
+        }
+        matches.unpack()
+    }
+
+    /// Matches a query set with the train descriptors collection and keeps
+    /// the matches passing the [ratio_test](fn.ratio_test.html).
+    pub fn ratio_test_match(&self, query_descriptors: &Mat, ratio: f32) -> Vec<DMatch> {
+        ratio_test(&self.knn_match(query_descriptors, 2), ratio)
+    }
+
+    /// Matches two sets of descriptors in both directions and keeps the
+    /// matches passing the [cross_check](fn.cross_check.html).
+    pub fn cross_check_match(&self, query_descriptors: &Mat, train_descriptors: &Mat) -> Vec<DMatch> {
+        let forward = self.match_two(query_descriptors, train_descriptors);
+        let backward = self.match_two(train_descriptors, query_descriptors);
+        cross_check(&forward, &backward)
+    }
 }
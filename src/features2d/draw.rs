@@ -0,0 +1,185 @@
+//! Provide functions to visualize keypoints and matches
+use super::DMatch;
+use core::*;
+use errors::*;
+use failure::Error;
+use std::os::raw::*;
+use *;
+
+extern "C" {
+    fn cv_draw_keypoints(
+        image: *const CMat,
+        keypoints: *const CVecView<KeyPoint>,
+        out_image: *mut CMat,
+        color: Scalar,
+        flags: c_int,
+    ) -> CEmptyResult;
+    fn cv_draw_matches(
+        img1: *const CMat,
+        keypoints1: *const CVecView<KeyPoint>,
+        img2: *const CMat,
+        keypoints2: *const CVecView<KeyPoint>,
+        matches: *const CVecView<DMatch>,
+        out_image: *mut CMat,
+        match_color: Scalar,
+        single_point_color: Scalar,
+        matches_mask: *const CVecView<c_char>,
+        flags: c_int,
+    ) -> CEmptyResult;
+    fn cv_draw_matches_knn(
+        img1: *const CMat,
+        keypoints1: *const CVecView<KeyPoint>,
+        img2: *const CMat,
+        keypoints2: *const CVecView<KeyPoint>,
+        matches: *const CVecView<CVecView<DMatch>>,
+        out_image: *mut CMat,
+        match_color: Scalar,
+        single_point_color: Scalar,
+        matches_mask: *const CVecView<CVecView<c_char>>,
+        flags: c_int,
+    ) -> CEmptyResult;
+}
+
+/// Options of the drawing functions of `features2d`.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct DrawMatchesFlags {
+    /// Doesn't draw the keypoints that are not matched
+    pub not_draw_single_points: bool,
+    /// Draws a circle of the keypoint size with its orientation around each
+    /// keypoint, instead of a small circle at its location
+    pub draw_rich_keypoints: bool,
+}
+
+impl DrawMatchesFlags {
+    fn bits(&self) -> c_int {
+        let mut flags = 0;
+        if self.not_draw_single_points {
+            flags |= 2;
+        }
+        if self.draw_rich_keypoints {
+            flags |= 4;
+        }
+        flags
+    }
+}
+
+const DRAW_OVER_OUTIMG: c_int = 1;
+
+/// Draws keypoints on a copy of `image` (converted to BGR if needed).
+///
+/// Use `Scalar::all(-1)` to draw each keypoint with a different color.
+pub fn draw_keypoints(
+    image: &Mat,
+    keypoints: &[KeyPoint],
+    color: Scalar,
+    flags: DrawMatchesFlags,
+) -> Result<Mat, Error> {
+    let keypoints = CVecView::pack(&keypoints.to_vec());
+    let out = CMat::new();
+    let result = unsafe { cv_draw_keypoints(image.inner, &keypoints, out, color, flags.bits()) };
+    let out = Mat::from_raw(out);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(out)
+}
+
+/// Draws keypoints directly on `image`, which must be a BGR image.
+pub fn draw_keypoints_over(
+    image: &mut Mat,
+    keypoints: &[KeyPoint],
+    color: Scalar,
+    flags: DrawMatchesFlags,
+) -> Result<(), Error> {
+    let keypoints = CVecView::pack(&keypoints.to_vec());
+    let flags = flags.bits() | DRAW_OVER_OUTIMG;
+    let result = unsafe { cv_draw_keypoints(image.inner, &keypoints, image.inner, color, flags) };
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(())
+}
+
+/// Draws the matches of keypoints from two images, side by side, with lines
+/// connecting the matched keypoints.
+///
+/// `matches` index `keypoints1` with `query_idx` and `keypoints2` with
+/// `train_idx`. `matches_mask`, if not empty, selects the matches to draw and
+/// must have the same length as `matches`. Use `Scalar::all(-1)` for random
+/// colors.
+pub fn draw_matches(
+    img1: &Mat,
+    keypoints1: &[KeyPoint],
+    img2: &Mat,
+    keypoints2: &[KeyPoint],
+    matches: &[DMatch],
+    match_color: Scalar,
+    single_point_color: Scalar,
+    matches_mask: &[bool],
+    flags: DrawMatchesFlags,
+) -> Result<Mat, Error> {
+    let keypoints1 = CVecView::pack(&keypoints1.to_vec());
+    let keypoints2 = CVecView::pack(&keypoints2.to_vec());
+    let matches = CVecView::pack(&matches.to_vec());
+    let matches_mask = CVecView::pack(&matches_mask.iter().map(|&m| m as c_char).collect());
+    let out = CMat::new();
+    let result = unsafe {
+        cv_draw_matches(
+            img1.inner,
+            &keypoints1,
+            img2.inner,
+            &keypoints2,
+            &matches,
+            out,
+            match_color,
+            single_point_color,
+            &matches_mask,
+            flags.bits(),
+        )
+    };
+    let out = Mat::from_raw(out);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(out)
+}
+
+/// Same as [draw_matches](fn.draw_matches.html), for the results of
+/// [DescriptorMatcher::knn_match](struct.DescriptorMatcher.html#method.knn_match).
+/// `matches_mask`, if not empty, must have the same shape as `matches`.
+pub fn draw_matches_knn(
+    img1: &Mat,
+    keypoints1: &[KeyPoint],
+    img2: &Mat,
+    keypoints2: &[KeyPoint],
+    matches: &[Vec<DMatch>],
+    match_color: Scalar,
+    single_point_color: Scalar,
+    matches_mask: &[Vec<bool>],
+    flags: DrawMatchesFlags,
+) -> Result<Mat, Error> {
+    let keypoints1 = CVecView::pack(&keypoints1.to_vec());
+    let keypoints2 = CVecView::pack(&keypoints2.to_vec());
+    let matches = CVecView::pack(&matches.to_vec());
+    let matches_mask = matches_mask
+        .iter()
+        .map(|row| row.iter().map(|&m| m as c_char).collect())
+        .collect();
+    let matches_mask = CVecView::pack(&matches_mask);
+    let out = CMat::new();
+    let result = unsafe {
+        cv_draw_matches_knn(
+            img1.inner,
+            &keypoints1,
+            img2.inner,
+            &keypoints2,
+            &matches,
+            out,
+            match_color,
+            single_point_color,
+            &matches_mask,
+            flags.bits(),
+        )
+    };
+    let out = Mat::from_raw(out);
+    let result: Result<(), String> = result.into();
+    result.map_err(CvError::UnknownError)?;
+    Ok(out)
+}
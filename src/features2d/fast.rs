@@ -0,0 +1,77 @@
+//! Provide the type that encapsulates all the parameters of the FAST detection algorithm
+use super::private::*;
+use std::os::raw::*;
+
+extern "C" {
+    fn cv_fast_new(threshold: c_int, nonmax_suppression: bool, detector_type: FastDetectorType) -> *mut CFeature2D;
+}
+
+/// Neighborhood used by the FAST detector, as `pixels_needed_contiguous_circle`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[allow(non_camel_case_types)]
+pub enum FastDetectorType {
+    /// 5 out of 8 pixels
+    Type5_8 = 0,
+    /// 7 out of 12 pixels
+    Type7_12 = 1,
+    /// 9 out of 16 pixels
+    Type9_16 = 2,
+}
+
+/// Features from accelerated segment test corner detector. It only detects
+/// keypoints, the descriptors computed by
+/// [detect_and_compute](trait.Feature2D.html#tymethod.detect_and_compute)
+/// are empty.
+#[derive(Debug)]
+pub struct FastFeatureDetector {
+    value: *mut CFeature2D,
+}
+
+impl FastFeatureDetector {
+    /// Creates a new FAST detector.
+    pub fn new(threshold: c_int, nonmax_suppression: bool, detector_type: FastDetectorType) -> Self {
+        let fast = unsafe { cv_fast_new(threshold, nonmax_suppression, detector_type) };
+        FastFeatureDetector { value: fast }
+    }
+}
+
+impl_feature2d!(FastFeatureDetector);
+
+/// Builder that provides defaults for FastFeatureDetector
+#[derive(Debug, Copy, Clone, Default)]
+pub struct FastFeatureDetectorBuilder {
+    threshold: Option<c_int>,
+    nonmax_suppression: Option<bool>,
+    detector_type: Option<FastDetectorType>,
+}
+
+impl FastFeatureDetectorBuilder {
+    /// Replace current threshold with specified value
+    pub fn threshold(mut self, value: c_int) -> Self {
+        self.threshold = Some(value);
+        self
+    }
+
+    /// Replace current nonmax_suppression with specified value
+    pub fn nonmax_suppression(mut self, value: bool) -> Self {
+        self.nonmax_suppression = Some(value);
+        self
+    }
+
+    /// Replace current detector_type with specified value
+    pub fn detector_type(mut self, value: FastDetectorType) -> Self {
+        self.detector_type = Some(value);
+        self
+    }
+}
+
+impl Into<FastFeatureDetector> for FastFeatureDetectorBuilder {
+    fn into(self) -> FastFeatureDetector {
+        FastFeatureDetector::new(
+            self.threshold.unwrap_or(10),
+            self.nonmax_suppression.unwrap_or(true),
+            self.detector_type.unwrap_or(FastDetectorType::Type9_16),
+        )
+    }
+}
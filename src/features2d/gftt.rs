@@ -0,0 +1,112 @@
+//! Provide the type that encapsulates all the parameters of the "good features to track" detector
+use super::private::*;
+use std::os::raw::*;
+
+extern "C" {
+    fn cv_gftt_new(
+        max_corners: c_int,
+        quality_level: c_double,
+        min_distance: c_double,
+        block_size: c_int,
+        use_harris_detector: bool,
+        k: c_double,
+    ) -> *mut CFeature2D;
+}
+
+/// Corner detector based on
+/// [Mat::good_features_to_track](../struct.Mat.html#method.good_features_to_track).
+/// It only detects keypoints, the descriptors computed by
+/// [detect_and_compute](trait.Feature2D.html#tymethod.detect_and_compute)
+/// are empty.
+#[derive(Debug)]
+pub struct GFTTDetector {
+    value: *mut CFeature2D,
+}
+
+impl GFTTDetector {
+    /// Creates a new GFTT detector.
+    pub fn new(
+        max_corners: c_int,
+        quality_level: f64,
+        min_distance: f64,
+        block_size: c_int,
+        use_harris_detector: bool,
+        k: f64,
+    ) -> Self {
+        let gftt = unsafe {
+            cv_gftt_new(
+                max_corners,
+                quality_level,
+                min_distance,
+                block_size,
+                use_harris_detector,
+                k,
+            )
+        };
+        GFTTDetector { value: gftt }
+    }
+}
+
+impl_feature2d!(GFTTDetector);
+
+/// Builder that provides defaults for GFTTDetector
+#[derive(Debug, Copy, Clone, Default)]
+pub struct GFTTDetectorBuilder {
+    max_corners: Option<c_int>,
+    quality_level: Option<f64>,
+    min_distance: Option<f64>,
+    block_size: Option<c_int>,
+    use_harris_detector: Option<bool>,
+    k: Option<f64>,
+}
+
+impl GFTTDetectorBuilder {
+    /// Replace current max_corners with specified value
+    pub fn max_corners(mut self, value: c_int) -> Self {
+        self.max_corners = Some(value);
+        self
+    }
+
+    /// Replace current quality_level with specified value
+    pub fn quality_level(mut self, value: f64) -> Self {
+        self.quality_level = Some(value);
+        self
+    }
+
+    /// Replace current min_distance with specified value
+    pub fn min_distance(mut self, value: f64) -> Self {
+        self.min_distance = Some(value);
+        self
+    }
+
+    /// Replace current block_size with specified value
+    pub fn block_size(mut self, value: c_int) -> Self {
+        self.block_size = Some(value);
+        self
+    }
+
+    /// Replace current use_harris_detector with specified value
+    pub fn use_harris_detector(mut self, value: bool) -> Self {
+        self.use_harris_detector = Some(value);
+        self
+    }
+
+    /// Replace current k with specified value
+    pub fn k(mut self, value: f64) -> Self {
+        self.k = Some(value);
+        self
+    }
+}
+
+impl Into<GFTTDetector> for GFTTDetectorBuilder {
+    fn into(self) -> GFTTDetector {
+        GFTTDetector::new(
+            self.max_corners.unwrap_or(1000),
+            self.quality_level.unwrap_or(0.01),
+            self.min_distance.unwrap_or(1.0),
+            self.block_size.unwrap_or(3),
+            self.use_harris_detector.unwrap_or(false),
+            self.k.unwrap_or(0.04),
+        )
+    }
+}
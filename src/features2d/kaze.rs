@@ -0,0 +1,114 @@
+//! Provide the type that encapsulates all the parameters of the KAZE extraction algorithm
+use super::private::*;
+use std::os::raw::*;
+
+extern "C" {
+    fn cv_kaze_new(
+        extended: bool,
+        upright: bool,
+        threshold: c_float,
+        octaves: c_int,
+        octave_layers: c_int,
+        diffusivity: KAZEDiffusivity,
+    ) -> *mut CFeature2D;
+}
+
+/// Conductivity function used by the nonlinear diffusion of KAZE and AKAZE.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum KAZEDiffusivity {
+    /// Perona-Malik, favours high-contrast edges
+    PmG1 = 0,
+    /// Perona-Malik, favours wide regions
+    PmG2 = 1,
+    /// Weickert, smoothes inside regions more than across them
+    Weickert = 2,
+    /// Charbonnier
+    Charbonnier = 3,
+}
+
+/// KAZE features detector and descriptor extractor, working in a nonlinear
+/// scale space.
+#[derive(Debug)]
+pub struct KAZE {
+    value: *mut CFeature2D,
+}
+
+impl KAZE {
+    /// Creates a new KAZE detector and descriptor extractor.
+    pub fn new(
+        extended: bool,
+        upright: bool,
+        threshold: f32,
+        octaves: c_int,
+        octave_layers: c_int,
+        diffusivity: KAZEDiffusivity,
+    ) -> Self {
+        let kaze = unsafe { cv_kaze_new(extended, upright, threshold, octaves, octave_layers, diffusivity) };
+        KAZE { value: kaze }
+    }
+}
+
+impl_feature2d!(KAZE);
+
+/// Builder that provides defaults for KAZE
+#[derive(Debug, Copy, Clone, Default)]
+pub struct KAZEBuilder {
+    extended: Option<bool>,
+    upright: Option<bool>,
+    threshold: Option<f32>,
+    octaves: Option<c_int>,
+    octave_layers: Option<c_int>,
+    diffusivity: Option<KAZEDiffusivity>,
+}
+
+impl KAZEBuilder {
+    /// Replace current extended with specified value
+    pub fn extended(mut self, value: bool) -> Self {
+        self.extended = Some(value);
+        self
+    }
+
+    /// Replace current upright with specified value
+    pub fn upright(mut self, value: bool) -> Self {
+        self.upright = Some(value);
+        self
+    }
+
+    /// Replace current threshold with specified value
+    pub fn threshold(mut self, value: f32) -> Self {
+        self.threshold = Some(value);
+        self
+    }
+
+    /// Replace current octaves with specified value
+    pub fn octaves(mut self, value: c_int) -> Self {
+        self.octaves = Some(value);
+        self
+    }
+
+    /// Replace current octave_layers with specified value
+    pub fn octave_layers(mut self, value: c_int) -> Self {
+        self.octave_layers = Some(value);
+        self
+    }
+
+    /// Replace current diffusivity with specified value
+    pub fn diffusivity(mut self, value: KAZEDiffusivity) -> Self {
+        self.diffusivity = Some(value);
+        self
+    }
+}
+
+impl Into<KAZE> for KAZEBuilder {
+    fn into(self) -> KAZE {
+        KAZE::new(
+            self.extended.unwrap_or(false),
+            self.upright.unwrap_or(false),
+            self.threshold.unwrap_or(0.001),
+            self.octaves.unwrap_or(4),
+            self.octave_layers.unwrap_or(4),
+            self.diffusivity.unwrap_or(KAZEDiffusivity::PmG2),
+        )
+    }
+}
@@ -0,0 +1,98 @@
+//! Provide helpers to filter and compare keypoints
+use core::*;
+use std::f32;
+use std::os::raw::*;
+use *;
+
+extern "C" {
+    fn cv_keypoint_overlap(kp1: KeyPoint, kp2: KeyPoint) -> c_float;
+    fn cv_keypoints_filter_retain_best(
+        provided_keypoints: *const CVecView<KeyPoint>,
+        keypoints: *mut CVec<KeyPoint>,
+        n_points: c_int,
+    );
+    fn cv_keypoints_filter_remove_duplicated(
+        provided_keypoints: *const CVecView<KeyPoint>,
+        keypoints: *mut CVec<KeyPoint>,
+    );
+    fn cv_keypoints_filter_run_by_pixels_mask(
+        provided_keypoints: *const CVecView<KeyPoint>,
+        keypoints: *mut CVec<KeyPoint>,
+        mask: *const CMat,
+    );
+    fn cv_keypoints_filter_run_by_keypoint_size(
+        provided_keypoints: *const CVecView<KeyPoint>,
+        keypoints: *mut CVec<KeyPoint>,
+        min_size: c_float,
+        max_size: c_float,
+    );
+    fn cv_keypoints_filter_run_by_image_border(
+        provided_keypoints: *const CVecView<KeyPoint>,
+        keypoints: *mut CVec<KeyPoint>,
+        image_size: Size2i,
+        border_size: c_int,
+    );
+}
+
+impl KeyPoint {
+    /// Computes the overlap of the neighborhoods of two keypoints: the ratio
+    /// between the area of their intersection and the area of their union.
+    /// Returns 0 if they don't overlap and 1 if they coincide.
+    pub fn overlap(&self, other: &KeyPoint) -> f32 {
+        unsafe { cv_keypoint_overlap(*self, *other) }
+    }
+}
+
+/// Helpers to filter the keypoints returned by a detector.
+#[derive(Debug, Clone, Copy)]
+pub struct KeyPointsFilter;
+
+impl KeyPointsFilter {
+    /// Keeps the `n_points` keypoints with the strongest response. Keypoints
+    /// with the same response as the last retained one are kept as well.
+    pub fn retain_best(keypoints: &mut Vec<KeyPoint>, n_points: c_int) {
+        Self::run(keypoints, |p, k| unsafe {
+            cv_keypoints_filter_retain_best(p, k, n_points)
+        });
+    }
+
+    /// Removes the keypoints with the same coordinates and size.
+    pub fn remove_duplicated(keypoints: &mut Vec<KeyPoint>) {
+        Self::run(keypoints, |p, k| unsafe { cv_keypoints_filter_remove_duplicated(p, k) });
+    }
+
+    /// Removes the keypoints located on a zero pixel of `mask`. An empty mask
+    /// keeps all the keypoints.
+    pub fn run_by_pixels_mask(keypoints: &mut Vec<KeyPoint>, mask: &Mat) {
+        Self::run(keypoints, |p, k| unsafe {
+            cv_keypoints_filter_run_by_pixels_mask(p, k, mask.inner)
+        });
+    }
+
+    /// Keeps the keypoints with a size in `[min_size, max_size]`. `max_size`
+    /// can be `None` for no upper bound.
+    pub fn run_by_keypoint_size(keypoints: &mut Vec<KeyPoint>, min_size: f32, max_size: Option<f32>) {
+        let max_size = max_size.unwrap_or(f32::MAX);
+        Self::run(keypoints, |p, k| unsafe {
+            cv_keypoints_filter_run_by_keypoint_size(p, k, min_size, max_size)
+        });
+    }
+
+    /// Removes the keypoints closer than `border_size` to the border of an
+    /// image of size `image_size`.
+    pub fn run_by_image_border(keypoints: &mut Vec<KeyPoint>, image_size: Size2i, border_size: c_int) {
+        Self::run(keypoints, |p, k| unsafe {
+            cv_keypoints_filter_run_by_image_border(p, k, image_size, border_size)
+        });
+    }
+
+    fn run<F>(keypoints: &mut Vec<KeyPoint>, filter: F)
+    where
+        F: FnOnce(*const CVecView<KeyPoint>, *mut CVec<KeyPoint>),
+    {
+        let provided_keypoints = CVecView::pack(keypoints);
+        let mut filtered = CVec::<KeyPoint>::default();
+        filter(&provided_keypoints, &mut filtered);
+        *keypoints = filtered.unpack();
+    }
+}
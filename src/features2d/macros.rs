@@ -0,0 +1,21 @@
+//! Macros shared by the detectors wrapping a `cv::Ptr<cv::Feature2D>`
+
+macro_rules! impl_feature2d {
+    ($x:ident) => {
+        impl Drop for $x {
+            fn drop(&mut self) {
+                unsafe {
+                    super::cv_feature2d_drop(self.value);
+                }
+            }
+        }
+
+        impl super::private::Feature2DImpl for $x {
+            fn get_value(&self) -> *mut super::private::CFeature2D {
+                self.value
+            }
+        }
+
+        impl super::Feature2DImplInterface for $x {}
+    };
+}
@@ -1,21 +1,199 @@
 //! Provide 2D image feature detectors and descriptor extractors
+#[macro_use]
+mod macros;
+mod agast;
+mod akaze;
+mod bow_img_descriptor_extractor;
 mod bow_k_means_trainer;
+mod brisk;
 mod descriptor_matcher;
+mod draw;
+mod fast;
+mod gftt;
+mod kaze;
+mod keypoints_filter;
 mod mser;
+mod orb;
 mod sift;
+mod simple_blob_detector;
 mod surf;
 
+pub use self::agast::*;
+pub use self::akaze::*;
+pub use self::bow_img_descriptor_extractor::*;
 pub use self::bow_k_means_trainer::*;
+pub use self::brisk::*;
 pub use self::descriptor_matcher::*;
+pub use self::draw::*;
+pub use self::fast::*;
+pub use self::gftt::*;
+pub use self::kaze::*;
+pub use self::keypoints_filter::*;
 pub use self::mser::*;
+pub use self::orb::*;
 pub use self::sift::*;
+pub use self::simple_blob_detector::*;
 pub use self::surf::*;
+pub use core::KMeansCenters;
 
+use self::private::*;
 use core::*;
 use mat::*;
+use std::os::raw::c_int;
+use *;
 
-/// Basic trait for 2D image feature detectors and descriptor extractors
+extern "C" {
+    fn cv_feature2d_drop(detector: *mut CFeature2D);
+    fn cv_feature2d_detect(
+        detector: *const CFeature2D,
+        image: *const CMat,
+        mask: *const CMat,
+        keypoints: *mut CVec<KeyPoint>,
+    );
+    fn cv_feature2d_compute(
+        detector: *const CFeature2D,
+        image: *const CMat,
+        provided_keypoints: *const CVecView<KeyPoint>,
+        keypoints: *mut CVec<KeyPoint>,
+        descriptors: *mut CMat,
+    );
+    fn cv_feature2d_detect_and_compute(
+        detector: *const CFeature2D,
+        image: *const CMat,
+        mask: *const CMat,
+        provided_keypoints: *const CVecView<KeyPoint>,
+        keypoints: *mut CVec<KeyPoint>,
+        descriptors: *mut CMat,
+        use_provided_keypoints: bool,
+    );
+    fn cv_feature2d_descriptor_size(detector: *const CFeature2D) -> c_int;
+    fn cv_feature2d_descriptor_type(detector: *const CFeature2D) -> c_int;
+    fn cv_feature2d_default_norm(detector: *const CFeature2D) -> c_int;
+}
+
+mod private {
+    #[allow(missing_copy_implementations, missing_debug_implementations)]
+    pub enum CFeature2D {}
+
+    pub trait Feature2DImpl {
+        fn get_value(&self) -> *mut CFeature2D;
+    }
+}
+
+#[allow(missing_docs)]
+pub trait Feature2DImplInterface: Feature2DImpl {}
+
+/// Basic trait for 2D image feature detectors and descriptor extractors.
+///
+/// For detectors that don't extract descriptors (e.g.
+/// `FastFeatureDetector`), the computed descriptors are empty and
+/// `descriptor_size` is 0.
 pub trait Feature2D {
+    /// Detects keypoints in an image. The `mask`, if not empty, selects the
+    /// region of interest.
+    fn detect(&self, image: &Mat, mask: &Mat) -> Vec<KeyPoint>;
+
+    /// Computes the descriptors of the given keypoints, one row per keypoint.
+    /// Keypoints for which a descriptor cannot be computed are removed and
+    /// new keypoints may be added (e.g. SIFT duplicates keypoints with several
+    /// dominant orientations), so `keypoints` always matches the rows of the
+    /// returned descriptors.
+    fn compute(&self, image: &Mat, keypoints: &mut Vec<KeyPoint>) -> Mat;
+
     /// Detects keypoints and computes the descriptors
     fn detect_and_compute(&self, image: &Mat, mask: &Mat) -> (Vec<KeyPoint>, Mat);
+
+    /// Same as [detect_and_compute](#tymethod.detect_and_compute), but
+    /// reuses the given keypoints instead of detecting new ones. `keypoints`
+    /// is updated like in [compute](#tymethod.compute).
+    fn detect_and_compute_with_keypoints(&self, image: &Mat, mask: &Mat, keypoints: &mut Vec<KeyPoint>) -> Mat;
+
+    /// Returns the size of a descriptor, in elements of `descriptor_type`.
+    fn descriptor_size(&self) -> c_int;
+
+    /// Returns the type of the descriptors (e.g. `Cv32FC1` for SIFT,
+    /// `Cv8UC1` for binary descriptors), or `None` if `CvType` has no
+    /// matching variant.
+    fn descriptor_type(&self) -> Option<CvType>;
+
+    /// Returns the norm to use when matching the descriptors (e.g. `L2` for
+    /// SIFT, `Hamming` for ORB), or `None` if `NormType` has no matching
+    /// variant.
+    fn default_norm(&self) -> Option<NormType>;
+}
+
+impl<T: Feature2DImplInterface> Feature2D for T {
+    fn detect(&self, image: &Mat, mask: &Mat) -> Vec<KeyPoint> {
+        let mut keypoints = CVec::<KeyPoint>::default();
+        unsafe {
+            cv_feature2d_detect(self.get_value(), image.inner, mask.inner, &mut keypoints);
+        }
+        keypoints.unpack()
+    }
+
+    fn compute(&self, image: &Mat, keypoints: &mut Vec<KeyPoint>) -> Mat {
+        let provided_keypoints = CVecView::pack(keypoints);
+        let mut computed_keypoints = CVec::<KeyPoint>::default();
+        let descriptors = CMat::new();
+        unsafe {
+            cv_feature2d_compute(
+                self.get_value(),
+                image.inner,
+                &provided_keypoints,
+                &mut computed_keypoints,
+                descriptors,
+            );
+        }
+        *keypoints = computed_keypoints.unpack();
+        Mat::from_raw(descriptors)
+    }
+
+    fn detect_and_compute(&self, image: &Mat, mask: &Mat) -> (Vec<KeyPoint>, Mat) {
+        let provided_keypoints = CVecView::pack(&Vec::new());
+        let mut keypoints = CVec::<KeyPoint>::default();
+        let descriptors = CMat::new();
+        unsafe {
+            cv_feature2d_detect_and_compute(
+                self.get_value(),
+                image.inner,
+                mask.inner,
+                &provided_keypoints,
+                &mut keypoints,
+                descriptors,
+                false,
+            );
+        }
+        (keypoints.unpack(), Mat::from_raw(descriptors))
+    }
+
+    fn detect_and_compute_with_keypoints(&self, image: &Mat, mask: &Mat, keypoints: &mut Vec<KeyPoint>) -> Mat {
+        let provided_keypoints = CVecView::pack(keypoints);
+        let mut computed_keypoints = CVec::<KeyPoint>::default();
+        let descriptors = CMat::new();
+        unsafe {
+            cv_feature2d_detect_and_compute(
+                self.get_value(),
+                image.inner,
+                mask.inner,
+                &provided_keypoints,
+                &mut computed_keypoints,
+                descriptors,
+                true,
+            );
+        }
+        *keypoints = computed_keypoints.unpack();
+        Mat::from_raw(descriptors)
+    }
+
+    fn descriptor_size(&self) -> c_int {
+        unsafe { cv_feature2d_descriptor_size(self.get_value()) }
+    }
+
+    fn descriptor_type(&self) -> Option<CvType> {
+        CvType::from_raw(unsafe { cv_feature2d_descriptor_type(self.get_value()) })
+    }
+
+    fn default_norm(&self) -> Option<NormType> {
+        NormType::from_raw(unsafe { cv_feature2d_default_norm(self.get_value()) })
+    }
 }
@@ -1,10 +1,9 @@
 //! Provide the type that encapsulates all the parameters of the MSER extraction algorithm
+use super::private::*;
 use core::*;
 use std::os::raw::*;
 use *;
 
-enum CMSER {}
-
 extern "C" {
     fn cv_mser_new(
         delta: c_int,
@@ -16,24 +15,38 @@ extern "C" {
         area_threshold: c_double,
         min_margin: c_double,
         edge_blur_size: c_int,
-    ) -> *mut CMSER;
-    fn cv_mser_drop(cmser: *mut CMSER);
+    ) -> *mut CFeature2D;
     fn cv_mser_detect_regions(
-        detector: *const CMSER,
+        detector: *const CFeature2D,
         image: *const CMat,
         msers: *mut CVec<CVec<Point2i>>,
         bboxes: *mut CVec<Rect>,
     );
+    fn cv_mser_get_delta(detector: *const CFeature2D) -> c_int;
+    fn cv_mser_set_delta(detector: *mut CFeature2D, delta: c_int);
+    fn cv_mser_get_min_area(detector: *const CFeature2D) -> c_int;
+    fn cv_mser_set_min_area(detector: *mut CFeature2D, min_area: c_int);
+    fn cv_mser_get_max_area(detector: *const CFeature2D) -> c_int;
+    fn cv_mser_set_max_area(detector: *mut CFeature2D, max_area: c_int);
+    fn cv_mser_get_pass2only(detector: *const CFeature2D) -> bool;
+    fn cv_mser_set_pass2only(detector: *mut CFeature2D, pass2only: bool);
 }
 
 /// Maximally stable extremal region extractor.
+///
+/// Grayscale images use the MSER algorithm, 3-channel images use the MSCR
+/// algorithm, which is tuned by `max_evolution`, `area_threshold`,
+/// `min_margin` and `edge_blur_size`. As a `Feature2D`, `MSER` only detects
+/// keypoints (one per region) and computes no descriptors.
 #[derive(Debug)]
 pub struct MSER {
-    value: *mut CMSER,
+    value: *mut CFeature2D,
 }
 
 impl MSER {
     /// Creates a new maximally stable extremal region extractor criteria.
+    /// Prefer [MSERBuilder](struct.MSERBuilder.html), which provides the
+    /// defaults and also sets [pass2only](#method.pass2only).
     pub fn new(
         delta: c_int,
         min_area: c_int,
@@ -61,7 +74,8 @@ impl MSER {
         MSER { value: mser }
     }
 
-    /// Detect MSER regions.
+    /// Detect MSER regions, returning the points and the bounding box of
+    /// each region.
     pub fn detect_regions(&self, image: &Mat) -> (Vec<Vec<Point2i>>, Vec<Rect>) {
         let mut msers = CVec::<CVec<Point2i>>::default();
         let mut bboxes = CVec::<Rect>::default();
@@ -72,16 +86,51 @@ impl MSER {
         let boxes = bboxes.unpack();
         (msers, boxes)
     }
-}
 
-impl Drop for MSER {
-    fn drop(&mut self) {
-        unsafe {
-            cv_mser_drop(self.value);
-        }
+    /// Returns the step between the intensity thresholds
+    pub fn delta(&self) -> c_int {
+        unsafe { cv_mser_get_delta(self.value) }
+    }
+
+    /// Sets the step between the intensity thresholds
+    pub fn set_delta(&mut self, delta: c_int) {
+        unsafe { cv_mser_set_delta(self.value, delta) }
+    }
+
+    /// Returns the minimal area of the detected regions
+    pub fn min_area(&self) -> c_int {
+        unsafe { cv_mser_get_min_area(self.value) }
+    }
+
+    /// Sets the minimal area of the detected regions
+    pub fn set_min_area(&mut self, min_area: c_int) {
+        unsafe { cv_mser_set_min_area(self.value, min_area) }
+    }
+
+    /// Returns the maximal area of the detected regions
+    pub fn max_area(&self) -> c_int {
+        unsafe { cv_mser_get_max_area(self.value) }
+    }
+
+    /// Sets the maximal area of the detected regions
+    pub fn set_max_area(&mut self, max_area: c_int) {
+        unsafe { cv_mser_set_max_area(self.value, max_area) }
+    }
+
+    /// Returns whether only the dark-on-bright regions are detected
+    pub fn pass2only(&self) -> bool {
+        unsafe { cv_mser_get_pass2only(self.value) }
+    }
+
+    /// Sets whether only the dark-on-bright regions are detected, skipping
+    /// the bright-on-dark pass on grayscale images.
+    pub fn set_pass2only(&mut self, pass2only: bool) {
+        unsafe { cv_mser_set_pass2only(self.value, pass2only) }
     }
 }
 
+impl_feature2d!(MSER);
+
 /// Builder that provides defaults for MSER
 #[derive(Debug, Copy, Clone, Default)]
 pub struct MSERBuilder {
@@ -94,6 +143,7 @@ pub struct MSERBuilder {
     area_threshold: Option<f64>,
     min_margin: Option<f64>,
     edge_blur_size: Option<c_int>,
+    pass2only: Option<bool>,
 }
 
 impl MSERBuilder {
@@ -150,11 +200,17 @@ impl MSERBuilder {
         self.edge_blur_size = Some(value);
         self
     }
+
+    /// Replace current pass2only with specified value
+    pub fn pass2only(mut self, value: bool) -> Self {
+        self.pass2only = Some(value);
+        self
+    }
 }
 
 impl Into<MSER> for MSERBuilder {
     fn into(self) -> MSER {
-        MSER::new(
+        let mut mser = MSER::new(
             self.delta.unwrap_or(5),
             self.min_area.unwrap_or(60),
             self.max_area.unwrap_or(14400),
@@ -164,6 +220,8 @@ impl Into<MSER> for MSERBuilder {
             self.area_threshold.unwrap_or(1.01),
             self.min_margin.unwrap_or(0.003),
             self.edge_blur_size.unwrap_or(5),
-        )
+        );
+        mser.set_pass2only(self.pass2only.unwrap_or(false));
+        mser
     }
 }
@@ -0,0 +1,151 @@
+//! Provide the type that encapsulates all the parameters of the ORB extraction algorithm
+use super::private::*;
+use std::os::raw::*;
+
+extern "C" {
+    fn cv_orb_new(
+        features: c_int,
+        scale_factor: c_float,
+        levels: c_int,
+        edge_threshold: c_int,
+        first_level: c_int,
+        wta_k: c_int,
+        score_type: ORBScoreType,
+        patch_size: c_int,
+        fast_threshold: c_int,
+    ) -> *mut CFeature2D;
+}
+
+/// Algorithm used to rank the detected features.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ORBScoreType {
+    /// Harris corner measure, slightly more stable
+    Harris = 0,
+    /// FAST score, slightly faster to compute
+    Fast = 1,
+}
+
+/// Oriented FAST and rotated BRIEF detector and binary descriptor extractor.
+#[derive(Debug)]
+pub struct ORB {
+    value: *mut CFeature2D,
+}
+
+impl ORB {
+    /// Creates a new ORB detector and descriptor extractor.
+    pub fn new(
+        features: c_int,
+        scale_factor: f32,
+        levels: c_int,
+        edge_threshold: c_int,
+        first_level: c_int,
+        wta_k: c_int,
+        score_type: ORBScoreType,
+        patch_size: c_int,
+        fast_threshold: c_int,
+    ) -> Self {
+        let orb = unsafe {
+            cv_orb_new(
+                features,
+                scale_factor,
+                levels,
+                edge_threshold,
+                first_level,
+                wta_k,
+                score_type,
+                patch_size,
+                fast_threshold,
+            )
+        };
+        ORB { value: orb }
+    }
+}
+
+impl_feature2d!(ORB);
+
+/// Builder that provides defaults for ORB
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ORBBuilder {
+    features: Option<c_int>,
+    scale_factor: Option<f32>,
+    levels: Option<c_int>,
+    edge_threshold: Option<c_int>,
+    first_level: Option<c_int>,
+    wta_k: Option<c_int>,
+    score_type: Option<ORBScoreType>,
+    patch_size: Option<c_int>,
+    fast_threshold: Option<c_int>,
+}
+
+impl ORBBuilder {
+    /// Replace current features with specified value
+    pub fn features(mut self, value: c_int) -> Self {
+        self.features = Some(value);
+        self
+    }
+
+    /// Replace current scale_factor with specified value
+    pub fn scale_factor(mut self, value: f32) -> Self {
+        self.scale_factor = Some(value);
+        self
+    }
+
+    /// Replace current levels with specified value
+    pub fn levels(mut self, value: c_int) -> Self {
+        self.levels = Some(value);
+        self
+    }
+
+    /// Replace current edge_threshold with specified value
+    pub fn edge_threshold(mut self, value: c_int) -> Self {
+        self.edge_threshold = Some(value);
+        self
+    }
+
+    /// Replace current first_level with specified value
+    pub fn first_level(mut self, value: c_int) -> Self {
+        self.first_level = Some(value);
+        self
+    }
+
+    /// Replace current wta_k with specified value
+    pub fn wta_k(mut self, value: c_int) -> Self {
+        self.wta_k = Some(value);
+        self
+    }
+
+    /// Replace current score_type with specified value
+    pub fn score_type(mut self, value: ORBScoreType) -> Self {
+        self.score_type = Some(value);
+        self
+    }
+
+    /// Replace current patch_size with specified value
+    pub fn patch_size(mut self, value: c_int) -> Self {
+        self.patch_size = Some(value);
+        self
+    }
+
+    /// Replace current fast_threshold with specified value
+    pub fn fast_threshold(mut self, value: c_int) -> Self {
+        self.fast_threshold = Some(value);
+        self
+    }
+}
+
+impl Into<ORB> for ORBBuilder {
+    fn into(self) -> ORB {
+        ORB::new(
+            self.features.unwrap_or(500),
+            self.scale_factor.unwrap_or(1.2),
+            self.levels.unwrap_or(8),
+            self.edge_threshold.unwrap_or(31),
+            self.first_level.unwrap_or(0),
+            self.wta_k.unwrap_or(2),
+            self.score_type.unwrap_or(ORBScoreType::Harris),
+            self.patch_size.unwrap_or(31),
+            self.fast_threshold.unwrap_or(20),
+        )
+    }
+}
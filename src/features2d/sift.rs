@@ -1,10 +1,6 @@
 //! Provide the type that encapsulates all the parameters of the SIFT extraction algorithm
-use super::*;
-use core::*;
+use super::private::*;
 use std::os::raw::*;
-use *;
-
-enum CSIFT {}
 
 extern "C" {
     fn cv_sift_new(
@@ -13,22 +9,13 @@ extern "C" {
         contrast_threshold: c_double,
         edge_threshold: c_double,
         sigma: c_double,
-    ) -> *mut CSIFT;
-    fn cv_sift_drop(cmser: *mut CSIFT);
-    fn cv_sift_detect_and_compute(
-        detector: *const CSIFT,
-        image: *const CMat,
-        mask: *const CMat,
-        keypoints: *mut CVec<KeyPoint>,
-        descriptors: *mut CMat,
-        use_provided_keypoints: bool,
-    );
+    ) -> *mut CFeature2D;
 }
 
 /// Speeded up robust features extractor.
 #[derive(Debug)]
 pub struct SIFT {
-    value: *mut CSIFT,
+    value: *mut CFeature2D,
 }
 
 impl SIFT {
@@ -45,13 +32,7 @@ impl SIFT {
     }
 }
 
-impl Drop for SIFT {
-    fn drop(&mut self) {
-        unsafe {
-            cv_sift_drop(self.value);
-        }
-    }
-}
+impl_feature2d!(SIFT);
 
 /// Builder that provides defaults for MSER
 #[derive(Debug, Copy, Clone, Default)]
@@ -106,14 +87,3 @@ impl Into<SIFT> for SIFTBuilder {
         )
     }
 }
-
-impl Feature2D for SIFT {
-    fn detect_and_compute(&self, image: &Mat, mask: &Mat) -> (Vec<KeyPoint>, Mat) {
-        let mut keypoints = CVec::<KeyPoint>::default();
-        let descriptors = CMat::new();
-        unsafe {
-            cv_sift_detect_and_compute(self.value, image.inner, mask.inner, &mut keypoints, descriptors, false);
-        }
-        (keypoints.unpack(), Mat::from_raw(descriptors))
-    }
-}
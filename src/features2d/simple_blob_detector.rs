@@ -0,0 +1,187 @@
+//! Provide the type that encapsulates all the parameters of the simple blob detection algorithm
+use super::private::*;
+use std::f32;
+
+extern "C" {
+    fn cv_simple_blob_detector_new(params: SimpleBlobDetectorParams) -> *mut CFeature2D;
+}
+
+/// Parameters of the [SimpleBlobDetector](struct.SimpleBlobDetector.html).
+///
+/// The image is thresholded at several levels from `min_threshold` to
+/// `max_threshold` (every `threshold_step`), the connected components of each
+/// binary image are grouped by center across the levels and the groups found
+/// in at least `min_repeatability` levels become blobs. The `filter_by_*`
+/// flags enable filtering the blobs by color, area, circularity, inertia
+/// ratio and convexity.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct SimpleBlobDetectorParams {
+    /// Step between two thresholds
+    pub threshold_step: f32,
+    /// First threshold
+    pub min_threshold: f32,
+    /// Last threshold (exclusive)
+    pub max_threshold: f32,
+    /// Minimum number of thresholds in which a blob is found
+    pub min_repeatability: usize,
+    /// Minimum distance between the centers of two blobs
+    pub min_dist_between_blobs: f32,
+    /// Keeps only the blobs of intensity `blob_color`
+    pub filter_by_color: bool,
+    /// Intensity of the blobs: 0 for dark blobs, 255 for light blobs
+    pub blob_color: u8,
+    /// Keeps only the blobs with an area in `[min_area, max_area)`
+    pub filter_by_area: bool,
+    /// Minimum area
+    pub min_area: f32,
+    /// Maximum area
+    pub max_area: f32,
+    /// Keeps only the blobs with a circularity in
+    /// `[min_circularity, max_circularity)`
+    pub filter_by_circularity: bool,
+    /// Minimum circularity
+    pub min_circularity: f32,
+    /// Maximum circularity
+    pub max_circularity: f32,
+    /// Keeps only the blobs with an inertia ratio in
+    /// `[min_inertia_ratio, max_inertia_ratio)`
+    pub filter_by_inertia: bool,
+    /// Minimum inertia ratio
+    pub min_inertia_ratio: f32,
+    /// Maximum inertia ratio
+    pub max_inertia_ratio: f32,
+    /// Keeps only the blobs with a convexity in
+    /// `[min_convexity, max_convexity)`
+    pub filter_by_convexity: bool,
+    /// Minimum convexity
+    pub min_convexity: f32,
+    /// Maximum convexity
+    pub max_convexity: f32,
+}
+
+impl Default for SimpleBlobDetectorParams {
+    fn default() -> Self {
+        SimpleBlobDetectorParams {
+            threshold_step: 10.0,
+            min_threshold: 50.0,
+            max_threshold: 220.0,
+            min_repeatability: 2,
+            min_dist_between_blobs: 10.0,
+            filter_by_color: true,
+            blob_color: 0,
+            filter_by_area: true,
+            min_area: 25.0,
+            max_area: 5000.0,
+            filter_by_circularity: false,
+            min_circularity: 0.8,
+            max_circularity: f32::MAX,
+            filter_by_inertia: true,
+            min_inertia_ratio: 0.1,
+            max_inertia_ratio: f32::MAX,
+            filter_by_convexity: true,
+            min_convexity: 0.95,
+            max_convexity: f32::MAX,
+        }
+    }
+}
+
+/// Extracts blobs from an image. It only detects keypoints, the descriptors
+/// computed by
+/// [detect_and_compute](trait.Feature2D.html#tymethod.detect_and_compute)
+/// are empty.
+#[derive(Debug)]
+pub struct SimpleBlobDetector {
+    value: *mut CFeature2D,
+}
+
+impl SimpleBlobDetector {
+    /// Creates a new blob detector.
+    pub fn new(params: &SimpleBlobDetectorParams) -> Self {
+        let detector = unsafe { cv_simple_blob_detector_new(*params) };
+        SimpleBlobDetector { value: detector }
+    }
+}
+
+impl_feature2d!(SimpleBlobDetector);
+
+/// Builder that provides defaults for SimpleBlobDetector
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SimpleBlobDetectorBuilder {
+    params: SimpleBlobDetectorParams,
+}
+
+impl SimpleBlobDetectorBuilder {
+    /// Replace current thresholds with specified values
+    pub fn thresholds(mut self, min: f32, max: f32, step: f32) -> Self {
+        self.params.min_threshold = min;
+        self.params.max_threshold = max;
+        self.params.threshold_step = step;
+        self
+    }
+
+    /// Replace current min_repeatability with specified value
+    pub fn min_repeatability(mut self, value: usize) -> Self {
+        self.params.min_repeatability = value;
+        self
+    }
+
+    /// Replace current min_dist_between_blobs with specified value
+    pub fn min_dist_between_blobs(mut self, value: f32) -> Self {
+        self.params.min_dist_between_blobs = value;
+        self
+    }
+
+    /// Filter blobs by color, `None` to disable
+    pub fn color(mut self, value: Option<u8>) -> Self {
+        self.params.filter_by_color = value.is_some();
+        self.params.blob_color = value.unwrap_or(0);
+        self
+    }
+
+    /// Filter blobs by area, `None` to disable
+    pub fn area(mut self, value: Option<(f32, f32)>) -> Self {
+        self.params.filter_by_area = value.is_some();
+        if let Some((min, max)) = value {
+            self.params.min_area = min;
+            self.params.max_area = max;
+        }
+        self
+    }
+
+    /// Filter blobs by circularity, `None` to disable
+    pub fn circularity(mut self, value: Option<(f32, f32)>) -> Self {
+        self.params.filter_by_circularity = value.is_some();
+        if let Some((min, max)) = value {
+            self.params.min_circularity = min;
+            self.params.max_circularity = max;
+        }
+        self
+    }
+
+    /// Filter blobs by inertia ratio, `None` to disable
+    pub fn inertia_ratio(mut self, value: Option<(f32, f32)>) -> Self {
+        self.params.filter_by_inertia = value.is_some();
+        if let Some((min, max)) = value {
+            self.params.min_inertia_ratio = min;
+            self.params.max_inertia_ratio = max;
+        }
+        self
+    }
+
+    /// Filter blobs by convexity, `None` to disable
+    pub fn convexity(mut self, value: Option<(f32, f32)>) -> Self {
+        self.params.filter_by_convexity = value.is_some();
+        if let Some((min, max)) = value {
+            self.params.min_convexity = min;
+            self.params.max_convexity = max;
+        }
+        self
+    }
+}
+
+impl Into<SimpleBlobDetector> for SimpleBlobDetectorBuilder {
+    fn into(self) -> SimpleBlobDetector {
+        SimpleBlobDetector::new(&self.params)
+    }
+}
@@ -1,10 +1,6 @@
 //! Provide the type that encapsulates all the parameters of the SURF extraction algorithm
-use super::*;
-use core::*;
+use super::private::*;
 use std::os::raw::*;
-use *;
-
-enum CSURF {}
 
 extern "C" {
     fn cv_surf_new(
@@ -13,22 +9,13 @@ extern "C" {
         octave_layers: c_int,
         extended: bool,
         upright: bool,
-    ) -> *mut CSURF;
-    fn cv_surf_drop(cmser: *mut CSURF);
-    fn cv_surf_detect_and_compute(
-        detector: *const CSURF,
-        image: *const CMat,
-        mask: *const CMat,
-        keypoints: *mut CVec<KeyPoint>,
-        descriptors: *mut CMat,
-        use_provided_keypoints: bool,
-    );
+    ) -> *mut CFeature2D;
 }
 
 /// Speeded up robust features extractor.
 #[derive(Debug)]
 pub struct SURF {
-    value: *mut CSURF,
+    value: *mut CFeature2D,
 }
 
 impl SURF {
@@ -39,13 +26,7 @@ impl SURF {
     }
 }
 
-impl Drop for SURF {
-    fn drop(&mut self) {
-        unsafe {
-            cv_surf_drop(self.value);
-        }
-    }
-}
+impl_feature2d!(SURF);
 
 /// Builder that provides defaults for MSER
 #[derive(Debug, Copy, Clone, Default)]
@@ -100,14 +81,3 @@ impl Into<SURF> for SURFBuilder {
         )
     }
 }
-
-impl Feature2D for SURF {
-    fn detect_and_compute(&self, image: &Mat, mask: &Mat) -> (Vec<KeyPoint>, Mat) {
-        let mut keypoints = CVec::<KeyPoint>::default();
-        let descriptors = CMat::new();
-        unsafe {
-            cv_surf_detect_and_compute(self.value, image.inner, mask.inner, &mut keypoints, descriptors, false);
-        }
-        (keypoints.unpack(), Mat::from_raw(descriptors))
-    }
-}
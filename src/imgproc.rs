@@ -2,7 +2,9 @@
 //! imgproc](http://docs.opencv.org/3.1.0/d7/dbd/group__imgproc.html).
 
 use super::core::*;
+use super::errors::*;
 use super::*;
+use failure::Error;
 use std::os::raw::{c_double, c_float, c_int};
 
 // =============================================================================
@@ -35,6 +37,7 @@ extern "C" {
     );
 
     fn cv_cvt_color(cmat: *const CMat, output: *mut CMat, code: ColorConversion);
+    fn cv_demosaicing(cmat: *const CMat, output: *mut CMat, code: ColorConversion, dst_cn: c_int) -> CEmptyResult;
     fn cv_pyr_down(cmat: *const CMat, output: *mut CMat);
     fn cv_threshold(from: *const CMat, to: *mut CMat, thresh: f64, maxval: f64, ttype: ThresholdType);
     fn cv_erode(
@@ -97,6 +100,65 @@ extern "C" {
         result: *mut CResult<c_double>,
     );
 
+    fn cv_calc_hist_images(
+        images: *const CVecView<*mut CMat>,
+        channels: *const CVecView<c_int>,
+        mask: *const CMat,
+        hist: *mut CMat,
+        hist_size: *const CVecView<c_int>,
+        ranges: *const CVecView<CVecView<c_float>>,
+        uniform: bool,
+        accumulate: bool,
+    ) -> CEmptyResult;
+
+    fn cv_calc_back_project_images(
+        images: *const CVecView<*mut CMat>,
+        channels: *const CVecView<c_int>,
+        hist: *const CMat,
+        back_project: *mut CMat,
+        ranges: *const CVecView<CVecView<c_float>>,
+        scale: c_double,
+        uniform: bool,
+    ) -> CEmptyResult;
+
+    fn cv_calc_sparse_hist_images(
+        images: *const CVecView<*mut CMat>,
+        channels: *const CVecView<c_int>,
+        mask: *const CMat,
+        hist_size: *const CVecView<c_int>,
+        ranges: *const CVecView<CVecView<c_float>>,
+        uniform: bool,
+        result: *mut CResult<*mut CSparseMat>,
+    );
+    fn cv_sparse_mat_drop(mat: *mut CSparseMat);
+    fn cv_sparse_mat_nz_count(mat: *const CSparseMat) -> usize;
+    fn cv_sparse_mat_value(mat: *const CSparseMat, idx: *const c_int) -> c_float;
+    fn cv_sparse_mat_to_dense(mat: *const CSparseMat, dst: *mut CMat);
+    fn cv_compare_sparse_hist(
+        first: *const CSparseMat,
+        second: *const CSparseMat,
+        method: HistogramComparisionMethod,
+        result: *mut CResult<c_double>,
+    );
+
+    fn cv_equalize_hist(src: *const CMat, dst: *mut CMat) -> CEmptyResult;
+    fn cv_remap(
+        src: *const CMat,
+        dst: *mut CMat,
+        map1: *const CMat,
+        map2: *const CMat,
+        interpolation: InterpolationFlag,
+        border_type: c_int,
+        border_value: Scalar,
+    ) -> CEmptyResult;
+
+    fn cv_emd(
+        signature1: *const CMat,
+        signature2: *const CMat,
+        distance_type: DistanceType,
+        result: *mut CResult<c_float>,
+    );
+
     fn cv_sobel(
         src: *const CMat,
         dst: *mut CMat,
@@ -129,6 +191,91 @@ extern "C" {
         l2_gradient: c_int,
     ) -> CEmptyResult;
 
+    fn cv_flood_fill(
+        image: *mut CMat,
+        mask: *mut CMat,
+        seed_point: Point2i,
+        new_val: Scalar,
+        lo_diff: Scalar,
+        up_diff: Scalar,
+        flags: c_int,
+        rect: *mut Rect,
+        result: *mut CResult<c_int>,
+    );
+
+    fn cv_watershed(image: *const CMat, markers: *mut CMat) -> CEmptyResult;
+
+    fn cv_grab_cut(
+        image: *const CMat,
+        mask: *mut CMat,
+        rect: Rect,
+        bgd_model: *mut CMat,
+        fgd_model: *mut CMat,
+        iter_count: c_int,
+        mode: GrabCutMode,
+    ) -> CEmptyResult;
+
+    fn cv_distance_transform(
+        src: *const CMat,
+        dst: *mut CMat,
+        distance_type: DistanceType,
+        mask_size: DistanceTransformMask,
+    ) -> CEmptyResult;
+
+    fn cv_distance_transform_with_labels(
+        src: *const CMat,
+        dst: *mut CMat,
+        labels: *mut CMat,
+        distance_type: DistanceType,
+        mask_size: DistanceTransformMask,
+        label_type: DistanceTransformLabelType,
+    ) -> CEmptyResult;
+
+    fn cv_integral(src: *const CMat, sum: *mut CMat, sdepth: c_int) -> CEmptyResult;
+    fn cv_integral2(src: *const CMat, sum: *mut CMat, sqsum: *mut CMat, sdepth: c_int, sqdepth: c_int) -> CEmptyResult;
+    fn cv_integral3(
+        src: *const CMat,
+        sum: *mut CMat,
+        sqsum: *mut CMat,
+        tilted: *mut CMat,
+        sdepth: c_int,
+        sqdepth: c_int,
+    ) -> CEmptyResult;
+
+    fn cv_good_features_to_track(
+        image: *const CMat,
+        corners: *mut CVec<Point2f>,
+        max_corners: c_int,
+        quality_level: c_double,
+        min_distance: c_double,
+        mask: *const CMat,
+        block_size: c_int,
+        use_harris_detector: bool,
+        k: c_double,
+    ) -> CEmptyResult;
+    fn cv_corner_harris(
+        src: *const CMat,
+        dst: *mut CMat,
+        block_size: c_int,
+        ksize: c_int,
+        k: c_double,
+        border_type: c_int,
+    ) -> CEmptyResult;
+    fn cv_corner_min_eigen_val(
+        src: *const CMat,
+        dst: *mut CMat,
+        block_size: c_int,
+        ksize: c_int,
+        border_type: c_int,
+    ) -> CEmptyResult;
+    fn cv_corner_sub_pix(
+        image: *const CMat,
+        corners: *const CVecView<Point2f>,
+        refined: *mut CVec<Point2f>,
+        win_size: Size2i,
+        zero_zone: Size2i,
+        criteria: *const CTermCriteria,
+    ) -> CEmptyResult;
 }
 
 /// Possible methods for histogram comparision method
@@ -149,6 +296,287 @@ pub enum HistogramComparisionMethod {
     KullbackLeiblerDivergence = 5,
 }
 
+/// Builder for dense and sparse histograms of one or more images.
+///
+/// Every dimension of the histogram is described by a channel, a number of
+/// bins and a range, so `channels`, `bins` and `ranges` must have the same
+/// length. The channels are numbered across all the images: the channels of
+/// the first image are `0..images[0].channels`, followed by the channels of
+/// the second image and so on.
+///
+/// ```rust,ignore
+/// let hist = Histogram::new()
+///     .channels(&[0, 1])
+///     .bins(&[30, 32])
+///     .ranges(&[&[0.0, 180.0], &[0.0, 256.0]])
+///     .calc(&[&hsv], &Mat::new())?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct Histogram {
+    channels: Vec<c_int>,
+    bins: Vec<c_int>,
+    ranges: Vec<Vec<f32>>,
+    uniform: bool,
+    accumulate: bool,
+}
+
+impl Default for Histogram {
+    fn default() -> Histogram {
+        Histogram {
+            channels: Vec::new(),
+            bins: Vec::new(),
+            ranges: Vec::new(),
+            uniform: true,
+            accumulate: false,
+        }
+    }
+}
+
+impl Histogram {
+    /// Creates an empty histogram description, with uniform bins and no
+    /// accumulation.
+    pub fn new() -> Histogram {
+        Histogram::default()
+    }
+
+    /// Sets the channels used to compute the histogram, one per dimension.
+    pub fn channels(mut self, channels: &[c_int]) -> Self {
+        self.channels = channels.to_vec();
+        self
+    }
+
+    /// Sets the number of bins of each dimension.
+    pub fn bins(mut self, bins: &[c_int]) -> Self {
+        self.bins = bins.to_vec();
+        self
+    }
+
+    /// Sets the range of each dimension. For uniform histograms, a range is
+    /// the inclusive lower and the exclusive upper boundary (`[low, high]`).
+    /// Otherwise, it contains the `bins + 1` boundaries of the bins.
+    pub fn ranges(mut self, ranges: &[&[f32]]) -> Self {
+        self.ranges = ranges.iter().map(|r| r.to_vec()).collect();
+        self
+    }
+
+    /// Sets whether the bins are evenly spaced. The default is `true`.
+    pub fn uniform(mut self, uniform: bool) -> Self {
+        self.uniform = uniform;
+        self
+    }
+
+    /// Sets whether [calc_into](#method.calc_into) adds to the existing
+    /// histogram instead of clearing it first. The default is `false`.
+    pub fn accumulate(mut self, accumulate: bool) -> Self {
+        self.accumulate = accumulate;
+        self
+    }
+
+    /// Calculates a dense histogram of a set of images. The `mask`, if not
+    /// empty, selects the pixels that are counted.
+    pub fn calc(&self, images: &[&Mat], mask: &Mat) -> Result<Mat, Error> {
+        let mut hist = Mat::new();
+        self.calc_into(images, mask, &mut hist)?;
+        Ok(hist)
+    }
+
+    /// Same as [calc](#method.calc), but writes into `hist`. If accumulation
+    /// is enabled, `hist` is not cleared so a single histogram can be computed
+    /// from several sets of images.
+    pub fn calc_into(&self, images: &[&Mat], mask: &Mat, hist: &mut Mat) -> Result<(), Error> {
+        self.validate(images)?;
+        let images = images.iter().map(|m| m.inner).collect();
+        let images = CVecView::pack(&images);
+        let channels = CVecView::pack(&self.channels);
+        let bins = CVecView::pack(&self.bins);
+        let ranges = CVecView::pack(&self.ranges);
+        let result = unsafe {
+            cv_calc_hist_images(
+                &images,
+                &channels,
+                mask.inner,
+                hist.inner,
+                &bins,
+                &ranges,
+                self.uniform,
+                self.accumulate,
+            )
+        };
+        hist.refresh_header();
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(())
+    }
+
+    /// Calculates a sparse histogram of a set of images, which is better
+    /// suited for high-dimensional histograms where most bins are empty.
+    pub fn calc_sparse(&self, images: &[&Mat], mask: &Mat) -> Result<SparseHistogram, Error> {
+        self.validate(images)?;
+        let images = images.iter().map(|m| m.inner).collect();
+        let images = CVecView::pack(&images);
+        let channels = CVecView::pack(&self.channels);
+        let bins = CVecView::pack(&self.bins);
+        let ranges = CVecView::pack(&self.ranges);
+        let result = CResult::<*mut CSparseMat>::from_callback(|r| unsafe {
+            cv_calc_sparse_hist_images(&images, &channels, mask.inner, &bins, &ranges, self.uniform, r)
+        });
+        let result: Result<_, String> = result.into();
+        let inner = result.map_err(CvError::UnknownError)?;
+        Ok(SparseHistogram {
+            inner: inner,
+            dims: self.channels.len(),
+        })
+    }
+
+    /// Calculates the back projection of a dense histogram `hist` computed
+    /// with the same channels and ranges. Each value of the back projection is
+    /// multiplied by `scale`.
+    pub fn back_project(&self, images: &[&Mat], hist: &Mat, scale: f64) -> Result<Mat, Error> {
+        self.validate(images)?;
+        let images = images.iter().map(|m| m.inner).collect();
+        let images = CVecView::pack(&images);
+        let channels = CVecView::pack(&self.channels);
+        let ranges = CVecView::pack(&self.ranges);
+        let back_project = CMat::new();
+        let result = unsafe {
+            cv_calc_back_project_images(
+                &images,
+                &channels,
+                hist.inner,
+                back_project,
+                &ranges,
+                scale,
+                self.uniform,
+            )
+        };
+        let back_project = Mat::from_raw(back_project);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(back_project)
+    }
+
+    fn validate(&self, images: &[&Mat]) -> Result<(), CvError> {
+        if images.is_empty() {
+            return Err(CvError::InvalidArgument("no input images".into()));
+        }
+        let dims = self.channels.len();
+        if dims == 0 || self.bins.len() != dims || self.ranges.len() != dims {
+            return Err(CvError::InvalidArgument(format!(
+                "histogram dimensions mismatch: {} channels, {} bins, {} ranges",
+                dims,
+                self.bins.len(),
+                self.ranges.len()
+            )));
+        }
+        for (bins, range) in self.bins.iter().zip(self.ranges.iter()) {
+            let expected = if self.uniform { 2 } else { *bins as usize + 1 };
+            if range.len() != expected {
+                return Err(CvError::InvalidArgument(format!(
+                    "range {:?} should contain {} values",
+                    range, expected
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) enum CSparseMat {}
+
+/// A sparse histogram, as computed by
+/// [Histogram::calc_sparse](struct.Histogram.html#method.calc_sparse). Only
+/// the non-empty bins are stored.
+#[derive(Debug)]
+pub struct SparseHistogram {
+    inner: *mut CSparseMat,
+    dims: usize,
+}
+
+impl SparseHistogram {
+    /// Returns the number of non-empty bins.
+    pub fn nz_count(&self) -> usize {
+        unsafe { cv_sparse_mat_nz_count(self.inner) }
+    }
+
+    /// Returns the number of dimensions of this histogram.
+    pub fn dims(&self) -> usize {
+        self.dims
+    }
+
+    /// Returns the value of the bin at the specified index (one entry per
+    /// dimension), or 0 if the bin is empty.
+    pub fn value(&self, idx: &[c_int]) -> Result<f32, Error> {
+        if idx.len() != self.dims {
+            return Err(CvError::InvalidArgument(format!(
+                "expected an index with {} entries, got {}",
+                self.dims,
+                idx.len()
+            ))
+            .into());
+        }
+        Ok(unsafe { cv_sparse_mat_value(self.inner, idx.as_ptr()) })
+    }
+
+    /// Converts this histogram to a dense one.
+    pub fn to_dense(&self) -> Mat {
+        let m = CMat::new();
+        unsafe { cv_sparse_mat_to_dense(self.inner, m) }
+        Mat::from_raw(m)
+    }
+
+    /// Compares two sparse histograms, see
+    /// [Mat::compare_hist](../struct.Mat.html#method.compare_hist).
+    pub fn compare(&self, other: &SparseHistogram, method: HistogramComparisionMethod) -> Result<f64, Error> {
+        let result =
+            CResult::<f64>::from_callback(|r| unsafe { cv_compare_sparse_hist(self.inner, other.inner, method, r) });
+        let result: Result<f64, String> = result.into();
+        let result = result.map_err(CvError::UnknownError)?;
+        Ok(result)
+    }
+}
+
+impl Drop for SparseHistogram {
+    fn drop(&mut self) {
+        unsafe {
+            cv_sparse_mat_drop(self.inner);
+        }
+    }
+}
+
+/// Parameters of [Mat::good_features_to_track](../struct.Mat.html#method.good_features_to_track).
+#[derive(Debug, Clone, Copy)]
+pub struct GoodFeaturesParams {
+    /// Maximum number of corners to return, the strongest ones are kept. If
+    /// zero or negative, all the detected corners are returned.
+    pub max_corners: c_int,
+    /// Minimal accepted quality of the corners, relative to the best corner
+    /// measure in the image. Corners with a measure below
+    /// `quality_level * best` are rejected.
+    pub quality_level: f64,
+    /// Minimum Euclidean distance between the returned corners.
+    pub min_distance: f64,
+    /// Size of the neighborhood used to compute the corner measure.
+    pub block_size: c_int,
+    /// Uses the Harris corner measure instead of the minimal eigenvalue
+    /// (Shi-Tomasi).
+    pub use_harris_detector: bool,
+    /// Free parameter of the Harris detector, ignored otherwise.
+    pub k: f64,
+}
+
+impl Default for GoodFeaturesParams {
+    fn default() -> GoodFeaturesParams {
+        GoodFeaturesParams {
+            max_corners: 0,
+            quality_level: 0.01,
+            min_distance: 1.0,
+            block_size: 3,
+            use_harris_detector: false,
+            k: 0.04,
+        }
+    }
+}
+
 /// ThresholdTypes used in
 /// [threshold](../struct.Mat.html#method.threshold).
 #[repr(C)]
@@ -165,8 +593,62 @@ pub enum ThresholdType {
     Triangle = 16,
 }
 
+/// Distance types for
+/// [distance_transform](../struct.Mat.html#method.distance_transform).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DistanceType {
+    /// User defined distance
+    User = -1,
+    /// distance = |x1-x2| + |y1-y2|
+    L1 = 1,
+    /// the simple euclidean distance
+    L2 = 2,
+    /// distance = max(|x1-x2|,|y1-y2|)
+    C = 3,
+    /// L1-L2 metric: distance = 2(sqrt(1+x*x/2) - 1))
+    L12 = 4,
+    /// distance = c^2(|x|/c-log(1+|x|/c)), c = 1.3998
+    Fair = 5,
+    /// distance = c^2/2(1-exp(-(x/c)^2)), c = 2.9846
+    Welsch = 6,
+    /// distance = |x|<c ? x^2/2 : c(|x|-c/2), c=1.345
+    Huber = 7,
+}
+
+/// Mask size for
+/// [distance_transform](../struct.Mat.html#method.distance_transform).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DistanceTransformMask {
+    /// Precise distance computation (not supported when computing labels)
+    Precise = 0,
+    /// 3x3 mask
+    Mask3 = 3,
+    /// 5x5 mask
+    Mask5 = 5,
+}
+
+/// Type of the labels built by
+/// [distance_transform_with_labels](../struct.Mat.html#method.distance_transform_with_labels).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DistanceTransformLabelType {
+    /// Each connected component of zeros in the source image (as well as all
+    /// the non-zero pixels closest to the connected component) will be
+    /// assigned the same label.
+    ConnectedComponent = 0,
+    /// Each zero pixel (and all the non-zero pixels closest to it) gets its
+    /// own label.
+    Pixel = 1,
+}
+
 /// Color conversion code used in
 /// [cvt_color](../struct.Mat.html#method.cvt_color).
+///
+/// Codes that are aliases of another code in OpenCV (for example `RGB2BGR`
+/// is the same conversion as `BGR2RGB`) are available as associated
+/// constants.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 #[allow(non_camel_case_types, missing_docs)]
@@ -304,7 +786,153 @@ pub enum ColorConversion {
     BayerGB2BGR_EA = 136,
     BayerRG2BGR_EA = 137,
     BayerGR2BGR_EA = 138,
-    COLORCVT_MAX = 139,
+    // Demosaicing with alpha channel, not available in older OpenCV 3.x
+    // releases.
+    BayerBG2BGRA = 139,
+    BayerGB2BGRA = 140,
+    BayerRG2BGRA = 141,
+    BayerGR2BGRA = 142,
+    COLORCVT_MAX = 143,
+}
+
+#[allow(non_upper_case_globals)]
+impl ColorConversion {
+    /// Same as `BGR2BGRA`
+    pub const RGB2RGBA: ColorConversion = ColorConversion::BGR2BGRA;
+    /// Same as `BGRA2BGR`
+    pub const RGBA2RGB: ColorConversion = ColorConversion::BGRA2BGR;
+    /// Same as `BGR2RGBA`
+    pub const RGB2BGRA: ColorConversion = ColorConversion::BGR2RGBA;
+    /// Same as `RGBA2BGR`
+    pub const BGRA2RGB: ColorConversion = ColorConversion::RGBA2BGR;
+    /// Same as `BGR2RGB`
+    pub const RGB2BGR: ColorConversion = ColorConversion::BGR2RGB;
+    /// Same as `BGRA2RGBA`
+    pub const RGBA2BGRA: ColorConversion = ColorConversion::BGRA2RGBA;
+    /// Same as `GRAY2BGR`
+    pub const GRAY2RGB: ColorConversion = ColorConversion::GRAY2BGR;
+    /// Same as `GRAY2BGRA`
+    pub const GRAY2RGBA: ColorConversion = ColorConversion::GRAY2BGRA;
+    /// Same as `YUV2RGB_NV21`
+    pub const YUV420sp2RGB: ColorConversion = ColorConversion::YUV2RGB_NV21;
+    /// Same as `YUV2BGR_NV21`
+    pub const YUV420sp2BGR: ColorConversion = ColorConversion::YUV2BGR_NV21;
+    /// Same as `YUV2RGBA_NV21`
+    pub const YUV420sp2RGBA: ColorConversion = ColorConversion::YUV2RGBA_NV21;
+    /// Same as `YUV2BGRA_NV21`
+    pub const YUV420sp2BGRA: ColorConversion = ColorConversion::YUV2BGRA_NV21;
+    /// Same as `YUV2RGB_IYUV`
+    pub const YUV2RGB_I420: ColorConversion = ColorConversion::YUV2RGB_IYUV;
+    /// Same as `YUV2BGR_IYUV`
+    pub const YUV2BGR_I420: ColorConversion = ColorConversion::YUV2BGR_IYUV;
+    /// Same as `YUV2RGB_YV12`
+    pub const YUV420p2RGB: ColorConversion = ColorConversion::YUV2RGB_YV12;
+    /// Same as `YUV2BGR_YV12`
+    pub const YUV420p2BGR: ColorConversion = ColorConversion::YUV2BGR_YV12;
+    /// Same as `YUV2RGBA_IYUV`
+    pub const YUV2RGBA_I420: ColorConversion = ColorConversion::YUV2RGBA_IYUV;
+    /// Same as `YUV2BGRA_IYUV`
+    pub const YUV2BGRA_I420: ColorConversion = ColorConversion::YUV2BGRA_IYUV;
+    /// Same as `YUV2RGBA_YV12`
+    pub const YUV420p2RGBA: ColorConversion = ColorConversion::YUV2RGBA_YV12;
+    /// Same as `YUV2BGRA_YV12`
+    pub const YUV420p2BGRA: ColorConversion = ColorConversion::YUV2BGRA_YV12;
+    /// Same as `YUV2GRAY_420`
+    pub const YUV2GRAY_NV21: ColorConversion = ColorConversion::YUV2GRAY_420;
+    /// Same as `YUV2GRAY_420`
+    pub const YUV2GRAY_NV12: ColorConversion = ColorConversion::YUV2GRAY_420;
+    /// Same as `YUV2GRAY_420`
+    pub const YUV2GRAY_YV12: ColorConversion = ColorConversion::YUV2GRAY_420;
+    /// Same as `YUV2GRAY_420`
+    pub const YUV2GRAY_IYUV: ColorConversion = ColorConversion::YUV2GRAY_420;
+    /// Same as `YUV2GRAY_420`
+    pub const YUV2GRAY_I420: ColorConversion = ColorConversion::YUV2GRAY_420;
+    /// Same as `YUV2GRAY_420`
+    pub const YUV420sp2GRAY: ColorConversion = ColorConversion::YUV2GRAY_420;
+    /// Same as `YUV2GRAY_420`
+    pub const YUV420p2GRAY: ColorConversion = ColorConversion::YUV2GRAY_420;
+    /// Same as `YUV2RGB_UYVY`
+    pub const YUV2RGB_Y422: ColorConversion = ColorConversion::YUV2RGB_UYVY;
+    /// Same as `YUV2BGR_UYVY`
+    pub const YUV2BGR_Y422: ColorConversion = ColorConversion::YUV2BGR_UYVY;
+    /// Same as `YUV2RGB_UYVY`
+    pub const YUV2RGB_UYNV: ColorConversion = ColorConversion::YUV2RGB_UYVY;
+    /// Same as `YUV2BGR_UYVY`
+    pub const YUV2BGR_UYNV: ColorConversion = ColorConversion::YUV2BGR_UYVY;
+    /// Same as `YUV2RGBA_UYVY`
+    pub const YUV2RGBA_Y422: ColorConversion = ColorConversion::YUV2RGBA_UYVY;
+    /// Same as `YUV2BGRA_UYVY`
+    pub const YUV2BGRA_Y422: ColorConversion = ColorConversion::YUV2BGRA_UYVY;
+    /// Same as `YUV2RGBA_UYVY`
+    pub const YUV2RGBA_UYNV: ColorConversion = ColorConversion::YUV2RGBA_UYVY;
+    /// Same as `YUV2BGRA_UYVY`
+    pub const YUV2BGRA_UYNV: ColorConversion = ColorConversion::YUV2BGRA_UYVY;
+    /// Same as `YUV2RGB_YUY2`
+    pub const YUV2RGB_YUYV: ColorConversion = ColorConversion::YUV2RGB_YUY2;
+    /// Same as `YUV2BGR_YUY2`
+    pub const YUV2BGR_YUYV: ColorConversion = ColorConversion::YUV2BGR_YUY2;
+    /// Same as `YUV2RGB_YUY2`
+    pub const YUV2RGB_YUNV: ColorConversion = ColorConversion::YUV2RGB_YUY2;
+    /// Same as `YUV2BGR_YUY2`
+    pub const YUV2BGR_YUNV: ColorConversion = ColorConversion::YUV2BGR_YUY2;
+    /// Same as `YUV2RGBA_YUY2`
+    pub const YUV2RGBA_YUYV: ColorConversion = ColorConversion::YUV2RGBA_YUY2;
+    /// Same as `YUV2BGRA_YUY2`
+    pub const YUV2BGRA_YUYV: ColorConversion = ColorConversion::YUV2BGRA_YUY2;
+    /// Same as `YUV2RGBA_YUY2`
+    pub const YUV2RGBA_YUNV: ColorConversion = ColorConversion::YUV2RGBA_YUY2;
+    /// Same as `YUV2BGRA_YUY2`
+    pub const YUV2BGRA_YUNV: ColorConversion = ColorConversion::YUV2BGRA_YUY2;
+    /// Same as `YUV2GRAY_UYVY`
+    pub const YUV2GRAY_Y422: ColorConversion = ColorConversion::YUV2GRAY_UYVY;
+    /// Same as `YUV2GRAY_UYVY`
+    pub const YUV2GRAY_UYNV: ColorConversion = ColorConversion::YUV2GRAY_UYVY;
+    /// Same as `YUV2GRAY_YUY2`
+    pub const YUV2GRAY_YVYU: ColorConversion = ColorConversion::YUV2GRAY_YUY2;
+    /// Same as `YUV2GRAY_YUY2`
+    pub const YUV2GRAY_YUYV: ColorConversion = ColorConversion::YUV2GRAY_YUY2;
+    /// Same as `YUV2GRAY_YUY2`
+    pub const YUV2GRAY_YUNV: ColorConversion = ColorConversion::YUV2GRAY_YUY2;
+    /// Same as `RGB2YUV_I420`
+    pub const RGB2YUV_IYUV: ColorConversion = ColorConversion::RGB2YUV_I420;
+    /// Same as `BGR2YUV_I420`
+    pub const BGR2YUV_IYUV: ColorConversion = ColorConversion::BGR2YUV_I420;
+    /// Same as `RGBA2YUV_I420`
+    pub const RGBA2YUV_IYUV: ColorConversion = ColorConversion::RGBA2YUV_I420;
+    /// Same as `BGRA2YUV_I420`
+    pub const BGRA2YUV_IYUV: ColorConversion = ColorConversion::BGRA2YUV_I420;
+    /// Same as `BayerRG2BGR`
+    pub const BayerBG2RGB: ColorConversion = ColorConversion::BayerRG2BGR;
+    /// Same as `BayerGR2BGR`
+    pub const BayerGB2RGB: ColorConversion = ColorConversion::BayerGR2BGR;
+    /// Same as `BayerBG2BGR`
+    pub const BayerRG2RGB: ColorConversion = ColorConversion::BayerBG2BGR;
+    /// Same as `BayerGB2BGR`
+    pub const BayerGR2RGB: ColorConversion = ColorConversion::BayerGB2BGR;
+    /// Same as `BayerRG2BGR_VNG`
+    pub const BayerBG2RGB_VNG: ColorConversion = ColorConversion::BayerRG2BGR_VNG;
+    /// Same as `BayerGR2BGR_VNG`
+    pub const BayerGB2RGB_VNG: ColorConversion = ColorConversion::BayerGR2BGR_VNG;
+    /// Same as `BayerBG2BGR_VNG`
+    pub const BayerRG2RGB_VNG: ColorConversion = ColorConversion::BayerBG2BGR_VNG;
+    /// Same as `BayerGB2BGR_VNG`
+    pub const BayerGR2RGB_VNG: ColorConversion = ColorConversion::BayerGB2BGR_VNG;
+    /// Same as `BayerRG2BGR_EA`
+    pub const BayerBG2RGB_EA: ColorConversion = ColorConversion::BayerRG2BGR_EA;
+    /// Same as `BayerGR2BGR_EA`
+    pub const BayerGB2RGB_EA: ColorConversion = ColorConversion::BayerGR2BGR_EA;
+    /// Same as `BayerBG2BGR_EA`
+    pub const BayerRG2RGB_EA: ColorConversion = ColorConversion::BayerBG2BGR_EA;
+    /// Same as `BayerGB2BGR_EA`
+    pub const BayerGR2RGB_EA: ColorConversion = ColorConversion::BayerGB2BGR_EA;
+    /// Same as `BayerRG2BGRA`
+    pub const BayerBG2RGBA: ColorConversion = ColorConversion::BayerRG2BGRA;
+    /// Same as `BayerGR2BGRA`
+    pub const BayerGB2RGBA: ColorConversion = ColorConversion::BayerGR2BGRA;
+    /// Same as `BayerBG2BGRA`
+    pub const BayerRG2RGBA: ColorConversion = ColorConversion::BayerBG2BGRA;
+    /// Same as `BayerGB2BGRA`
+    pub const BayerGR2RGBA: ColorConversion = ColorConversion::BayerGB2BGRA;
 }
 
 /// Interpolation algorithm
@@ -334,6 +962,161 @@ pub enum InterpolationFlag {
     WarpInverseMap = 16,
 }
 
+/// Operation flags for [flood_fill](../struct.Mat.html#method.flood_fill).
+#[derive(Debug, Clone, Copy)]
+pub struct FloodFillFlags {
+    /// Connectivity value, either 4 or 8. The default is 4.
+    pub connectivity: c_int,
+
+    /// Value written to the mask for every filled pixel. If it is zero, the
+    /// mask is filled with 1.
+    pub mask_fill_value: u8,
+
+    /// If set, the difference between the current pixel and the seed pixel is
+    /// considered. Otherwise, the difference between neighbor pixels is
+    /// considered (that is, the range is floating).
+    pub fixed_range: bool,
+
+    /// If set, the function does not change the image (`new_val` is ignored),
+    /// and only fills the mask.
+    pub mask_only: bool,
+}
+
+impl Default for FloodFillFlags {
+    fn default() -> FloodFillFlags {
+        FloodFillFlags {
+            connectivity: 4,
+            mask_fill_value: 0,
+            fixed_range: false,
+            mask_only: false,
+        }
+    }
+}
+
+impl FloodFillFlags {
+    fn bits(&self) -> c_int {
+        let mut flags = self.connectivity | ((self.mask_fill_value as c_int) << 8);
+        if self.fixed_range {
+            flags |= 1 << 16;
+        }
+        if self.mask_only {
+            flags |= 1 << 17;
+        }
+        flags
+    }
+}
+
+/// Operation mode of the GrabCut algorithm.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GrabCutMode {
+    /// Initializes the state and the mask using the provided rectangle.
+    InitWithRect = 0,
+    /// Initializes the state using the provided mask.
+    InitWithMask = 1,
+    /// Resumes the algorithm with the current state.
+    Eval = 2,
+}
+
+/// Pixel classes stored in the [GrabCut](struct.GrabCut.html) mask.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GrabCutClass {
+    /// An obvious background pixel
+    Background = 0,
+    /// An obvious foreground (object) pixel
+    Foreground = 1,
+    /// A possible background pixel
+    ProbableBackground = 2,
+    /// A possible foreground pixel
+    ProbableForeground = 3,
+}
+
+/// Interactive foreground extraction using the GrabCut algorithm.
+///
+/// The segmentation state (the mask and both GMM models) is kept between
+/// calls, so the mask can be corrected through
+/// [mask_mut](#method.mask_mut) and the result improved with
+/// [refine](#method.refine).
+#[derive(Debug)]
+pub struct GrabCut {
+    mask: Mat,
+    bgd_model: Mat,
+    fgd_model: Mat,
+}
+
+impl GrabCut {
+    /// Segments `image` (8-bit, 3-channel) assuming that everything outside of
+    /// `rect` is background.
+    pub fn with_rect(image: &Mat, rect: Rect, iterations: c_int) -> Result<GrabCut, Error> {
+        Self::run(image, CMat::new(), rect, iterations, GrabCutMode::InitWithRect)
+    }
+
+    /// Segments `image` (8-bit, 3-channel) starting from a mask of
+    /// [GrabCutClass](enum.GrabCutClass.html) values of the same size.
+    pub fn with_mask(image: &Mat, mask: Mat, iterations: c_int) -> Result<GrabCut, Error> {
+        let inner = mask.inner;
+        // The returned `GrabCut` takes over the ownership of the mask.
+        ::std::mem::forget(mask);
+        Self::run(image, inner, Rect::default(), iterations, GrabCutMode::InitWithMask)
+    }
+
+    fn run(image: &Mat, mask: *mut CMat, rect: Rect, iterations: c_int, mode: GrabCutMode) -> Result<GrabCut, Error> {
+        let bgd_model = CMat::new();
+        let fgd_model = CMat::new();
+        let result = unsafe { cv_grab_cut(image.inner, mask, rect, bgd_model, fgd_model, iterations, mode) };
+        let grab_cut = GrabCut {
+            mask: Mat::from_raw(mask),
+            bgd_model: Mat::from_raw(bgd_model),
+            fgd_model: Mat::from_raw(fgd_model),
+        };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(grab_cut)
+    }
+
+    /// Runs more iterations of the algorithm, taking into account any change
+    /// made to the mask.
+    pub fn refine(&mut self, image: &Mat, iterations: c_int) -> Result<(), Error> {
+        let result = unsafe {
+            cv_grab_cut(
+                image.inner,
+                self.mask.inner,
+                Rect::default(),
+                self.bgd_model.inner,
+                self.fgd_model.inner,
+                iterations,
+                GrabCutMode::Eval,
+            )
+        };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(())
+    }
+
+    /// Returns the current mask, where each pixel is one of
+    /// [GrabCutClass](enum.GrabCutClass.html).
+    pub fn mask(&self) -> &Mat {
+        &self.mask
+    }
+
+    /// Returns the current mask for manual corrections before calling
+    /// [refine](#method.refine).
+    pub fn mask_mut(&mut self) -> &mut Mat {
+        &mut self.mask
+    }
+
+    /// Returns a binary mask (255 for obvious or probable foreground, 0
+    /// otherwise).
+    pub fn foreground_mask(&self) -> Mat {
+        let fgd = GrabCutClass::Foreground as c_int;
+        let pr_fgd = GrabCutClass::ProbableForeground as c_int;
+        let obvious = self.mask.in_range(Scalar::all(fgd), Scalar::all(fgd));
+        let probable = self.mask.in_range(Scalar::all(pr_fgd), Scalar::all(pr_fgd));
+        obvious | probable
+    }
+}
+
 impl Mat {
     /// Draws a simple line.
     pub fn line(&self, pt1: Point2i, pt2: Point2i) {
@@ -423,6 +1206,23 @@ impl Mat {
         Mat::from_raw(m)
     }
 
+    /// Converts a single-channel Bayer pattern image (8 or 16-bit) to a color
+    /// or grayscale image.
+    ///
+    /// The `code` must be one of the `Bayer*` conversions: bilinear
+    /// (`BayerBG2BGR`, ...), grayscale (`BayerBG2GRAY`, ...), variable number
+    /// of gradients (`BayerBG2BGR_VNG`, ...) or edge-aware
+    /// (`BayerBG2BGR_EA`, ...). `dst_cn` is the number of channels of the
+    /// output, 0 to deduce it from `code`.
+    pub fn demosaicing(&self, code: ColorConversion, dst_cn: c_int) -> Result<Mat, Error> {
+        let m = CMat::new();
+        let result = unsafe { cv_demosaicing(self.inner, m, code, dst_cn) };
+        let m = Mat::from_raw(m);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(m)
+    }
+
     /// Blurs an image and downsamples it. This function performs the
     /// downsampling step of the Gaussian pyramid construction.
     pub fn pyr_down(&self) -> Mat {
@@ -517,6 +1317,41 @@ impl Mat {
         Mat::from_raw(m)
     }
 
+    /// Applies a generic geometrical transformation: each pixel of the result
+    /// is taken from the source at the position given by the maps, e.g. the
+    /// ones computed by
+    /// [init_undistort_rectify_map](../calib3d/fn.init_undistort_rectify_map.html).
+    /// The result has the size of the maps.
+    ///
+    /// `map1` holds either the `(x, y)` positions or the x positions, and
+    /// `map2` the y positions or is empty. `border_value` is used with
+    /// `BorderType::Constant`.
+    pub fn remap(
+        &self,
+        map1: &Mat,
+        map2: &Mat,
+        interpolation: InterpolationFlag,
+        border_type: BorderType,
+        border_value: Scalar,
+    ) -> Result<Mat, Error> {
+        let m = CMat::new();
+        let result = unsafe {
+            cv_remap(
+                self.inner,
+                m,
+                map1.inner,
+                map2.inner,
+                interpolation,
+                border_type as c_int,
+                border_value,
+            )
+        };
+        let m = Mat::from_raw(m);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(m)
+    }
+
     /// Calculate a histogram of an image.
     pub fn calc_hist<T: AsRef<[c_int]>, U: AsRef<[c_int]>, MElem: AsRef<[f32]>, M: AsRef<[MElem]>>(
         &self,
@@ -575,10 +1410,36 @@ impl Mat {
     /// In such histograms, because of aliasing and sampling problems,
     /// the coordinates of non-zero histogram bins can slightly shift.
     /// To compare such histograms or more general sparse configurations of weighted points,
-    /// consider using the cv::EMD function.
-    pub fn compare_hist(&self, other: &Mat, method: HistogramComparisionMethod) -> Result<f64, String> {
+    /// consider using [emd](#method.emd).
+    pub fn compare_hist(&self, other: &Mat, method: HistogramComparisionMethod) -> Result<f64, Error> {
         let result = CResult::<f64>::from_callback(|r| unsafe { cv_compare_hist(self.inner, other.inner, method, r) });
-        result.into()
+        let result: Result<f64, String> = result.into();
+        let result = result.map_err(CvError::UnknownError)?;
+        Ok(result)
+    }
+
+    /// Computes the "minimal work" distance (Earth Mover's Distance) between
+    /// two weighted point configurations.
+    ///
+    /// Both `self` and `other` are signatures: `CV_32FC1` matrices where each
+    /// row is a point, the first column is the point weight and the other
+    /// columns are its coordinates. `DistanceType::User` is not supported.
+    pub fn emd(&self, other: &Mat, distance_type: DistanceType) -> Result<f32, Error> {
+        let result = CResult::<f32>::from_callback(|r| unsafe { cv_emd(self.inner, other.inner, distance_type, r) });
+        let result: Result<f32, String> = result.into();
+        let result = result.map_err(CvError::UnknownError)?;
+        Ok(result)
+    }
+
+    /// Equalizes the histogram of a grayscale (8-bit, single-channel) image,
+    /// which normalizes the brightness and increases the contrast.
+    pub fn equalize_hist(&self) -> Result<Mat, Error> {
+        let m = CMat::new();
+        let result = unsafe { cv_equalize_hist(self.inner, m) };
+        let m = Mat::from_raw(m);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(m)
     }
 
     /// Calculates the first x- or y- image derivative using Sobel operator.
@@ -634,6 +1495,272 @@ impl Mat {
         result.map(|_| edges)
     }
 
+    /// Fills a connected component starting from the seed point with the
+    /// specified color. Returns the number of repainted pixels and the minimum
+    /// bounding rectangle of the repainted domain.
+    ///
+    /// * `lo_diff` - maximal lower brightness/color difference between the
+    ///   currently observed pixel and one of its neighbors belonging to the
+    ///   component, or the seed pixel being added to the component.
+    /// * `up_diff` - maximal upper brightness/color difference.
+    pub fn flood_fill(
+        &mut self,
+        seed_point: Point2i,
+        new_val: Scalar,
+        lo_diff: Scalar,
+        up_diff: Scalar,
+        flags: FloodFillFlags,
+    ) -> Result<(c_int, Rect), Error> {
+        let mut mask = Mat::new();
+        self.flood_fill_with_mask(&mut mask, seed_point, new_val, lo_diff, up_diff, flags)
+    }
+
+    /// Same as [flood_fill](#method.flood_fill), but also updates `mask`. The
+    /// mask must be a single-channel 8-bit image, 2 pixels wider and 2 pixels
+    /// taller than `self`; filling can't go across non-zero pixels of the mask.
+    pub fn flood_fill_with_mask(
+        &mut self,
+        mask: &mut Mat,
+        seed_point: Point2i,
+        new_val: Scalar,
+        lo_diff: Scalar,
+        up_diff: Scalar,
+        flags: FloodFillFlags,
+    ) -> Result<(c_int, Rect), Error> {
+        let mut rect = Rect::default();
+        let result = CResult::<c_int>::from_callback(|r| unsafe {
+            cv_flood_fill(
+                self.inner,
+                mask.inner,
+                seed_point,
+                new_val,
+                lo_diff,
+                up_diff,
+                flags.bits(),
+                &mut rect,
+                r,
+            )
+        });
+        let result: Result<c_int, String> = result.into();
+        let area = result.map_err(CvError::UnknownError)?;
+        Ok((area, rect))
+    }
+
+    /// Performs a marker-based image segmentation using the watershed
+    /// algorithm.
+    ///
+    /// `self` must be an 8-bit 3-channel image. `markers` is a 32-bit
+    /// single-channel image of the same size, where each seeded region is
+    /// labeled with a positive index and unknown regions are 0. On return,
+    /// each pixel is set to the index of its region, or -1 at boundaries.
+    pub fn watershed(&self, markers: &mut Mat) -> Result<(), Error> {
+        let result = unsafe { cv_watershed(self.inner, markers.inner) };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(())
+    }
+
+    /// Calculates the distance to the closest zero pixel for each pixel of the
+    /// source image, which must be a single-channel 8-bit (binary) image, such
+    /// as the output of [threshold](#method.threshold).
+    ///
+    /// The output is a single-channel 32-bit floating-point image of the same
+    /// size.
+    pub fn distance_transform(
+        &self,
+        distance_type: DistanceType,
+        mask_size: DistanceTransformMask,
+    ) -> Result<Mat, Error> {
+        let dst = CMat::new();
+        let result = unsafe { cv_distance_transform(self.inner, dst, distance_type, mask_size) };
+        let dst = Mat::from_raw(dst);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(dst)
+    }
+
+    /// Same as [distance_transform](#method.distance_transform), but also
+    /// computes the discrete Voronoi diagram. Returns the distances and a
+    /// 32-bit signed integer `Mat` of labels.
+    ///
+    /// Only `DistanceType::L1`, `DistanceType::L2` and `DistanceType::C` are
+    /// supported, with a 3x3 or 5x5 mask.
+    pub fn distance_transform_with_labels(
+        &self,
+        distance_type: DistanceType,
+        mask_size: DistanceTransformMask,
+        label_type: DistanceTransformLabelType,
+    ) -> Result<(Mat, Mat), Error> {
+        let dst = CMat::new();
+        let labels = CMat::new();
+        let result =
+            unsafe { cv_distance_transform_with_labels(self.inner, dst, labels, distance_type, mask_size, label_type) };
+        let dst = Mat::from_raw(dst);
+        let labels = Mat::from_raw(labels);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok((dst, labels))
+    }
+
+    /// Calculates the integral image: a `(rows + 1) x (cols + 1)` image where
+    /// each pixel is the sum of all the source pixels above and to the left of
+    /// it. The sum of any rectangle can then be obtained in constant time, see
+    /// [integral_sum](#method.integral_sum).
+    ///
+    /// `sdepth` is the depth of the integral image (`CV_32S`, `CV_32F` or
+    /// `CV_64F`), or -1 to use `CV_32S` for 8-bit images and `CV_64F`
+    /// otherwise.
+    pub fn integral(&self, sdepth: c_int) -> Result<Mat, Error> {
+        let sum = CMat::new();
+        let result = unsafe { cv_integral(self.inner, sum, sdepth) };
+        let sum = Mat::from_raw(sum);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(sum)
+    }
+
+    /// Same as [integral](#method.integral), but also calculates the integral
+    /// image of the squared pixel values, which can be used to get the variance
+    /// of any rectangle. `sqdepth` is the depth of the squared sums (`CV_32F`
+    /// or `CV_64F`), or -1 for `CV_64F`.
+    pub fn integral2(&self, sdepth: c_int, sqdepth: c_int) -> Result<(Mat, Mat), Error> {
+        let sum = CMat::new();
+        let sqsum = CMat::new();
+        let result = unsafe { cv_integral2(self.inner, sum, sqsum, sdepth, sqdepth) };
+        let sum = Mat::from_raw(sum);
+        let sqsum = Mat::from_raw(sqsum);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok((sum, sqsum))
+    }
+
+    /// Same as [integral2](#method.integral2), but also calculates the
+    /// integral image rotated by 45 degrees, used for tilted Haar-like
+    /// features. Returns the sums, the squared sums and the tilted sums.
+    pub fn integral3(&self, sdepth: c_int, sqdepth: c_int) -> Result<(Mat, Mat, Mat), Error> {
+        let sum = CMat::new();
+        let sqsum = CMat::new();
+        let tilted = CMat::new();
+        let result = unsafe { cv_integral3(self.inner, sum, sqsum, tilted, sdepth, sqdepth) };
+        let sum = Mat::from_raw(sum);
+        let sqsum = Mat::from_raw(sqsum);
+        let tilted = Mat::from_raw(tilted);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok((sum, sqsum, tilted))
+    }
+
+    /// Returns the sum of the source pixels inside `rect`, where `self` is a
+    /// single-channel integral image computed by [integral](#method.integral)
+    /// (or the squared sums of [integral2](#method.integral2)).
+    ///
+    /// Returns `None` if `self` is not a single-channel `CV_32S`, `CV_32F` or
+    /// `CV_64F` image, or if `rect` does not fit in the source image.
+    pub fn integral_sum(&self, rect: Rect) -> Option<f64> {
+        if self.channels != 1 || rect.x < 0 || rect.y < 0 || rect.width < 0 || rect.height < 0 {
+            return None;
+        }
+        let (x0, y0) = (rect.x, rect.y);
+        let (x1, y1) = (x0.checked_add(rect.width)?, y0.checked_add(rect.height)?);
+        if x1 >= self.cols || y1 >= self.rows {
+            return None;
+        }
+        // Single-channel, so the depth is the value of the matching CvType
+        let at = |y, x| match self.depth {
+            d if d == CvType::Cv32SC1 as c_int => Some(f64::from(self.at2::<i32>(y, x))),
+            d if d == CvType::Cv32FC1 as c_int => Some(f64::from(self.at2::<f32>(y, x))),
+            d if d == CvType::Cv64FC1 as c_int => Some(self.at2::<f64>(y, x)),
+            _ => None,
+        };
+        Some(at(y1, x1)? - at(y0, x1)? - at(y1, x0)? + at(y0, x0)?)
+    }
+
+    /// Determines strong corners of a grayscale image, using either the
+    /// Shi-Tomasi or the Harris corner measure. The `mask`, if not empty,
+    /// selects the region where corners are searched.
+    pub fn good_features_to_track(&self, params: &GoodFeaturesParams, mask: &Mat) -> Result<Vec<Point2f>, Error> {
+        let mut corners = CVec::<Point2f>::default();
+        let result = unsafe {
+            cv_good_features_to_track(
+                self.inner,
+                &mut corners,
+                params.max_corners,
+                params.quality_level,
+                params.min_distance,
+                mask.inner,
+                params.block_size,
+                params.use_harris_detector,
+                params.k,
+            )
+        };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(corners.unpack())
+    }
+
+    /// Runs the Harris corner detector on a grayscale image. Returns an image
+    /// of the same size and `CV_32FC1` type with the Harris response of every
+    /// pixel.
+    ///
+    /// `block_size` is the neighborhood size, `ksize` the aperture of the
+    /// Sobel operator and `k` the Harris detector free parameter.
+    pub fn corner_harris(
+        &self,
+        block_size: c_int,
+        ksize: c_int,
+        k: f64,
+        border_type: BorderType,
+    ) -> Result<Mat, Error> {
+        let m = CMat::new();
+        let result = unsafe { cv_corner_harris(self.inner, m, block_size, ksize, k, border_type as c_int) };
+        let m = Mat::from_raw(m);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(m)
+    }
+
+    /// Calculates the minimal eigenvalue of the gradient matrices of a
+    /// grayscale image, i.e. the Shi-Tomasi corner measure of every pixel.
+    /// Returns an image of the same size and `CV_32FC1` type.
+    pub fn corner_min_eigen_val(&self, block_size: c_int, ksize: c_int, border_type: BorderType) -> Result<Mat, Error> {
+        let m = CMat::new();
+        let result = unsafe { cv_corner_min_eigen_val(self.inner, m, block_size, ksize, border_type as c_int) };
+        let m = Mat::from_raw(m);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(m)
+    }
+
+    /// Refines the location of `corners` to sub-pixel accuracy and returns
+    /// the refined corners, in the same order.
+    ///
+    /// `win_size` is half of the side of the search window, `zero_zone` is
+    /// half of the size of a dead region in the middle of the search window,
+    /// (-1, -1) for none.
+    pub fn corner_sub_pix(
+        &self,
+        corners: &[Point2f],
+        win_size: Size2i,
+        zero_zone: Size2i,
+        criteria: &TermCriteria,
+    ) -> Result<Vec<Point2f>, Error> {
+        let corners = CVecView::pack(&corners.to_vec());
+        let mut refined = CVec::<Point2f>::default();
+        let result = unsafe {
+            cv_corner_sub_pix(
+                self.inner,
+                &corners,
+                &mut refined,
+                win_size,
+                zero_zone,
+                criteria.c_criteria,
+            )
+        };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(refined.unpack())
+    }
+
     fn matrix_to_vec<T, MElem: AsRef<[T]>, M: AsRef<[MElem]>>(value: M) -> Vec<*const T> {
         value.as_ref().iter().map(|x| x.as_ref().as_ptr()).collect::<Vec<_>>()
     }
@@ -19,6 +19,7 @@ extern crate bytes;
 #[macro_use]
 extern crate failure;
 
+pub mod calib3d;
 pub mod core;
 #[cfg(feature = "cuda")]
 pub mod cuda;
@@ -23,6 +23,7 @@ impl CMat {
 extern "C" {
     fn cv_mat_new() -> *mut CMat;
     fn cv_mat_from_file_storage(path: *const c_char, section: *const c_char) -> *mut CMat;
+    fn cv_mat_to_file_storage(mat: *const CMat, path: *const c_char, section: *const c_char) -> CEmptyResult;
     fn cv_mat_new_with_size(rows: c_int, cols: c_int, t: c_int) -> *mut CMat;
     fn cv_mat_zeros(rows: c_int, cols: c_int, t: c_int) -> *mut CMat;
     fn cv_mat_from_buffer(rows: c_int, cols: c_int, t: CvType, buffer: *const u8) -> *mut CMat;
@@ -117,6 +118,19 @@ impl Mat {
         Ok(Mat::from_raw(result))
     }
 
+    /// Saves `Mat` to file storage (XML, YAML or JSON, depending on the file
+    /// extension) under `section`, replacing the file if it exists. It can be
+    /// read back with [from_file_storage](#method.from_file_storage).
+    pub fn to_file_storage<P: AsRef<Path>>(&self, path: P, section: &str) -> Result<(), Error> {
+        let path = path_to_cstring(path)?;
+        let section = CString::new(section)?;
+
+        let result = unsafe { cv_mat_to_file_storage(self.inner, path.as_ptr(), section.as_ptr()) };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(())
+    }
+
     #[inline]
     /// Creates a `Mat` object from raw `CMat` pointer. This will read the rows
     /// and cols of the image.
@@ -130,6 +144,17 @@ impl Mat {
         }
     }
 
+    #[inline]
+    /// Re-reads the rows, cols, depth and channels of the image, for functions
+    /// that (re)allocate a `Mat` passed as an output argument.
+    pub(crate) fn refresh_header(&mut self) {
+        let raw = self.inner;
+        self.rows = unsafe { cv_mat_rows(raw) };
+        self.cols = unsafe { cv_mat_cols(raw) };
+        self.depth = unsafe { cv_mat_depth(raw) };
+        self.channels = unsafe { cv_mat_channels(raw) };
+    }
+
     /// Creates an empty `Mat` struct.
     pub fn new() -> Mat {
         let m = CMat::new();
@@ -5,7 +5,12 @@ pub mod tracking {
     //! track](http://docs.opencv.org/3.1.0/dc/d6b/group__video__track.html)
 
     use core::*;
+    use errors::*;
+    use failure::Error;
     use mat::*;
+    use std::os::raw::{c_double, c_float, c_int};
+    use std::ptr;
+    use *;
 
     // =========================================================================
     //   VideoTrack
@@ -13,6 +18,18 @@ pub mod tracking {
 
     extern "C" {
         fn cv_camshift(image: *mut CMat, w: Rect, c_criteria: *const CTermCriteria) -> RotatedRect;
+        fn cv_mean_shift(
+            image: *const CMat,
+            window: *mut Rect,
+            criteria: *const CTermCriteria,
+            result: *mut CResult<c_int>,
+        );
+        fn cv_cam_shift(
+            image: *const CMat,
+            window: *mut Rect,
+            criteria: *const CTermCriteria,
+            result: *mut CResult<RotatedRect>,
+        );
     }
 
     impl Mat {
@@ -20,13 +37,936 @@ pub mod tracking {
         ///
         /// * `wndw` - initial search window.
         /// * `criteria` - stop criteria for the underlying meanShift.
+        #[deprecated(note = "use `cam_shift`, which also returns the updated search window")]
         pub fn camshift(&self, wndw: Rect, criteria: &TermCriteria) -> RotatedRect {
             unsafe { cv_camshift(self.inner, wndw, criteria.c_criteria) }
         }
+
+        /// Moves `window` to the local maximum of the back projection `self`
+        /// (e.g. from [calc_hue_back_project](fn.calc_hue_back_project.html))
+        /// and returns the number of iterations.
+        pub fn mean_shift(&self, window: &mut Rect, criteria: &TermCriteria) -> Result<c_int, Error> {
+            let result = CResult::<c_int>::from_callback(|r| unsafe {
+                cv_mean_shift(self.inner, window, criteria.c_criteria, r)
+            });
+            let result: Result<c_int, String> = result.into();
+            let iterations = result.map_err(CvError::UnknownError)?;
+            Ok(iterations)
+        }
+
+        /// Finds the object center, size and orientation in the back
+        /// projection `self` like [mean_shift](#method.mean_shift), adapting
+        /// the size of the window. `window` is updated to the search window
+        /// of the next frame.
+        pub fn cam_shift(&self, window: &mut Rect, criteria: &TermCriteria) -> Result<RotatedRect, Error> {
+            let result = CResult::<RotatedRect>::from_callback(|r| unsafe {
+                cv_cam_shift(self.inner, window, criteria.c_criteria, r)
+            });
+            let result: Result<RotatedRect, String> = result.into();
+            let object = result.map_err(CvError::UnknownError)?;
+            Ok(object)
+        }
+    }
+
+    /// Hue range of 8-bit HSV images
+    const HUE_RANGES: [[f32; 2]; 1] = [[0.0, 180.0]];
+
+    /// Computes the color model of an object for
+    /// [calc_hue_back_project](fn.calc_hue_back_project.html): the hue
+    /// histogram of the pixels of `roi` in an 8-bit HSV image (see
+    /// `ColorConversion::BGR2HSV`), normalized to `0..255`. Only the pixels
+    /// between `lowerb` and `upperb` are counted, which excludes pixels with a
+    /// low saturation or value, whose hue is unreliable.
+    pub fn calc_hue_histogram(hsv: &Mat, roi: Rect, bins: c_int, lowerb: Scalar, upperb: Scalar) -> Mat {
+        let object = hsv.roi(roi);
+        let hue = object.mix_channels(1, 1, &[(0, 0)]);
+        let mask = object.in_range(lowerb, upperb);
+        let hist = hue.calc_hist(&[0], &mask, &[bins], &HUE_RANGES);
+        hist.normalize(0.0, 255.0, NormType::MinMax)
+    }
+
+    /// Computes the back projection of a hue histogram from
+    /// [calc_hue_histogram](fn.calc_hue_histogram.html) on an 8-bit HSV
+    /// image, the probability of each pixel to belong to the object. The
+    /// pixels outside of `lowerb` and `upperb` are set to 0. The result is
+    /// the input of [Mat::mean_shift](../../struct.Mat.html#method.mean_shift)
+    /// and [Mat::cam_shift](../../struct.Mat.html#method.cam_shift).
+    pub fn calc_hue_back_project(hsv: &Mat, hist: &Mat, lowerb: Scalar, upperb: Scalar) -> Mat {
+        let hue = hsv.mix_channels(1, 1, &[(0, 0)]);
+        hue.calc_back_project(&[0], hist, &HUE_RANGES) & hsv.in_range(lowerb, upperb)
+    }
+
+    // =========================================================================
+    //   Kalman Filter
+    // =========================================================================
+
+    enum CKalmanFilter {}
+
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy)]
+    enum KalmanMatrix {
+        StatePre = 0,
+        StatePost = 1,
+        TransitionMatrix = 2,
+        ControlMatrix = 3,
+        MeasurementMatrix = 4,
+        ProcessNoiseCov = 5,
+        MeasurementNoiseCov = 6,
+        ErrorCovPre = 7,
+        ErrorCovPost = 8,
+        Gain = 9,
+    }
+
+    extern "C" {
+        fn cv_kalman_filter_new(
+            dynam_params: c_int,
+            measure_params: c_int,
+            control_params: c_int,
+            cv_type: CvType,
+        ) -> *mut CKalmanFilter;
+        fn cv_kalman_filter_drop(kf: *mut CKalmanFilter);
+        fn cv_kalman_filter_predict(kf: *mut CKalmanFilter, control: *const CMat, state: *mut CMat) -> CEmptyResult;
+        fn cv_kalman_filter_correct(kf: *mut CKalmanFilter, measurement: *const CMat, state: *mut CMat)
+            -> CEmptyResult;
+        fn cv_kalman_filter_get_matrix(kf: *const CKalmanFilter, which: KalmanMatrix, matrix: *mut CMat);
+        fn cv_kalman_filter_set_matrix(
+            kf: *mut CKalmanFilter,
+            which: KalmanMatrix,
+            matrix: *const CMat,
+        ) -> CEmptyResult;
+    }
+
+    /// Standard Kalman filter, e.g. to smooth and predict the position of a
+    /// tracked object.
+    ///
+    /// The state `x(k) = A x(k-1) + B u(k-1) + w(k-1)` is observed through
+    /// the measurements `z(k) = H x(k) + v(k)`, where `A` is the transition
+    /// matrix, `B` the control matrix, `H` the measurement matrix, and `w`
+    /// and `v` are the process and measurement noises. All the matrices are
+    /// initialized to zero except the transition matrix and the error
+    /// covariances, initialized to the identity, so at least the transition
+    /// and measurement matrices have to be set before filtering.
+    #[derive(Debug)]
+    pub struct KalmanFilter {
+        value: *mut CKalmanFilter,
+    }
+
+    impl Drop for KalmanFilter {
+        fn drop(&mut self) {
+            unsafe {
+                cv_kalman_filter_drop(self.value);
+            }
+        }
+    }
+
+    impl KalmanFilter {
+        /// Creates a new Kalman filter with `dynam_params` state variables,
+        /// `measure_params` measured variables and `control_params` control
+        /// variables (0 without control). `cv_type` is `Cv32FC1` or `Cv64FC1`.
+        pub fn new(
+            dynam_params: c_int,
+            measure_params: c_int,
+            control_params: c_int,
+            cv_type: CvType,
+        ) -> Result<Self, Error> {
+            if dynam_params <= 0 || measure_params <= 0 || control_params < 0 {
+                return Err(CvError::InvalidArgument(format!(
+                    "invalid dimensions: {} state, {} measurement and {} control parameters",
+                    dynam_params, measure_params, control_params
+                ))
+                .into());
+            }
+            if cv_type != CvType::Cv32FC1 && cv_type != CvType::Cv64FC1 {
+                return Err(CvError::InvalidArgument(format!("expected Cv32FC1 or Cv64FC1, got {:?}", cv_type)).into());
+            }
+            let value = unsafe { cv_kalman_filter_new(dynam_params, measure_params, control_params, cv_type) };
+            Ok(KalmanFilter { value })
+        }
+
+        /// Predicts the next state from the current one and the optional
+        /// control vector, and returns the predicted state (`state_pre`).
+        pub fn predict(&mut self, control: Option<&Mat>) -> Result<Mat, Error> {
+            let state = CMat::new();
+            let result = unsafe { cv_kalman_filter_predict(self.value, inner_or_null(control), state) };
+            let state = Mat::from_raw(state);
+            let result: Result<(), String> = result.into();
+            result.map_err(CvError::UnknownError)?;
+            Ok(state)
+        }
+
+        /// Updates the predicted state with a measurement (a column vector)
+        /// and returns the corrected state (`state_post`).
+        pub fn correct(&mut self, measurement: &Mat) -> Result<Mat, Error> {
+            let state = CMat::new();
+            let result = unsafe { cv_kalman_filter_correct(self.value, measurement.inner, state) };
+            let state = Mat::from_raw(state);
+            let result: Result<(), String> = result.into();
+            result.map_err(CvError::UnknownError)?;
+            Ok(state)
+        }
+
+        fn matrix(&self, which: KalmanMatrix) -> Mat {
+            let matrix = CMat::new();
+            unsafe {
+                cv_kalman_filter_get_matrix(self.value, which, matrix);
+            }
+            Mat::from_raw(matrix)
+        }
+
+        fn set_matrix(&mut self, which: KalmanMatrix, matrix: &Mat) -> Result<(), Error> {
+            let result = unsafe { cv_kalman_filter_set_matrix(self.value, which, matrix.inner) };
+            let result: Result<(), String> = result.into();
+            result.map_err(CvError::UnknownError)?;
+            Ok(())
+        }
+
+        /// Returns the predicted state `x'(k)`
+        pub fn state_pre(&self) -> Mat {
+            self.matrix(KalmanMatrix::StatePre)
+        }
+
+        /// Sets the predicted state. The matrix must have the same size and
+        /// type as the current one, which holds for all the setters.
+        pub fn set_state_pre(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::StatePre, matrix)
+        }
+
+        /// Returns the corrected state `x(k)`
+        pub fn state_post(&self) -> Mat {
+            self.matrix(KalmanMatrix::StatePost)
+        }
+
+        /// Sets the corrected state, e.g. to initialize the filter with the
+        /// first measurement
+        pub fn set_state_post(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::StatePost, matrix)
+        }
+
+        /// Returns the transition matrix `A`
+        pub fn transition_matrix(&self) -> Mat {
+            self.matrix(KalmanMatrix::TransitionMatrix)
+        }
+
+        /// Sets the transition matrix `A`
+        pub fn set_transition_matrix(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::TransitionMatrix, matrix)
+        }
+
+        /// Returns the control matrix `B`, empty without control
+        pub fn control_matrix(&self) -> Mat {
+            self.matrix(KalmanMatrix::ControlMatrix)
+        }
+
+        /// Sets the control matrix `B`
+        pub fn set_control_matrix(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::ControlMatrix, matrix)
+        }
+
+        /// Returns the measurement matrix `H`
+        pub fn measurement_matrix(&self) -> Mat {
+            self.matrix(KalmanMatrix::MeasurementMatrix)
+        }
+
+        /// Sets the measurement matrix `H`
+        pub fn set_measurement_matrix(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::MeasurementMatrix, matrix)
+        }
+
+        /// Returns the process noise covariance matrix `Q`
+        pub fn process_noise_cov(&self) -> Mat {
+            self.matrix(KalmanMatrix::ProcessNoiseCov)
+        }
+
+        /// Sets the process noise covariance matrix `Q`
+        pub fn set_process_noise_cov(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::ProcessNoiseCov, matrix)
+        }
+
+        /// Returns the measurement noise covariance matrix `R`
+        pub fn measurement_noise_cov(&self) -> Mat {
+            self.matrix(KalmanMatrix::MeasurementNoiseCov)
+        }
+
+        /// Sets the measurement noise covariance matrix `R`
+        pub fn set_measurement_noise_cov(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::MeasurementNoiseCov, matrix)
+        }
+
+        /// Returns the a priori error covariance matrix `P'(k)`
+        pub fn error_cov_pre(&self) -> Mat {
+            self.matrix(KalmanMatrix::ErrorCovPre)
+        }
+
+        /// Sets the a priori error covariance matrix `P'(k)`
+        pub fn set_error_cov_pre(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::ErrorCovPre, matrix)
+        }
+
+        /// Returns the a posteriori error covariance matrix `P(k)`
+        pub fn error_cov_post(&self) -> Mat {
+            self.matrix(KalmanMatrix::ErrorCovPost)
+        }
+
+        /// Sets the a posteriori error covariance matrix `P(k)`
+        pub fn set_error_cov_post(&mut self, matrix: &Mat) -> Result<(), Error> {
+            self.set_matrix(KalmanMatrix::ErrorCovPost, matrix)
+        }
+
+        /// Returns the Kalman gain `K(k)` of the last correction
+        pub fn gain(&self) -> Mat {
+            self.matrix(KalmanMatrix::Gain)
+        }
+    }
+
+    // =========================================================================
+    //   Optical Flow
+    // =========================================================================
+
+    enum CDISOpticalFlow {}
+
+    extern "C" {
+        fn cv_calc_optical_flow_farneback(
+            prev: *const CMat,
+            next: *const CMat,
+            flow: *mut CMat,
+            initial_flow: *const CMat,
+            pyr_scale: c_double,
+            levels: c_int,
+            winsize: c_int,
+            iterations: c_int,
+            poly_n: c_int,
+            poly_sigma: c_double,
+            gaussian: bool,
+        ) -> CEmptyResult;
+        fn cv_dis_optical_flow_new(preset: DISPreset) -> *mut CDISOpticalFlow;
+        fn cv_dis_optical_flow_drop(dis: *mut CDISOpticalFlow);
+        fn cv_dis_optical_flow_calc(
+            dis: *mut CDISOpticalFlow,
+            i0: *const CMat,
+            i1: *const CMat,
+            flow: *mut CMat,
+            initial_flow: *const CMat,
+        ) -> CEmptyResult;
+        fn cv_dis_optical_flow_get_finest_scale(dis: *const CDISOpticalFlow) -> c_int;
+        fn cv_dis_optical_flow_set_finest_scale(dis: *mut CDISOpticalFlow, value: c_int);
+        fn cv_dis_optical_flow_get_patch_size(dis: *const CDISOpticalFlow) -> c_int;
+        fn cv_dis_optical_flow_set_patch_size(dis: *mut CDISOpticalFlow, value: c_int);
+        fn cv_dis_optical_flow_get_patch_stride(dis: *const CDISOpticalFlow) -> c_int;
+        fn cv_dis_optical_flow_set_patch_stride(dis: *mut CDISOpticalFlow, value: c_int);
+        fn cv_dis_optical_flow_get_gradient_descent_iterations(dis: *const CDISOpticalFlow) -> c_int;
+        fn cv_dis_optical_flow_set_gradient_descent_iterations(dis: *mut CDISOpticalFlow, value: c_int);
+        fn cv_dis_optical_flow_get_variational_refinement_iterations(dis: *const CDISOpticalFlow) -> c_int;
+        fn cv_dis_optical_flow_set_variational_refinement_iterations(dis: *mut CDISOpticalFlow, value: c_int);
+        fn cv_dis_optical_flow_get_use_spatial_propagation(dis: *const CDISOpticalFlow) -> bool;
+        fn cv_dis_optical_flow_set_use_spatial_propagation(dis: *mut CDISOpticalFlow, value: bool);
+        fn cv_flow_to_color(flow: *const CMat, max_magnitude: c_float, color: *mut CMat) -> CEmptyResult;
+        fn cv_calc_optical_flow_pyr_lk(
+            prev: *const CMat,
+            next: *const CMat,
+            prev_pts: *const CVecView<Point2f>,
+            win_size: Size2i,
+            max_level: c_int,
+            criteria: *const CTermCriteria,
+            next_pts: *mut CVec<Point2f>,
+            status: *mut CVec<u8>,
+            err: *mut CVec<c_float>,
+        ) -> CEmptyResult;
+        fn cv_calc_optical_flow_pyr_lk_pyramids(
+            prev: *const CVecView<*mut CMat>,
+            next: *const CVecView<*mut CMat>,
+            prev_pts: *const CVecView<Point2f>,
+            win_size: Size2i,
+            max_level: c_int,
+            criteria: *const CTermCriteria,
+            next_pts: *mut CVec<Point2f>,
+            status: *mut CVec<u8>,
+            err: *mut CVec<c_float>,
+        ) -> CEmptyResult;
+        fn cv_build_optical_flow_pyramid(
+            image: *const CMat,
+            win_size: Size2i,
+            max_level: c_int,
+            with_derivatives: bool,
+            pyramid: *mut CVec<*mut CMat>,
+        ) -> CEmptyResult;
+    }
+
+    fn inner_or_null(mat: Option<&Mat>) -> *const CMat {
+        mat.map_or(ptr::null(), |m| m.inner)
+    }
+
+    /// Parameters of
+    /// [calc_optical_flow_farneback](fn.calc_optical_flow_farneback.html)
+    #[derive(Debug, Clone, Copy)]
+    pub struct FarnebackParams {
+        /// Scale between two consecutive pyramid levels, less than 1
+        pub pyr_scale: f64,
+        /// Number of pyramid levels, 1 meaning only the original images
+        pub levels: c_int,
+        /// Averaging window size. Larger values are more robust to noise and
+        /// detect faster motions, but blur the motion field.
+        pub winsize: c_int,
+        /// Number of iterations at each pyramid level
+        pub iterations: c_int,
+        /// Size of the pixel neighborhood used for the polynomial expansion,
+        /// typically 5 or 7
+        pub poly_n: c_int,
+        /// Standard deviation of the Gaussian smoothing the derivatives of the
+        /// polynomial expansion, typically 1.1 for `poly_n = 5` and 1.5 for
+        /// `poly_n = 7`
+        pub poly_sigma: f64,
+        /// Uses a Gaussian window instead of a box filter, slower but usually
+        /// more accurate
+        pub gaussian: bool,
+    }
+
+    impl Default for FarnebackParams {
+        fn default() -> Self {
+            FarnebackParams {
+                pyr_scale: 0.5,
+                levels: 3,
+                winsize: 15,
+                iterations: 3,
+                poly_n: 5,
+                poly_sigma: 1.2,
+                gaussian: false,
+            }
+        }
+    }
+
+    /// Computes the dense optical flow between two single channel 8-bit
+    /// frames using the Gunnar Farneback algorithm. Returns a `Cv32FC2` flow
+    /// such that `prev(y, x)` moved to `next(y + flow(y, x).1, x + flow(y,
+    /// x).0)`. `initial_flow`, e.g. the flow of the previous frame pair, is
+    /// used as the initial approximation.
+    pub fn calc_optical_flow_farneback(
+        prev: &Mat,
+        next: &Mat,
+        params: &FarnebackParams,
+        initial_flow: Option<&Mat>,
+    ) -> Result<Mat, Error> {
+        let flow = CMat::new();
+        let result = unsafe {
+            cv_calc_optical_flow_farneback(
+                prev.inner,
+                next.inner,
+                flow,
+                inner_or_null(initial_flow),
+                params.pyr_scale,
+                params.levels,
+                params.winsize,
+                params.iterations,
+                params.poly_n,
+                params.poly_sigma,
+                params.gaussian,
+            )
+        };
+        let flow = Mat::from_raw(flow);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(flow)
+    }
+
+    /// Speed/quality trade-off of [DISOpticalFlow](struct.DISOpticalFlow.html)
+    #[repr(C)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum DISPreset {
+        /// Fastest, least accurate
+        UltraFast = 0,
+        /// Fast
+        Fast = 1,
+        /// Slowest, most accurate
+        Medium = 2,
+    }
+
+    /// Dense optical flow using the Dense Inverse Search algorithm. Requires
+    /// OpenCV 4.
+    #[derive(Debug)]
+    pub struct DISOpticalFlow {
+        value: *mut CDISOpticalFlow,
+    }
+
+    impl Drop for DISOpticalFlow {
+        fn drop(&mut self) {
+            unsafe {
+                cv_dis_optical_flow_drop(self.value);
+            }
+        }
+    }
+
+    impl DISOpticalFlow {
+        /// Creates a new DIS optical flow with the parameters of the given
+        /// preset. Fails with OpenCV 3.
+        pub fn new(preset: DISPreset) -> Result<Self, Error> {
+            let value = unsafe { cv_dis_optical_flow_new(preset) };
+            if value.is_null() {
+                return Err(CvError::UnknownError("DISOpticalFlow requires OpenCV 4".into()).into());
+            }
+            Ok(DISOpticalFlow { value })
+        }
+
+        /// Computes the dense optical flow between two single channel 8-bit
+        /// frames, see
+        /// [calc_optical_flow_farneback](fn.calc_optical_flow_farneback.html)
+        /// for the layout of the returned `Cv32FC2` flow.
+        pub fn calc(&mut self, i0: &Mat, i1: &Mat, initial_flow: Option<&Mat>) -> Result<Mat, Error> {
+            let flow = CMat::new();
+            let result =
+                unsafe { cv_dis_optical_flow_calc(self.value, i0.inner, i1.inner, flow, inner_or_null(initial_flow)) };
+            let flow = Mat::from_raw(flow);
+            let result: Result<(), String> = result.into();
+            result.map_err(CvError::UnknownError)?;
+            Ok(flow)
+        }
+
+        /// Returns the finest pyramid level of the computation, 0 being the
+        /// original resolution
+        pub fn finest_scale(&self) -> c_int {
+            unsafe { cv_dis_optical_flow_get_finest_scale(self.value) }
+        }
+
+        /// Sets the finest pyramid level of the computation
+        pub fn set_finest_scale(&mut self, value: c_int) {
+            unsafe { cv_dis_optical_flow_set_finest_scale(self.value, value) }
+        }
+
+        /// Returns the size of the matched patches
+        pub fn patch_size(&self) -> c_int {
+            unsafe { cv_dis_optical_flow_get_patch_size(self.value) }
+        }
+
+        /// Sets the size of the matched patches
+        pub fn set_patch_size(&mut self, value: c_int) {
+            unsafe { cv_dis_optical_flow_set_patch_size(self.value, value) }
+        }
+
+        /// Returns the stride between neighbor patches, less than the patch
+        /// size
+        pub fn patch_stride(&self) -> c_int {
+            unsafe { cv_dis_optical_flow_get_patch_stride(self.value) }
+        }
+
+        /// Sets the stride between neighbor patches
+        pub fn set_patch_stride(&mut self, value: c_int) {
+            unsafe { cv_dis_optical_flow_set_patch_stride(self.value, value) }
+        }
+
+        /// Returns the number of gradient descent iterations of the patch
+        /// inverse search
+        pub fn gradient_descent_iterations(&self) -> c_int {
+            unsafe { cv_dis_optical_flow_get_gradient_descent_iterations(self.value) }
+        }
+
+        /// Sets the number of gradient descent iterations of the patch
+        /// inverse search
+        pub fn set_gradient_descent_iterations(&mut self, value: c_int) {
+            unsafe { cv_dis_optical_flow_set_gradient_descent_iterations(self.value, value) }
+        }
+
+        /// Returns the number of variational refinement iterations, 0
+        /// disabling the refinement
+        pub fn variational_refinement_iterations(&self) -> c_int {
+            unsafe { cv_dis_optical_flow_get_variational_refinement_iterations(self.value) }
+        }
+
+        /// Sets the number of variational refinement iterations
+        pub fn set_variational_refinement_iterations(&mut self, value: c_int) {
+            unsafe { cv_dis_optical_flow_set_variational_refinement_iterations(self.value, value) }
+        }
+
+        /// Returns whether the flow of the neighbor patches is propagated
+        pub fn use_spatial_propagation(&self) -> bool {
+            unsafe { cv_dis_optical_flow_get_use_spatial_propagation(self.value) }
+        }
+
+        /// Sets whether the flow of the neighbor patches is propagated, which
+        /// is usually more accurate for the same speed
+        pub fn set_use_spatial_propagation(&mut self, value: bool) {
+            unsafe { cv_dis_optical_flow_set_use_spatial_propagation(self.value, value) }
+        }
+    }
+
+    /// Renders a `Cv32FC2` flow as a BGR image for visualization: the hue is
+    /// the direction of the motion and the brightness its magnitude, full
+    /// brightness corresponding to `max_magnitude` pixels (the largest motion
+    /// of the flow if `None`).
+    pub fn flow_to_color(flow: &Mat, max_magnitude: Option<f32>) -> Result<Mat, Error> {
+        let color = CMat::new();
+        let result = unsafe { cv_flow_to_color(flow.inner, max_magnitude.unwrap_or(0.0), color) };
+        let color = Mat::from_raw(color);
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(color)
+    }
+
+    /// Tracks sparse points from one single channel 8-bit frame to the next
+    /// using the iterative Lucas-Kanade method with pyramids. `win_size` is the
+    /// search window at each pyramid level, and `max_level` the index of the
+    /// coarsest level (0 disables the pyramids).
+    ///
+    /// Returns the tracked points, whether each point was found, and the
+    /// tracking error of each point. The points of `prev_pts` are usually
+    /// found with `GFTTDetector` or refined corners.
+    pub fn calc_optical_flow_pyr_lk(
+        prev: &Mat,
+        next: &Mat,
+        prev_pts: &[Point2f],
+        win_size: Size2i,
+        max_level: c_int,
+        criteria: &TermCriteria,
+    ) -> Result<(Vec<Point2f>, Vec<bool>, Vec<f32>), Error> {
+        let prev_pts = CVecView::pack(&prev_pts.to_vec());
+        let mut next_pts = CVec::<Point2f>::default();
+        let mut status = CVec::<u8>::default();
+        let mut err = CVec::<c_float>::default();
+        let result = unsafe {
+            cv_calc_optical_flow_pyr_lk(
+                prev.inner,
+                next.inner,
+                &prev_pts,
+                win_size,
+                max_level,
+                criteria.c_criteria,
+                &mut next_pts,
+                &mut status,
+                &mut err,
+            )
+        };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(unpack_tracks(next_pts, status, err))
+    }
+
+    /// Same as [calc_optical_flow_pyr_lk](fn.calc_optical_flow_pyr_lk.html),
+    /// but with the pyramids of both frames built by
+    /// [build_optical_flow_pyramid](fn.build_optical_flow_pyramid.html), so
+    /// that the pyramid of a frame is computed once when tracking over a
+    /// sequence. `win_size` and `max_level` should match the ones used to
+    /// build the pyramids.
+    pub fn calc_optical_flow_pyr_lk_pyramids(
+        prev_pyramid: &[Mat],
+        next_pyramid: &[Mat],
+        prev_pts: &[Point2f],
+        win_size: Size2i,
+        max_level: c_int,
+        criteria: &TermCriteria,
+    ) -> Result<(Vec<Point2f>, Vec<bool>, Vec<f32>), Error> {
+        let prev_pyramid = CVecView::pack(&prev_pyramid.iter().map(|m| m.inner).collect());
+        let next_pyramid = CVecView::pack(&next_pyramid.iter().map(|m| m.inner).collect());
+        let prev_pts = CVecView::pack(&prev_pts.to_vec());
+        let mut next_pts = CVec::<Point2f>::default();
+        let mut status = CVec::<u8>::default();
+        let mut err = CVec::<c_float>::default();
+        let result = unsafe {
+            cv_calc_optical_flow_pyr_lk_pyramids(
+                &prev_pyramid,
+                &next_pyramid,
+                &prev_pts,
+                win_size,
+                max_level,
+                criteria.c_criteria,
+                &mut next_pts,
+                &mut status,
+                &mut err,
+            )
+        };
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(unpack_tracks(next_pts, status, err))
+    }
+
+    fn unpack_tracks(
+        next_pts: CVec<Point2f>,
+        status: CVec<u8>,
+        err: CVec<c_float>,
+    ) -> (Vec<Point2f>, Vec<bool>, Vec<f32>) {
+        let status = status.unpack().into_iter().map(|s| s != 0).collect();
+        (next_pts.unpack(), status, err.unpack())
+    }
+
+    /// Builds the image pyramid used by
+    /// [calc_optical_flow_pyr_lk_pyramids](fn.calc_optical_flow_pyr_lk_pyramids.html).
+    /// With `with_derivatives`, each level is followed by its `Cv16SC2`
+    /// gradient. Fewer than `max_level + 1` levels are built for small
+    /// images.
+    pub fn build_optical_flow_pyramid(
+        image: &Mat,
+        win_size: Size2i,
+        max_level: c_int,
+        with_derivatives: bool,
+    ) -> Result<Vec<Mat>, Error> {
+        let mut pyramid = CVec::<*mut CMat>::default();
+        let result =
+            unsafe { cv_build_optical_flow_pyramid(image.inner, win_size, max_level, with_derivatives, &mut pyramid) };
+        let pyramid = pyramid.unpack().into_iter().map(Mat::from_raw).collect();
+        let result: Result<(), String> = result.into();
+        result.map_err(CvError::UnknownError)?;
+        Ok(pyramid)
     }
 }
 
 pub mod analysis {
     //! Motion Analysis, see [OpenCV video
     //! motion](http://docs.cv.org/3.1.0/de/de1/group__video__motion.html)
+
+    use self::private::*;
+    use errors::*;
+    use failure::Error;
+    use std::os::raw::{c_double, c_int};
+    use *;
+
+    // =========================================================================
+    //   Background Subtraction
+    // =========================================================================
+
+    extern "C" {
+        fn cv_background_subtractor_drop(subtractor: *mut CBackgroundSubtractor);
+        fn cv_background_subtractor_apply(
+            subtractor: *mut CBackgroundSubtractor,
+            image: *const CMat,
+            fg_mask: *mut CMat,
+            learning_rate: c_double,
+        ) -> CEmptyResult;
+        fn cv_background_subtractor_get_background_image(
+            subtractor: *const CBackgroundSubtractor,
+            background: *mut CMat,
+        ) -> CEmptyResult;
+        fn cv_background_subtractor_mog2_new(
+            history: c_int,
+            var_threshold: c_double,
+            detect_shadows: bool,
+        ) -> *mut CBackgroundSubtractor;
+        fn cv_background_subtractor_mog2_get_history(subtractor: *const CBackgroundSubtractor) -> c_int;
+        fn cv_background_subtractor_mog2_get_var_threshold(subtractor: *const CBackgroundSubtractor) -> c_double;
+        fn cv_background_subtractor_mog2_get_detect_shadows(subtractor: *const CBackgroundSubtractor) -> bool;
+        fn cv_background_subtractor_knn_new(
+            history: c_int,
+            dist2_threshold: c_double,
+            detect_shadows: bool,
+        ) -> *mut CBackgroundSubtractor;
+        fn cv_background_subtractor_knn_get_history(subtractor: *const CBackgroundSubtractor) -> c_int;
+        fn cv_background_subtractor_knn_get_dist2_threshold(subtractor: *const CBackgroundSubtractor) -> c_double;
+        fn cv_background_subtractor_knn_get_detect_shadows(subtractor: *const CBackgroundSubtractor) -> bool;
+    }
+
+    mod private {
+        #[allow(missing_copy_implementations, missing_debug_implementations)]
+        pub enum CBackgroundSubtractor {}
+
+        pub trait BackgroundSubtractorImpl {
+            fn get_value(&self) -> *mut CBackgroundSubtractor;
+        }
+    }
+
+    #[allow(missing_docs)]
+    pub trait BackgroundSubtractorImplInterface: BackgroundSubtractorImpl {}
+
+    /// Pixel value of the shadows in the foreground masks, when shadow
+    /// detection is enabled
+    pub const SHADOW_VALUE: u8 = 127;
+
+    /// Basic trait for the background subtraction algorithms, which model the
+    /// background of a fixed camera to segment the moving objects.
+    pub trait BackgroundSubtractor {
+        /// Updates the background model with the next frame and returns the
+        /// `Cv8UC1` foreground mask: 255 for the foreground, `SHADOW_VALUE`
+        /// for the shadows and 0 for the background. `learning_rate` is
+        /// between 0 (the model is not updated) and 1 (the model is
+        /// reinitialized from this frame), or negative to derive it from the
+        /// history length.
+        fn apply(&mut self, image: &Mat, learning_rate: f64) -> Result<Mat, Error>;
+
+        /// Returns the current background image, e.g. to check the model
+        fn get_background_image(&self) -> Result<Mat, Error>;
+    }
+
+    impl<T: BackgroundSubtractorImplInterface> BackgroundSubtractor for T {
+        fn apply(&mut self, image: &Mat, learning_rate: f64) -> Result<Mat, Error> {
+            let fg_mask = CMat::new();
+            let result =
+                unsafe { cv_background_subtractor_apply(self.get_value(), image.inner, fg_mask, learning_rate) };
+            let fg_mask = Mat::from_raw(fg_mask);
+            let result: Result<(), String> = result.into();
+            result.map_err(CvError::UnknownError)?;
+            Ok(fg_mask)
+        }
+
+        fn get_background_image(&self) -> Result<Mat, Error> {
+            let background = CMat::new();
+            let result = unsafe { cv_background_subtractor_get_background_image(self.get_value(), background) };
+            let background = Mat::from_raw(background);
+            let result: Result<(), String> = result.into();
+            result.map_err(CvError::UnknownError)?;
+            Ok(background)
+        }
+    }
+
+    macro_rules! impl_background_subtractor {
+        ($x:ident) => {
+            impl Drop for $x {
+                fn drop(&mut self) {
+                    unsafe {
+                        cv_background_subtractor_drop(self.value);
+                    }
+                }
+            }
+
+            impl BackgroundSubtractorImpl for $x {
+                fn get_value(&self) -> *mut CBackgroundSubtractor {
+                    self.value
+                }
+            }
+
+            impl BackgroundSubtractorImplInterface for $x {}
+        };
+    }
+
+    /// Gaussian mixture-based background segmentation, with an adaptive
+    /// number of Gaussian components per pixel
+    #[derive(Debug)]
+    pub struct BackgroundSubtractorMOG2 {
+        value: *mut CBackgroundSubtractor,
+    }
+
+    impl BackgroundSubtractorMOG2 {
+        /// Creates a new MOG2 background subtractor. Prefer
+        /// [BackgroundSubtractorMOG2Builder](struct.BackgroundSubtractorMOG2Builder.html),
+        /// which provides the defaults.
+        pub fn new(history: c_int, var_threshold: f64, detect_shadows: bool) -> Self {
+            let value = unsafe { cv_background_subtractor_mog2_new(history, var_threshold, detect_shadows) };
+            BackgroundSubtractorMOG2 { value }
+        }
+
+        /// Returns the number of last frames that affect the background model
+        pub fn history(&self) -> c_int {
+            unsafe { cv_background_subtractor_mog2_get_history(self.value) }
+        }
+
+        /// Returns the threshold on the squared Mahalanobis distance between
+        /// a pixel and the model to decide whether it is well described by
+        /// the background model
+        pub fn var_threshold(&self) -> f64 {
+            unsafe { cv_background_subtractor_mog2_get_var_threshold(self.value) }
+        }
+
+        /// Returns whether the shadows are detected and marked in the
+        /// foreground masks
+        pub fn detect_shadows(&self) -> bool {
+            unsafe { cv_background_subtractor_mog2_get_detect_shadows(self.value) }
+        }
+    }
+
+    impl_background_subtractor!(BackgroundSubtractorMOG2);
+
+    /// Builder that provides defaults for BackgroundSubtractorMOG2
+    #[derive(Debug, Copy, Clone, Default)]
+    pub struct BackgroundSubtractorMOG2Builder {
+        history: Option<c_int>,
+        var_threshold: Option<f64>,
+        detect_shadows: Option<bool>,
+    }
+
+    impl BackgroundSubtractorMOG2Builder {
+        /// Replace current history with specified value
+        pub fn history(mut self, value: c_int) -> Self {
+            self.history = Some(value);
+            self
+        }
+
+        /// Replace current var_threshold with specified value
+        pub fn var_threshold(mut self, value: f64) -> Self {
+            self.var_threshold = Some(value);
+            self
+        }
+
+        /// Replace current detect_shadows with specified value
+        pub fn detect_shadows(mut self, value: bool) -> Self {
+            self.detect_shadows = Some(value);
+            self
+        }
+    }
+
+    impl Into<BackgroundSubtractorMOG2> for BackgroundSubtractorMOG2Builder {
+        fn into(self) -> BackgroundSubtractorMOG2 {
+            BackgroundSubtractorMOG2::new(
+                self.history.unwrap_or(500),
+                self.var_threshold.unwrap_or(16.0),
+                self.detect_shadows.unwrap_or(true),
+            )
+        }
+    }
+
+    /// K-nearest neighbours based background segmentation, efficient when
+    /// the foreground pixels are few
+    #[derive(Debug)]
+    pub struct BackgroundSubtractorKNN {
+        value: *mut CBackgroundSubtractor,
+    }
+
+    impl BackgroundSubtractorKNN {
+        /// Creates a new KNN background subtractor. Prefer
+        /// [BackgroundSubtractorKNNBuilder](struct.BackgroundSubtractorKNNBuilder.html),
+        /// which provides the defaults.
+        pub fn new(history: c_int, dist2_threshold: f64, detect_shadows: bool) -> Self {
+            let value = unsafe { cv_background_subtractor_knn_new(history, dist2_threshold, detect_shadows) };
+            BackgroundSubtractorKNN { value }
+        }
+
+        /// Returns the number of last frames that affect the background model
+        pub fn history(&self) -> c_int {
+            unsafe { cv_background_subtractor_knn_get_history(self.value) }
+        }
+
+        /// Returns the threshold on the squared distance between a pixel and
+        /// its nearest samples to decide whether it belongs to the background
+        pub fn dist2_threshold(&self) -> f64 {
+            unsafe { cv_background_subtractor_knn_get_dist2_threshold(self.value) }
+        }
+
+        /// Returns whether the shadows are detected and marked in the
+        /// foreground masks
+        pub fn detect_shadows(&self) -> bool {
+            unsafe { cv_background_subtractor_knn_get_detect_shadows(self.value) }
+        }
+    }
+
+    impl_background_subtractor!(BackgroundSubtractorKNN);
+
+    /// Builder that provides defaults for BackgroundSubtractorKNN
+    #[derive(Debug, Copy, Clone, Default)]
+    pub struct BackgroundSubtractorKNNBuilder {
+        history: Option<c_int>,
+        dist2_threshold: Option<f64>,
+        detect_shadows: Option<bool>,
+    }
+
+    impl BackgroundSubtractorKNNBuilder {
+        /// Replace current history with specified value
+        pub fn history(mut self, value: c_int) -> Self {
+            self.history = Some(value);
+            self
+        }
+
+        /// Replace current dist2_threshold with specified value
+        pub fn dist2_threshold(mut self, value: f64) -> Self {
+            self.dist2_threshold = Some(value);
+            self
+        }
+
+        /// Replace current detect_shadows with specified value
+        pub fn detect_shadows(mut self, value: bool) -> Self {
+            self.detect_shadows = Some(value);
+            self
+        }
+    }
+
+    impl Into<BackgroundSubtractorKNN> for BackgroundSubtractorKNNBuilder {
+        fn into(self) -> BackgroundSubtractorKNN {
+            BackgroundSubtractorKNN::new(
+                self.history.unwrap_or(500),
+                self.dist2_threshold.unwrap_or(400.0),
+                self.detect_shadows.unwrap_or(true),
+            )
+        }
+    }
 }
@@ -40,3 +40,42 @@ fn test_mat_clone() {
 fn pixel_eq(a: u8, b: u8) -> bool {
     (a - b) <= 1
 }
+
+fn two_clusters() -> Mat {
+    let samples = [0.0f32, 0.1, 0.2, 10.0, 10.1, 10.2];
+    Mat::from_buffer(6, 1, CvType::Cv32FC1, &utils::to_bytes(&samples))
+}
+
+#[test]
+fn test_kmeans() {
+    let data = two_clusters();
+    let criteria = TermCriteria::new(TermType::CountEps, 10, 1.0);
+    let (compactness, labels, centers) = kmeans(&data, 2, &criteria, 3, KMeansCenters::Pp, None).unwrap();
+    assert!(compactness < 0.1);
+    assert_eq!(labels.rows, 6);
+    assert_eq!(centers.rows, 2);
+    let labels: Vec<i32> = (0..6).map(|i| labels.at2(i, 0)).collect();
+    assert_eq!(labels[0], labels[2]);
+    assert_eq!(labels[3], labels[5]);
+    assert_ne!(labels[0], labels[3]);
+    let center: f32 = centers.at2(labels[3], 0);
+    assert!((center - 10.1).abs() < 1e-4);
+}
+
+#[test]
+fn test_kmeans_initial_labels() {
+    let data = two_clusters();
+    let criteria = TermCriteria::new(TermType::Count, 10, 0.0);
+    assert!(kmeans(&data, 2, &criteria, 1, KMeansCenters::UseInitialLabels, None).is_err());
+
+    let initial = Mat::from_buffer(6, 1, CvType::Cv32SC1, &utils::to_bytes(&[1i32, 1, 1, 0, 0, 0]));
+    let (_, labels, _) = kmeans(&data, 2, &criteria, 1, KMeansCenters::UseInitialLabels, Some(&initial)).unwrap();
+    let labels: Vec<i32> = (0..6).map(|i| labels.at2(i, 0)).collect();
+    assert_eq!(labels, vec![1, 1, 1, 0, 0, 0]);
+}
+
+#[test]
+fn test_kmeans_invalid_data() {
+    let criteria = TermCriteria::new(TermType::Count, 10, 0.0);
+    assert!(kmeans(&two_clusters(), 7, &criteria, 1, KMeansCenters::Random, None).is_err());
+}
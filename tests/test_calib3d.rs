@@ -0,0 +1,694 @@
+extern crate cv;
+mod utils;
+
+use cv::calib3d::*;
+use cv::features2d::*;
+use cv::imgproc::InterpolationFlag;
+use cv::*;
+use utils::*;
+
+fn camera_matrix() -> Mat {
+    Mat::from_buffer(
+        3,
+        3,
+        CvType::Cv64FC1,
+        &to_bytes(&[500.0, 0.0, 320.0, 0.0, 500.0, 240.0, 0.0, 0.0, 1.0]),
+    )
+}
+
+/// Deterministic pseudo-random points in [0, 1)
+fn random_values(count: usize) -> Vec<f32> {
+    let mut state = 12345u32;
+    (0..count)
+        .map(|_| {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            (state >> 8) as f32 / (1 << 24) as f32
+        })
+        .collect()
+}
+
+fn grid(count: usize) -> Vec<Point2f> {
+    random_values(count * 2)
+        .chunks(2)
+        .map(|v| Point2f::new(v[0] * 640.0, v[1] * 480.0))
+        .collect()
+}
+
+/// Projects random 3D points in front of a camera at the origin and of a
+/// camera moved by `baseline` along the x axis.
+fn stereo_points(count: usize, baseline: f32) -> (Vec<Point2f>, Vec<Point2f>) {
+    let values = random_values(count * 3);
+    let project = |x: f32, y: f32, z: f32| Point2f::new(500.0 * x / z + 320.0, 500.0 * y / z + 240.0);
+    values
+        .chunks(3)
+        .map(|v| {
+            let (x, y, z) = (v[0] * 4.0 - 2.0, v[1] * 4.0 - 2.0, v[2] * 4.0 + 4.0);
+            (project(x, y, z), project(x - baseline, y, z))
+        })
+        .unzip()
+}
+
+#[test]
+fn homography_with_outliers() {
+    let src = grid(40);
+    let mut dst: Vec<_> = src
+        .iter()
+        .map(|p| Point2f::new(2.0 * p.x + 10.0, 2.0 * p.y + 5.0))
+        .collect();
+    dst[0] = Point2f::new(0.0, 0.0);
+    dst[1] = Point2f::new(600.0, 20.0);
+
+    let (homography, mask) = find_homography(&src, &dst, Method::Ransac, 3.0).unwrap();
+    assert_eq!(homography.rows, 3);
+    assert_eq!(homography.cols, 3);
+    assert!((homography.at2::<f64>(0, 0) - 2.0).abs() < 1e-3);
+    assert!((homography.at2::<f64>(0, 2) - 10.0).abs() < 1e-2);
+    assert!((homography.at2::<f64>(1, 2) - 5.0).abs() < 1e-2);
+    assert_eq!(mask.len(), 40);
+    assert!(!mask[0] && !mask[1]);
+    assert!(mask[2..].iter().all(|&m| m));
+
+    let (_, mask) = find_homography(&src, &dst, Method::Lmeds, 3.0).unwrap();
+    assert!(!mask[0]);
+    let (_, mask) = find_homography(&src, &dst, Method::Rho, 3.0).unwrap();
+    assert!(!mask[1]);
+}
+
+#[test]
+fn homography_rejects_invalid_points() {
+    let src = grid(10);
+    assert!(find_homography(&src, &src[..9], Method::Ransac, 3.0).is_err());
+    assert!(find_homography(&src[..3], &src[..3], Method::Ransac, 3.0).is_err());
+}
+
+#[test]
+fn homography_from_matched_keypoints() {
+    let lenna = load_lenna();
+    let orb: ORB = ORBBuilder::default().into();
+    let (keypoints, descriptors) = orb.detect_and_compute(&lenna, &Mat::new());
+    let matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForceHamming);
+    let matches = matcher.match_two(&descriptors, &descriptors);
+
+    let (query, train) = DMatch::matched_points(&matches, &keypoints, &keypoints);
+    assert_eq!(query.len(), matches.len());
+    let (homography, _) = find_homography(&query, &train, Method::Ransac, 3.0).unwrap();
+    assert!((homography.at2::<f64>(0, 0) - 1.0).abs() < 1e-3);
+    assert!(homography.at2::<f64>(0, 2).abs() < 1e-1);
+}
+
+#[test]
+fn fundamental_mat() {
+    let (points1, points2) = stereo_points(50, 1.0);
+    let (fundamental, mask) = find_fundamental_mat(&points1, &points2, FundamentalMethod::Ransac, 1.0, 0.99).unwrap();
+    assert_eq!(fundamental.rows, 3);
+    assert_eq!(fundamental.cols, 3);
+    assert_eq!(mask.len(), 50);
+    assert!(mask.iter().filter(|&&m| m).count() > 45);
+
+    let (fundamental, _) = find_fundamental_mat(&points1, &points2, FundamentalMethod::EightPoint, 1.0, 0.99).unwrap();
+    assert_eq!(fundamental.rows, 3);
+}
+
+#[test]
+fn essential_mat_and_pose() {
+    let (points1, points2) = stereo_points(50, 1.0);
+    let camera = camera_matrix();
+    let (essential, mask) = find_essential_mat(&points1, &points2, &camera, Method::Ransac, 0.999, 1.0).unwrap();
+    assert_eq!(essential.rows, 3);
+    assert_eq!(essential.cols, 3);
+    assert_eq!(mask.len(), 50);
+
+    let (inliers, rotation, translation, pose_mask) =
+        recover_pose(&essential, &points1, &points2, &camera, Some(&mask)).unwrap();
+    assert!(inliers > 45);
+    assert_eq!(pose_mask.len(), 50);
+    for i in 0..3 {
+        assert!((rotation.at2::<f64>(i, i) - 1.0).abs() < 1e-3);
+    }
+    assert!((translation.at2::<f64>(0, 0) + 1.0).abs() < 1e-2);
+    assert!(translation.at2::<f64>(1, 0).abs() < 1e-2);
+
+    assert!(recover_pose(&essential, &points1, &points2, &camera, Some(&mask[..10])).is_err());
+    let (inliers, _, _, _) = recover_pose(&essential, &points1, &points2, &camera, None).unwrap();
+    assert!(inliers > 45);
+}
+
+#[test]
+fn affine_2d() {
+    let src = grid(30);
+    let mut dst: Vec<_> = src
+        .iter()
+        .map(|p| Point2f::new(p.x + 0.5 * p.y + 3.0, 2.0 * p.y - 7.0))
+        .collect();
+    dst[5] = Point2f::new(1.0, 1.0);
+
+    let (affine, mask) = estimate_affine_2d(&src, &dst, Method::Ransac, 3.0).unwrap();
+    assert_eq!(affine.rows, 2);
+    assert_eq!(affine.cols, 3);
+    assert!((affine.at2::<f64>(0, 1) - 0.5).abs() < 1e-3);
+    assert!((affine.at2::<f64>(1, 2) + 7.0).abs() < 1e-2);
+    assert!(!mask[5]);
+    assert_eq!(mask.iter().filter(|&&m| m).count(), 29);
+}
+
+#[test]
+fn affine_partial_2d() {
+    let src = grid(30);
+    let dst: Vec<_> = src.iter().map(|p| Point2f::new(-p.y + 20.0, p.x + 10.0)).collect();
+
+    let (affine, mask) = estimate_affine_partial_2d(&src, &dst, Method::Lmeds, 3.0).unwrap();
+    assert_eq!(affine.rows, 2);
+    assert!(affine.at2::<f64>(0, 0).abs() < 1e-3);
+    assert!((affine.at2::<f64>(0, 1) + 1.0).abs() < 1e-3);
+    assert!((affine.at2::<f64>(0, 2) - 20.0).abs() < 1e-2);
+    assert!(mask.iter().all(|&m| m));
+}
+
+/// White image with a black and white chessboard of `cols` x `rows` squares
+fn chessboard_image(cols: i32, rows: i32, square: i32) -> Mat {
+    let image = Mat::zeros((rows + 2) * square, (cols + 2) * square, CvType::Cv8UC1 as i32);
+    let white = Scalar::all(255);
+    image.rectangle_custom(Rect::new(0, 0, image.cols, image.rows), white, -1, LineType::Line8);
+    for row in 0..rows {
+        for col in 0..cols {
+            if (row + col) % 2 == 0 {
+                let rect = Rect::new((col + 1) * square, (row + 1) * square, square, square);
+                image.rectangle_custom(rect, Scalar::all(0), -1, LineType::Line8);
+            }
+        }
+    }
+    image
+}
+
+#[test]
+fn chessboard_corners() {
+    let image = chessboard_image(8, 6, 40);
+    let corners = find_chessboard_corners(&image, Size2i::new(7, 5), ChessboardFlags::default())
+        .unwrap()
+        .unwrap();
+    assert_eq!(corners.len(), 35);
+    for corner in &corners {
+        let x = corner.x / 40.0;
+        let y = corner.y / 40.0;
+        assert!((x - x.round()).abs() < 0.1);
+        assert!((y - y.round()).abs() < 0.1);
+    }
+
+    let flags = ChessboardFlags {
+        fast_check: true,
+        ..ChessboardFlags::default()
+    };
+    let missing = find_chessboard_corners(&image, Size2i::new(9, 5), flags).unwrap();
+    assert!(missing.is_none());
+
+    let mut color = Mat::zeros(image.rows, image.cols, CvType::Cv8UC3 as i32);
+    draw_chessboard_corners(&mut color, Size2i::new(7, 5), &corners, true).unwrap();
+    let (x, y) = (corners[0].x.round() as i32, corners[0].y.round() as i32);
+    assert!((0..3).any(|c| color.at3::<u8>(y, x, c) != 0));
+}
+
+#[test]
+fn chessboard_corners_sb() {
+    let image = chessboard_image(8, 6, 40);
+    match find_chessboard_corners_sb(&image, Size2i::new(7, 5), ChessboardSbFlags::default()) {
+        Ok(corners) => assert_eq!(corners.unwrap().len(), 35),
+        // Not available before OpenCV 4
+        Err(e) => assert!(e.to_string().contains("OpenCV 4")),
+    }
+}
+
+#[test]
+fn circles_grid() {
+    let image = Mat::zeros(300, 400, CvType::Cv8UC1 as i32);
+    image.rectangle_custom(Rect::new(0, 0, 400, 300), Scalar::all(255), -1, LineType::Line8);
+    for row in 0..4 {
+        for col in 0..5 {
+            let center = Point2i::new(80 + col * 60, 60 + row * 60);
+            image.ellipse_custom(
+                center,
+                Size2i::new(15, 15),
+                0.0,
+                0.0,
+                360.0,
+                Scalar::all(0),
+                -1,
+                LineType::Line8,
+                0,
+            );
+        }
+    }
+
+    let centers = find_circles_grid(&image, Size2i::new(5, 4), CirclesGridType::Symmetric, false)
+        .unwrap()
+        .unwrap();
+    assert_eq!(centers.len(), 20);
+    assert!(centers
+        .iter()
+        .any(|c| (c.x - 80.0).abs() < 1.0 && (c.y - 60.0).abs() < 1.0));
+
+    let missing = find_circles_grid(&image, Size2i::new(6, 4), CirclesGridType::Symmetric, false).unwrap();
+    assert!(missing.is_none());
+}
+
+/// Rotates `p` around the x axis by `ax` then around the y axis by `ay`, and
+/// projects it with a 500 pixels focal length camera centered on (320, 240).
+fn project(p: Point3f, ax: f32, ay: f32, t: Point3f) -> Point2f {
+    let (y, z) = (p.y * ax.cos() - p.z * ax.sin(), p.y * ax.sin() + p.z * ax.cos());
+    let (x, z) = (p.x * ay.cos() + z * ay.sin(), -p.x * ay.sin() + z * ay.cos());
+    let (x, y, z) = (x + t.x, y + t.y, z + t.z);
+    Point2f::new(500.0 * x / z + 320.0, 500.0 * y / z + 240.0)
+}
+
+fn calibration_views() -> (Vec<Vec<Point3f>>, Vec<Vec<Point2f>>) {
+    let pattern: Vec<_> = (0..5)
+        .flat_map(|row| (0..7).map(move |col| Point3f::new(col as f32, row as f32, 0.0)))
+        .collect();
+    let poses = [(0.3, 0.0), (-0.3, 0.2), (0.1, -0.4), (0.0, 0.3), (-0.2, -0.2)];
+    let image_points = poses
+        .iter()
+        .map(|&(ax, ay)| {
+            pattern
+                .iter()
+                .map(|&p| project(p, ax, ay, Point3f::new(-3.0, -2.0, 12.0)))
+                .collect()
+        })
+        .collect();
+    (vec![pattern; poses.len()], image_points)
+}
+
+#[test]
+fn calibrate_camera_from_views() {
+    let (object_points, image_points) = calibration_views();
+    let criteria = TermCriteria::new(TermType::CountEps, 30, 1e-6);
+    let flags = CalibrationFlags {
+        zero_tangent_dist: true,
+        fix_k3: true,
+        ..CalibrationFlags::default()
+    };
+    let calibration = calibrate_camera(&object_points, &image_points, Size2i::new(640, 480), flags, &criteria).unwrap();
+    assert!(calibration.rms < 0.01);
+    assert!((calibration.camera_matrix.at2::<f64>(0, 0) - 500.0).abs() < 1.0);
+    assert!((calibration.camera_matrix.at2::<f64>(1, 1) - 500.0).abs() < 1.0);
+    assert!((calibration.camera_matrix.at2::<f64>(0, 2) - 320.0).abs() < 1.0);
+    assert!((calibration.camera_matrix.at2::<f64>(1, 2) - 240.0).abs() < 1.0);
+    assert_eq!(calibration.rvecs.len(), 5);
+    assert_eq!(calibration.tvecs.len(), 5);
+    assert!((calibration.tvecs[0].at2::<f64>(2, 0) - 12.0).abs() < 0.1);
+
+    let path = std::env::temp_dir().join("cv_rs_calibration.yml");
+    calibration.to_file_storage(&path).unwrap();
+    let loaded = Calibration::from_file_storage(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.rms, calibration.rms);
+    assert_eq!(
+        loaded.camera_matrix.at2::<f64>(0, 0),
+        calibration.camera_matrix.at2::<f64>(0, 0)
+    );
+    assert_eq!(loaded.dist_coeffs.cols, calibration.dist_coeffs.cols);
+    assert_eq!(loaded.rvecs.len(), 5);
+    assert!(Calibration::from_file_storage(&path).is_err());
+}
+
+#[test]
+fn calibrate_camera_rejects_inconsistent_views() {
+    let (object_points, image_points) = calibration_views();
+    let criteria = TermCriteria::new(TermType::Count, 30, 0.0);
+    let size = Size2i::new(640, 480);
+    let flags = CalibrationFlags::default();
+    assert!(calibrate_camera(&object_points[..2], &image_points, size, flags, &criteria).is_err());
+    let mut truncated = image_points.clone();
+    truncated[1].pop();
+    assert!(calibrate_camera(&object_points, &truncated, size, flags, &criteria).is_err());
+}
+
+#[test]
+fn rodrigues_round_trip() {
+    let rvec = [0.0, 0.0, std::f64::consts::FRAC_PI_2];
+    let matrix = rodrigues_to_matrix(&rvec).unwrap();
+    let expected = [[0.0, -1.0, 0.0], [1.0, 0.0, 0.0], [0.0, 0.0, 1.0]];
+    for (row, expected_row) in matrix.iter().zip(expected.iter()) {
+        for (value, expected_value) in row.iter().zip(expected_row.iter()) {
+            assert!((value - expected_value).abs() < 1e-9);
+        }
+    }
+    let back = rodrigues_to_vector(&matrix).unwrap();
+    for (value, expected_value) in back.iter().zip(rvec.iter()) {
+        assert!((value - expected_value).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn solve_pnp_and_project_points() {
+    let (object_points, image_points) = calibration_views();
+    let (object_points, image_points) = (&object_points[0], &image_points[0]);
+    let camera = camera_matrix();
+    for &method in &[PnPMethod::Iterative, PnPMethod::EPnP] {
+        let (rvec, tvec) = solve_pnp(object_points, image_points, &camera, &no_distortion(5), method)
+            .unwrap()
+            .unwrap();
+        assert!((rvec[0] - 0.3).abs() < 1e-3 && rvec[1].abs() < 1e-3 && rvec[2].abs() < 1e-3);
+        assert!((tvec[0] + 3.0).abs() < 1e-3 && (tvec[1] + 2.0).abs() < 1e-3 && (tvec[2] - 12.0).abs() < 1e-3);
+
+        let projected = project_points(object_points, &rvec, &tvec, &camera, &no_distortion(5)).unwrap();
+        assert_eq!(projected.len(), image_points.len());
+        for (p, expected) in projected.iter().zip(image_points.iter()) {
+            assert!((p.x - expected.x).abs() < 0.01 && (p.y - expected.y).abs() < 0.01);
+        }
+    }
+    assert!(solve_pnp(
+        &object_points[1..],
+        image_points,
+        &camera,
+        &no_distortion(5),
+        PnPMethod::Iterative
+    )
+    .is_err());
+}
+
+#[test]
+fn solve_pnp_ransac_with_outliers() {
+    let (object_points, image_points) = calibration_views();
+    let object_points = &object_points[1];
+    let mut image_points = image_points[1].clone();
+    for &i in &[3, 17, 30] {
+        image_points[i].x += 40.0;
+    }
+    let camera = camera_matrix();
+    let (rvec, tvec, inliers) = solve_pnp_ransac(
+        object_points,
+        &image_points,
+        &camera,
+        &no_distortion(5),
+        PnPMethod::EPnP,
+        100,
+        2.0,
+        0.99,
+    )
+    .unwrap()
+    .unwrap();
+    assert_eq!(inliers.len(), object_points.len() - 3);
+    assert!(!inliers.contains(&3) && !inliers.contains(&17) && !inliers.contains(&30));
+    let rotation = rodrigues_to_matrix(&rvec).unwrap();
+    assert!((rotation[2][2] - (0.3f64.cos() * 0.2f64.cos())).abs() < 1e-2);
+    assert!((tvec[2] - 12.0).abs() < 0.1);
+}
+
+#[test]
+fn homogeneous_conversion() {
+    let points = vec![Point2f::new(1.0, 2.0), Point2f::new(-3.0, 0.5)];
+    let homogeneous = convert_points_to_homogeneous(&points).unwrap();
+    assert_eq!(homogeneous.len(), 2);
+    assert_eq!(homogeneous[1].x, -3.0);
+    assert_eq!(homogeneous[1].z, 1.0);
+    let converted =
+        convert_points_from_homogeneous(&[Point3f::new(2.0, 4.0, 2.0), Point3f::new(3.0, 1.5, 0.5)]).unwrap();
+    assert_eq!(converted[0].x, 1.0);
+    assert_eq!(converted[0].y, 2.0);
+    assert_eq!(converted[1].x, 6.0);
+    assert_eq!(converted[1].y, 3.0);
+}
+
+fn projection_matrices() -> (Mat, Mat) {
+    let p1 = [500.0, 0.0, 320.0, 0.0, 0.0, 500.0, 240.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    let p2 = [500.0, 0.0, 320.0, -500.0, 0.0, 500.0, 240.0, 0.0, 0.0, 0.0, 1.0, 0.0];
+    (
+        Mat::from_buffer(3, 4, CvType::Cv64FC1, &to_bytes(&p1)),
+        Mat::from_buffer(3, 4, CvType::Cv64FC1, &to_bytes(&p2)),
+    )
+}
+
+#[test]
+fn triangulate_stereo_points() {
+    let (p1, p2) = projection_matrices();
+    let expected = vec![Point3f::new(0.5, -0.2, 8.0), Point3f::new(-1.0, 1.0, 12.0)];
+    let points1: Vec<_> = expected
+        .iter()
+        .map(|&p| project(p, 0.0, 0.0, Point3f::new(0.0, 0.0, 0.0)))
+        .collect();
+    let points2: Vec<_> = expected
+        .iter()
+        .map(|&p| project(p, 0.0, 0.0, Point3f::new(-1.0, 0.0, 0.0)))
+        .collect();
+    let points = triangulate_points(&p1, &p2, &points1, &points2).unwrap();
+    assert_eq!(points.len(), 2);
+    for (p, e) in points.iter().zip(&expected) {
+        assert!((p.x - f64::from(e.x)).abs() < 1e-3);
+        assert!((p.y - f64::from(e.y)).abs() < 1e-3);
+        assert!((p.z - f64::from(e.z)).abs() < 1e-2);
+    }
+    assert!(triangulate_points(&p1, &p2, &points1, &points2[..1]).is_err());
+}
+
+#[test]
+fn decompose_projection() {
+    let (_, p2) = projection_matrices();
+    let decomposition = decompose_projection_matrix(&p2).unwrap();
+    assert!((decomposition.camera_matrix[0][0] - 500.0).abs() < 1e-6);
+    assert!((decomposition.camera_matrix[1][2] - 240.0).abs() < 1e-6);
+    assert!((decomposition.rotation[0][0] - 1.0).abs() < 1e-6);
+    assert!((decomposition.camera_position[0] - 1.0).abs() < 1e-6);
+    assert!(decomposition.camera_position[2].abs() < 1e-6);
+    assert!(decomposition.euler_angles.iter().all(|a| a.abs() < 1e-6));
+}
+
+#[test]
+fn decompose_homography() {
+    // translation along x of a tenth of the distance to the fronto-parallel plane
+    let homography = Mat::from_buffer(
+        3,
+        3,
+        CvType::Cv64FC1,
+        &to_bytes(&[1.0, 0.0, 50.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0]),
+    );
+    let solutions = decompose_homography_mat(&homography, &camera_matrix()).unwrap();
+    assert!(!solutions.is_empty() && solutions.len() <= 4);
+    assert!(solutions.iter().any(|s| {
+        (s.rotation[0][0] - 1.0).abs() < 1e-6
+            && (s.translation[0].abs() - 0.1).abs() < 1e-6
+            && (s.normal[2].abs() - 1.0).abs() < 1e-6
+    }));
+}
+
+/// Rectified stereo pair of lenna with a disparity of 8 pixels
+fn shifted_pair() -> (Mat, Mat) {
+    let gray = load_lenna();
+    let width = gray.cols - 8;
+    let left = gray.roi(Rect::new(0, 0, width, gray.rows));
+    let right = gray.roi(Rect::new(8, 0, width, gray.rows));
+    (left, right)
+}
+
+#[test]
+fn stereo_bm_disparity() {
+    let (left, right) = shifted_pair();
+    let matcher: StereoBM = StereoBMBuilder::default().num_disparities(16).block_size(15).into();
+    assert_eq!(matcher.min_disparity(), 0);
+    assert_eq!(matcher.num_disparities(), 16);
+    assert_eq!(matcher.block_size(), 15);
+    let disparity = matcher.compute(&left, &right).unwrap();
+    assert_eq!(disparity.cv_type(), CvType::Cv16SC1);
+    assert_eq!((disparity.rows, disparity.cols), (left.rows, left.cols));
+    assert!((disparity.at2::<i16>(256, 256) - 8 * 16).abs() <= 8);
+}
+
+#[test]
+fn stereo_sgbm_disparity() {
+    let (left, right) = shifted_pair();
+    let matcher: StereoSGBM = StereoSGBMBuilder::default()
+        .num_disparities(16)
+        .block_size(5)
+        .p1(8 * 25)
+        .p2(32 * 25)
+        .mode(SGBMMode::SGBM3Way)
+        .into();
+    let disparity = matcher.compute(&left, &right).unwrap();
+    assert_eq!(disparity.cv_type(), CvType::Cv16SC1);
+    assert!((disparity.at2::<i16>(256, 256) - 8 * 16).abs() <= 8);
+    assert!(matcher.compute(&left, &load_lenna()).is_err());
+}
+
+#[test]
+fn filter_speckles_removes_small_blobs() {
+    let mut values = vec![100u8; 100];
+    values[55] = 200;
+    let mut disparity = Mat::from_buffer(10, 10, CvType::Cv8UC1, &values);
+    filter_speckles(&mut disparity, 0.0, 4, 16.0).unwrap();
+    assert_eq!(disparity.at2::<u8>(5, 5), 0);
+    assert_eq!(disparity.at2::<u8>(2, 2), 100);
+}
+
+#[test]
+fn reproject_constant_disparity() {
+    let disparity = Mat::from_buffer(4, 4, CvType::Cv8UC1, &[10u8; 16]);
+    let q = Mat::from_buffer(
+        4,
+        4,
+        CvType::Cv64FC1,
+        &to_bytes(&[
+            1.0, 0.0, 0.0, -2.0, 0.0, 1.0, 0.0, -2.0, 0.0, 0.0, 0.0, 500.0, 0.0, 0.0, 10.0, 0.0,
+        ]),
+    );
+    let points = reproject_image_to_3d(&disparity, &q, false).unwrap();
+    assert_eq!(points.cv_type(), CvType::Cv32FC3);
+    assert!((points.at3::<f32>(3, 3, 0) - 0.01).abs() < 1e-6);
+    assert!((points.at3::<f32>(3, 3, 8) - 5.0).abs() < 1e-4);
+}
+
+#[test]
+fn stereo_calibrate_and_rectify() {
+    let (object_points, image_points1) = calibration_views();
+    let poses = [(0.3, 0.0), (-0.3, 0.2), (0.1, -0.4), (0.0, 0.3), (-0.2, -0.2)];
+    let image_points2: Vec<Vec<_>> = poses
+        .iter()
+        .map(|&(ax, ay)| {
+            object_points[0]
+                .iter()
+                .map(|&p| project(p, ax, ay, Point3f::new(-4.0, -2.0, 12.0)))
+                .collect()
+        })
+        .collect();
+    let camera = camera_matrix();
+    let criteria = TermCriteria::new(TermType::CountEps, 30, 1e-6);
+    let size = Size2i::new(640, 480);
+    let calibration = stereo_calibrate(
+        &object_points,
+        &image_points1,
+        &image_points2,
+        &camera,
+        &no_distortion(5),
+        &camera,
+        &no_distortion(5),
+        size,
+        StereoCalibrationFlags::default(),
+        &criteria,
+    )
+    .unwrap();
+    assert!(calibration.rms < 0.01);
+    assert!((calibration.translation.at2::<f64>(0, 0) + 1.0).abs() < 1e-3);
+    assert!(calibration.translation.at2::<f64>(1, 0).abs() < 1e-3);
+    assert!((calibration.rotation.at2::<f64>(0, 0) - 1.0).abs() < 1e-3);
+    assert_eq!(calibration.camera_matrix1.at2::<f64>(0, 0), 500.0);
+
+    let rectification = stereo_rectify(&calibration, size, true, 0.0).unwrap();
+    assert_eq!(rectification.q.rows, 4);
+    assert!((rectification.q.at2::<f64>(3, 2) - 1.0).abs() < 1e-3);
+    assert!(rectification.valid_roi1.width > 0 && rectification.valid_roi2.width > 0);
+
+    assert!(stereo_calibrate(
+        &object_points,
+        &image_points1,
+        &image_points2[..2],
+        &camera,
+        &no_distortion(5),
+        &camera,
+        &no_distortion(5),
+        size,
+        StereoCalibrationFlags::default(),
+        &criteria,
+    )
+    .is_err());
+}
+
+fn no_distortion(count: i32) -> Mat {
+    Mat::zeros(1, count, CvType::Cv64FC1 as i32)
+}
+
+#[test]
+fn undistort_without_distortion() {
+    let lenna = load_lenna();
+    let camera = camera_matrix();
+    let undistorted = undistort(&lenna, &camera, &no_distortion(5), None).unwrap();
+    assert_eq!(undistorted.rows, lenna.rows);
+    assert_eq!(undistorted.cols, lenna.cols);
+    assert_eq!(undistorted.at2::<u8>(100, 100), lenna.at2::<u8>(100, 100));
+
+    let points = vec![Point2f::new(320.0, 240.0), Point2f::new(820.0, 740.0)];
+    let normalized = undistort_points(&points, &camera, &no_distortion(5), None, None).unwrap();
+    assert!(normalized[0].x.abs() < 1e-5 && normalized[0].y.abs() < 1e-5);
+    assert!((normalized[1].x - 1.0).abs() < 1e-5 && (normalized[1].y - 1.0).abs() < 1e-5);
+    let pixels = undistort_points(&points, &camera, &no_distortion(5), None, Some(&camera)).unwrap();
+    assert!((pixels[1].x - 820.0).abs() < 1e-3);
+}
+
+#[test]
+fn undistort_rectify_map_and_remap() {
+    let lenna = load_lenna();
+    let camera = camera_matrix();
+    let size = Size2i::new(lenna.cols, lenna.rows);
+    let (new_camera, roi) = get_optimal_new_camera_matrix(&camera, &no_distortion(5), size, 0.0, None, false).unwrap();
+    assert!((new_camera.at2::<f64>(0, 0) - 500.0).abs() < 1.0);
+    assert!(roi.width > 0 && roi.height > 0);
+
+    let (map1, map2) =
+        init_undistort_rectify_map(&camera, &no_distortion(5), None, &camera, size, MapType::Float).unwrap();
+    assert_eq!(map1.cv_type(), CvType::Cv32FC1);
+    assert_eq!(map1.rows, lenna.rows);
+    assert!((map1.at2::<f32>(10, 20) - 20.0).abs() < 1e-3);
+    assert!((map2.at2::<f32>(10, 20) - 10.0).abs() < 1e-3);
+
+    let remapped = lenna
+        .remap(
+            &map1,
+            &map2,
+            InterpolationFlag::InterLinear,
+            BorderType::Constant,
+            Scalar::all(0),
+        )
+        .unwrap();
+    assert_eq!(remapped.at2::<u8>(50, 60), lenna.at2::<u8>(50, 60));
+
+    let (fixed1, _) =
+        init_undistort_rectify_map(&camera, &no_distortion(5), None, &camera, size, MapType::FixedPoint).unwrap();
+    assert_eq!(fixed1.channels, 2);
+}
+
+/// Same as `project`, with the equidistant fisheye model
+fn project_fisheye(p: Point3f, ax: f32, ay: f32, t: Point3f) -> Point2f {
+    let projected = project(p, ax, ay, t);
+    let (x, y) = ((projected.x - 320.0) / 500.0, (projected.y - 240.0) / 500.0);
+    let r = (x * x + y * y).sqrt();
+    let scale = if r > 0.0 { r.atan() / r } else { 1.0 };
+    Point2f::new(500.0 * x * scale + 320.0, 500.0 * y * scale + 240.0)
+}
+
+#[test]
+fn fisheye_calibrate_and_undistort() {
+    let (object_points, _) = calibration_views();
+    let poses = [(0.3, 0.0), (-0.3, 0.2), (0.1, -0.4), (0.0, 0.3), (-0.2, -0.2)];
+    let image_points: Vec<Vec<_>> = poses
+        .iter()
+        .map(|&(ax, ay)| {
+            object_points[0]
+                .iter()
+                .map(|&p| project_fisheye(p, ax, ay, Point3f::new(-3.0, -2.0, 12.0)))
+                .collect()
+        })
+        .collect();
+    let criteria = TermCriteria::new(TermType::CountEps, 100, 1e-8);
+    let flags = fisheye::CalibrationFlags {
+        recompute_extrinsic: true,
+        fix_skew: true,
+        ..fisheye::CalibrationFlags::default()
+    };
+    let calibration =
+        fisheye::calibrate(&object_points, &image_points, Size2i::new(640, 480), flags, &criteria).unwrap();
+    assert!(calibration.rms < 0.1);
+    assert_eq!(calibration.dist_coeffs.rows * calibration.dist_coeffs.cols, 4);
+    assert!((calibration.camera_matrix.at2::<f64>(0, 0) - 500.0).abs() < 5.0);
+
+    let camera = camera_matrix();
+    let center =
+        fisheye::undistort_points(&[Point2f::new(320.0, 240.0)], &camera, &no_distortion(4), None, None).unwrap();
+    assert!(center[0].x.abs() < 1e-5 && center[0].y.abs() < 1e-5);
+
+    let lenna = load_lenna();
+    let undistorted = fisheye::undistort_image(&lenna, &camera, &no_distortion(4), Some(&camera), None).unwrap();
+    assert_eq!(undistorted.rows, lenna.rows);
+    let default_knew = fisheye::undistort_image(&lenna, &camera, &no_distortion(4), None, None).unwrap();
+    assert!(default_knew.data() == undistorted.data());
+    let size = Size2i::new(lenna.cols, lenna.rows);
+    let (map1, map2) =
+        fisheye::init_undistort_rectify_map(&camera, &no_distortion(4), None, &camera, size, MapType::Float).unwrap();
+    assert_eq!(map1.rows, lenna.rows);
+    assert_eq!(map2.cols, lenna.cols);
+}
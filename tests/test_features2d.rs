@@ -2,6 +2,7 @@ extern crate cv;
 mod utils;
 
 use cv::features2d::*;
+use cv::imgproc::ColorConversion;
 use cv::*;
 use utils::*;
 
@@ -14,6 +15,47 @@ fn mser_lenna_detect() {
     assert_ne!(boxes.len(), 0);
 }
 
+#[test]
+fn mser_detect_keypoints() {
+    let mser: MSER = MSERBuilder::default().into();
+    assert_detects_only(&mser);
+    assert_eq!(mser.descriptor_size(), 0);
+}
+
+#[test]
+fn mser_color_regions() {
+    let messi = load_messi_color();
+    let mser: MSER = MSERBuilder::default().max_evolution(100).edge_blur_size(3).into();
+    let (msers, boxes) = mser.detect_regions(&messi);
+    assert_ne!(msers.len(), 0);
+    assert_eq!(msers.len(), boxes.len());
+}
+
+#[test]
+fn mser_getters_and_setters() {
+    let lenna = load_lenna();
+    let mut mser: MSER = MSERBuilder::default().pass2only(true).into();
+    assert_eq!(mser.delta(), 5);
+    assert_eq!(mser.min_area(), 60);
+    assert_eq!(mser.max_area(), 14400);
+    assert!(mser.pass2only());
+    let (pass2only_regions, _) = mser.detect_regions(&lenna);
+
+    mser.set_pass2only(false);
+    assert!(!mser.pass2only());
+    let (all_regions, _) = mser.detect_regions(&lenna);
+    assert!(all_regions.len() > pass2only_regions.len());
+
+    mser.set_delta(10);
+    mser.set_min_area(200);
+    mser.set_max_area(1000);
+    assert_eq!(mser.delta(), 10);
+    assert_eq!(mser.min_area(), 200);
+    assert_eq!(mser.max_area(), 1000);
+    let (_, boxes) = mser.detect_regions(&lenna);
+    assert!(boxes.len() < all_regions.len());
+}
+
 #[test]
 fn surf_lenna_detect_and_compute() {
     let lenna = load_lenna();
@@ -100,3 +142,535 @@ fn bow() {
 
     assert_ne!(mat.cols, 0);
 }
+
+fn assert_detects_and_computes<T: Feature2D>(detector: &T) {
+    let lenna = load_lenna();
+    let (keypoints, descriptors) = detector.detect_and_compute(&lenna, &Mat::new());
+    assert_ne!(keypoints.len(), 0);
+    assert_ne!(descriptors.cols, 0);
+    assert_eq!(keypoints.len() as i32, descriptors.rows);
+}
+
+fn assert_detects_only<T: Feature2D>(detector: &T) {
+    let lenna = load_lenna();
+    let (keypoints, descriptors) = detector.detect_and_compute(&lenna, &Mat::new());
+    assert_ne!(keypoints.len(), 0);
+    assert_eq!(descriptors.rows, 0);
+}
+
+#[test]
+fn orb_lenna_detect_and_compute() {
+    let orb: ORB = ORBBuilder::default().features(100).into();
+    assert_detects_and_computes(&orb);
+    let lenna = load_lenna();
+    let (keypoints, descriptors) = orb.detect_and_compute(&lenna, &Mat::new());
+    assert!(keypoints.len() <= 100);
+    assert_eq!(descriptors.cols, 32);
+    assert_eq!(descriptors.cv_type(), CvType::Cv8UC1);
+}
+
+#[test]
+fn akaze_lenna_detect_and_compute() {
+    let akaze: AKAZE = AKAZEBuilder::default().into();
+    assert_detects_and_computes(&akaze);
+    let kaze_descriptors: AKAZE = AKAZEBuilder::default()
+        .descriptor_type(AKAZEDescriptorType::Kaze)
+        .into();
+    let (_, descriptors) = kaze_descriptors.detect_and_compute(&load_lenna(), &Mat::new());
+    assert_eq!(descriptors.cv_type(), CvType::Cv32FC1);
+}
+
+#[test]
+fn kaze_lenna_detect_and_compute() {
+    let kaze: KAZE = KAZEBuilder::default()
+        .extended(true)
+        .diffusivity(KAZEDiffusivity::Weickert)
+        .into();
+    assert_detects_and_computes(&kaze);
+    let (_, descriptors) = kaze.detect_and_compute(&load_lenna(), &Mat::new());
+    assert_eq!(descriptors.cols, 128);
+}
+
+#[test]
+fn brisk_lenna_detect_and_compute() {
+    let brisk: BRISK = BRISKBuilder::default().into();
+    assert_detects_and_computes(&brisk);
+}
+
+#[test]
+fn fast_lenna_detect() {
+    let fast: FastFeatureDetector = FastFeatureDetectorBuilder::default().threshold(20).into();
+    assert_detects_only(&fast);
+}
+
+#[test]
+fn agast_lenna_detect() {
+    let agast: AgastFeatureDetector = AgastFeatureDetectorBuilder::default()
+        .detector_type(AgastDetectorType::Agast7_12d)
+        .into();
+    assert_detects_only(&agast);
+}
+
+#[test]
+fn gftt_lenna_detect() {
+    let gftt: GFTTDetector = GFTTDetectorBuilder::default().max_corners(50).into();
+    assert_detects_only(&gftt);
+    let (keypoints, _) = gftt.detect_and_compute(&load_lenna(), &Mat::new());
+    assert!(keypoints.len() <= 50);
+}
+
+#[test]
+fn simple_blob_detector() {
+    let image = Mat::zeros(200, 200, CvType::Cv8UC1 as i32);
+    image.rectangle_custom(Rect::new(0, 0, 200, 200), Scalar::all(255), -1, LineType::Line8);
+    image.ellipse_custom(
+        Point2i::new(60, 60),
+        Size2i::new(15, 15),
+        0.0,
+        0.0,
+        360.0,
+        Scalar::all(0),
+        -1,
+        LineType::Line8,
+        0,
+    );
+    image.ellipse_custom(
+        Point2i::new(140, 140),
+        Size2i::new(20, 20),
+        0.0,
+        0.0,
+        360.0,
+        Scalar::all(0),
+        -1,
+        LineType::Line8,
+        0,
+    );
+    let detector: SimpleBlobDetector = SimpleBlobDetectorBuilder::default().into();
+    let (keypoints, descriptors) = detector.detect_and_compute(&image, &Mat::new());
+    assert_eq!(keypoints.len(), 2);
+    assert_eq!(descriptors.rows, 0);
+
+    let large_only: SimpleBlobDetector = SimpleBlobDetectorBuilder::default().area(Some((1000.0, 5000.0))).into();
+    let (keypoints, _) = large_only.detect_and_compute(&image, &Mat::new());
+    assert_eq!(keypoints.len(), 1);
+}
+
+#[test]
+fn sift_detect_then_compute() {
+    let lenna = load_lenna();
+    let sift: SIFT = SIFTBuilder::default().into();
+    let mut keypoints = sift.detect(&lenna, &Mat::new());
+    assert_ne!(keypoints.len(), 0);
+    assert!(keypoints.iter().any(|k| k.point.x > 0.0 && k.point.y > 0.0));
+    assert!(keypoints
+        .iter()
+        .all(|k| k.point.x < lenna.cols as f32 && k.point.y < lenna.rows as f32));
+
+    let descriptors = sift.compute(&lenna, &mut keypoints);
+    assert_eq!(descriptors.rows, keypoints.len() as i32);
+    assert_eq!(descriptors.cols, sift.descriptor_size());
+}
+
+#[test]
+fn detect_and_compute_with_provided_keypoints() {
+    let lenna = load_lenna();
+    let fast: FastFeatureDetector = FastFeatureDetectorBuilder::default().threshold(40).into();
+    let orb: ORB = ORBBuilder::default().into();
+    let mut keypoints = fast.detect(&lenna, &Mat::new());
+    assert_ne!(keypoints.len(), 0);
+    let detected = keypoints.len();
+    let descriptors = orb.detect_and_compute_with_keypoints(&lenna, &Mat::new(), &mut keypoints);
+    assert!(keypoints.len() <= detected);
+    assert_eq!(descriptors.rows, keypoints.len() as i32);
+    assert_eq!(descriptors.cols, 32);
+}
+
+#[test]
+fn compute_with_detector_only_algorithm() {
+    let lenna = load_lenna();
+    let fast: FastFeatureDetector = FastFeatureDetectorBuilder::default().into();
+    let mut keypoints = fast.detect(&lenna, &Mat::new());
+    let detected = keypoints.len();
+    let descriptors = fast.compute(&lenna, &mut keypoints);
+    assert_eq!(keypoints.len(), detected);
+    assert_eq!(descriptors.rows, 0);
+    assert_eq!(fast.descriptor_size(), 0);
+}
+
+#[test]
+fn descriptor_properties() {
+    let sift: SIFT = SIFTBuilder::default().into();
+    assert_eq!(sift.descriptor_size(), 128);
+    assert_eq!(sift.descriptor_type(), Some(CvType::Cv32FC1));
+    assert_eq!(sift.default_norm(), Some(NormType::L2));
+
+    let surf: SURF = SURFBuilder::default().extended(true).into();
+    assert_eq!(surf.descriptor_size(), 128);
+    assert_eq!(surf.descriptor_type(), Some(CvType::Cv32FC1));
+
+    let orb: ORB = ORBBuilder::default().into();
+    assert_eq!(orb.descriptor_size(), 32);
+    assert_eq!(orb.descriptor_type(), Some(CvType::Cv8UC1));
+    assert_eq!(orb.default_norm(), Some(NormType::Hamming));
+}
+
+#[test]
+fn keypoint_convert() {
+    let points = vec![Point2f::new(1.0, 2.0), Point2f::new(3.0, 4.0)];
+    let keypoints = KeyPoint::convert_points(&points, 5.0, 0.5, 1, 7);
+    assert_eq!(keypoints.len(), 2);
+    assert_eq!(keypoints[1].size, 5.0);
+    assert_eq!(keypoints[1].angle, -1.0);
+    assert_eq!(keypoints[1].class_id, 7);
+    let back = KeyPoint::convert(&keypoints);
+    assert_eq!(back[1].x, 3.0);
+    assert_eq!(back[1].y, 4.0);
+}
+
+#[test]
+fn keypoint_overlap() {
+    let a = KeyPoint::new(Point2f::new(10.0, 10.0), 4.0);
+    let b = KeyPoint::new(Point2f::new(10.0, 10.0), 4.0);
+    let c = KeyPoint::new(Point2f::new(100.0, 100.0), 4.0);
+    let d = KeyPoint::new(Point2f::new(11.0, 10.0), 4.0);
+    assert_eq!(a.overlap(&b), 1.0);
+    assert_eq!(a.overlap(&c), 0.0);
+    let partial = a.overlap(&d);
+    assert!(partial > 0.0 && partial < 1.0);
+}
+
+#[test]
+fn keypoints_filter() {
+    let mut keypoints: Vec<KeyPoint> = (0..10)
+        .map(|i| KeyPoint {
+            response: i as f32,
+            ..KeyPoint::new(Point2f::new(10.0 * i as f32, 5.0), 2.0 + i as f32)
+        })
+        .collect();
+    keypoints.push(keypoints[0]);
+
+    KeyPointsFilter::remove_duplicated(&mut keypoints);
+    assert_eq!(keypoints.len(), 10);
+
+    KeyPointsFilter::run_by_keypoint_size(&mut keypoints, 3.0, Some(9.0));
+    assert_eq!(keypoints.len(), 7);
+
+    KeyPointsFilter::retain_best(&mut keypoints, 3);
+    assert_eq!(keypoints.len(), 3);
+    assert!(keypoints.iter().all(|k| k.response >= 5.0));
+
+    let mask = Mat::zeros(20, 100, CvType::Cv8UC1 as i32);
+    mask.rectangle_custom(Rect::new(0, 0, 65, 20), Scalar::all(255), -1, LineType::Line8);
+    KeyPointsFilter::run_by_pixels_mask(&mut keypoints, &mask);
+    assert_eq!(keypoints.len(), 2);
+
+    KeyPointsFilter::run_by_image_border(&mut keypoints, Size2i::new(100, 20), 55);
+    assert_eq!(keypoints.len(), 0);
+}
+
+#[test]
+fn dmatch_fields() {
+    let lenna = load_lenna();
+    let orb: ORB = ORBBuilder::default().into();
+    let (_, descriptors) = orb.detect_and_compute(&lenna, &Mat::new());
+    let matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForceHamming);
+    let matches = matcher.match_two(&descriptors, &descriptors);
+    assert_eq!(matches.len() as i32, descriptors.rows);
+    for (i, m) in matches.iter().enumerate() {
+        assert_eq!(m.query_idx, i as i32);
+        assert!(m.train_idx >= 0 && m.train_idx < descriptors.rows);
+        assert_eq!(m.distance, 0.0);
+    }
+    let m = DMatch::new(1, 2, 0, 0.5);
+    assert_eq!((m.query_idx, m.train_idx, m.img_idx, m.distance), (1, 2, 0, 0.5));
+}
+
+#[test]
+fn draw_keypoints_and_matches() {
+    let lenna = load_lenna();
+    let orb: ORB = ORBBuilder::default().features(50).into();
+    let (keypoints, descriptors) = orb.detect_and_compute(&lenna, &Mat::new());
+
+    let flags = DrawMatchesFlags {
+        draw_rich_keypoints: true,
+        ..Default::default()
+    };
+    let drawn = draw_keypoints(&lenna, &keypoints, Scalar::new(0, 255, 0, 0), flags).unwrap();
+    assert_eq!(drawn.channels, 3);
+    assert_eq!(drawn.rows, lenna.rows);
+    assert_eq!(drawn.cols, lenna.cols);
+
+    let mut canvas = drawn.cvt_color(ColorConversion::BGR2RGB);
+    draw_keypoints_over(&mut canvas, &keypoints, Scalar::all(-1), DrawMatchesFlags::default()).unwrap();
+
+    let mut matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForceHamming);
+    let matches = matcher.match_two(&descriptors, &descriptors);
+    let mask: Vec<bool> = (0..matches.len()).map(|i| i % 2 == 0).collect();
+    let side_by_side = draw_matches(
+        &lenna,
+        &keypoints,
+        &lenna,
+        &keypoints,
+        &matches,
+        Scalar::all(-1),
+        Scalar::all(-1),
+        &mask,
+        DrawMatchesFlags::default(),
+    )
+    .unwrap();
+    assert_eq!(side_by_side.rows, lenna.rows);
+    assert_eq!(side_by_side.cols, 2 * lenna.cols);
+
+    matcher.add(&vec![&descriptors]);
+    matcher.train();
+    let knn_matches = matcher.knn_match(&descriptors, 2);
+    assert_eq!(knn_matches.len() as i32, descriptors.rows);
+    assert!(knn_matches.iter().all(|m| m.len() == 2));
+    let flags = DrawMatchesFlags {
+        not_draw_single_points: true,
+        ..Default::default()
+    };
+    let draw = |matches: &[Vec<DMatch>]| {
+        draw_matches_knn(
+            &lenna,
+            &keypoints,
+            &lenna,
+            &keypoints,
+            matches,
+            Scalar::all(-1),
+            Scalar::all(-1),
+            &[],
+            flags,
+        )
+        .unwrap()
+    };
+    let side_by_side = draw(&knn_matches);
+    let background = draw(&[]);
+    assert_eq!(side_by_side.cols, 2 * lenna.cols);
+
+    // Every match line crosses the border between the two images
+    let crossing = (0..lenna.rows)
+        .filter(|&y| (0..3).any(|c| side_by_side.at3::<u8>(y, lenna.cols, c) != background.at3::<u8>(y, lenna.cols, c)))
+        .count();
+    assert!(crossing > 0);
+}
+
+#[test]
+fn draw_matches_rejects_invalid_indices() {
+    let lenna = load_lenna();
+    let keypoints = vec![KeyPoint::new(Point2f::new(10.0, 10.0), 3.0)];
+    let matches = vec![DMatch::new(0, 5, 0, 0.0)];
+    let result = draw_matches(
+        &lenna,
+        &keypoints,
+        &lenna,
+        &keypoints,
+        &matches,
+        Scalar::all(-1),
+        Scalar::all(-1),
+        &[],
+        DrawMatchesFlags::default(),
+    );
+    assert!(result.is_err());
+}
+
+fn orb_descriptors() -> Mat {
+    let lenna = load_lenna();
+    let orb: ORB = ORBBuilder::default().features(200).into();
+    let (_, descriptors) = orb.detect_and_compute(&lenna, &Mat::new());
+    descriptors
+}
+
+#[test]
+fn matcher_train_descriptors_and_clear() {
+    let descriptors = orb_descriptors();
+    let mut matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForceHamming);
+    assert!(matcher.is_empty());
+    matcher.add(&vec![&descriptors, &descriptors]);
+    let train = matcher.get_train_descriptors();
+    assert_eq!(train.len(), 2);
+    assert_eq!(train[0].rows, descriptors.rows);
+    matcher.clear();
+    assert!(matcher.is_empty());
+    assert_eq!(matcher.get_train_descriptors().len(), 0);
+}
+
+#[test]
+fn matcher_radius_match() {
+    let descriptors = orb_descriptors();
+    let mut matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForceHamming);
+    matcher.add(&vec![&descriptors]);
+    let matches = matcher.radius_match(&descriptors, 1.0);
+    assert_eq!(matches.len() as i32, descriptors.rows);
+    assert!(matches
+        .iter()
+        .all(|m| !m.is_empty() && m.iter().all(|d| d.distance < 1.0)));
+}
+
+#[test]
+fn matcher_masks() {
+    let descriptors = orb_descriptors();
+    let mut matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForceHamming);
+    matcher.add(&vec![&descriptors]);
+    let mask = Mat::zeros(descriptors.rows, descriptors.rows, CvType::Cv8UC1 as i32);
+    mask.rectangle_custom(
+        Rect::new(0, 0, 1, descriptors.rows),
+        Scalar::all(255),
+        -1,
+        LineType::Line8,
+    );
+    let matches = matcher.match_masked(&descriptors, &[&mask]);
+    assert!(matches.iter().all(|m| m.train_idx == 0));
+
+    let empty_mask = Mat::zeros(descriptors.rows, descriptors.rows, CvType::Cv8UC1 as i32);
+    let matches = matcher.knn_match_masked(&descriptors, 2, &[&empty_mask], true);
+    assert_eq!(matches.len(), 0);
+    let matches = matcher.radius_match_masked(&descriptors, 1000.0, &[&empty_mask], false);
+    assert_eq!(matches.len() as i32, descriptors.rows);
+    assert!(matches.iter().all(|m| m.is_empty()));
+}
+
+#[test]
+fn flann_lsh_matcher() {
+    let descriptors = orb_descriptors();
+    let index = FlannIndexParams::Lsh {
+        table_number: 12,
+        key_size: 20,
+        multi_probe_level: 2,
+    };
+    let mut matcher = DescriptorMatcher::flann(index, FlannSearchParams::default());
+    matcher.add(&vec![&descriptors]);
+    matcher.train();
+    let matches = matcher.knn_match(&descriptors, 2);
+    assert_eq!(matches.len() as i32, descriptors.rows);
+}
+
+#[test]
+fn flann_kd_tree_matcher() {
+    let lenna = load_lenna();
+    let sift: SIFT = SIFTBuilder::default().into();
+    let (_, descriptors) = sift.detect_and_compute(&lenna, &Mat::new());
+    let search = FlannSearchParams {
+        checks: 64,
+        ..Default::default()
+    };
+    let matcher = DescriptorMatcher::flann(FlannIndexParams::KdTree { trees: 2 }, search);
+    let matches = matcher.match_two(&descriptors, &descriptors);
+    assert_eq!(matches.len() as i32, descriptors.rows);
+}
+
+#[test]
+fn ratio_test_and_cross_check() {
+    let knn = vec![
+        vec![DMatch::new(0, 1, 0, 1.0), DMatch::new(0, 2, 0, 10.0)],
+        vec![DMatch::new(1, 1, 0, 9.0), DMatch::new(1, 2, 0, 10.0)],
+        vec![DMatch::new(2, 0, 0, 3.0)],
+        vec![],
+    ];
+    let good = ratio_test(&knn, 0.75);
+    assert_eq!(good.len(), 2);
+    assert_eq!(good[0].train_idx, 1);
+    assert_eq!(good[1].query_idx, 2);
+
+    let forward = vec![
+        DMatch::new(0, 1, 0, 1.0),
+        DMatch::new(1, 0, 0, 1.0),
+        DMatch::new(2, 0, 0, 1.0),
+    ];
+    let backward = vec![DMatch::new(0, 2, 0, 1.0), DMatch::new(1, 0, 0, 1.0)];
+    let checked = cross_check(&forward, &backward);
+    assert_eq!(checked.len(), 2);
+    assert_eq!(checked[0].query_idx, 0);
+    assert_eq!(checked[1].query_idx, 2);
+
+    let forward = vec![DMatch::new(0, 1, 0, 1.0), DMatch::new(1, 1, 1, 1.0)];
+    let backward = vec![DMatch::new(1, 0, 0, 1.0), DMatch::new(1, 1, 1, 1.0)];
+    let checked = cross_check(&forward, &backward);
+    assert_eq!(checked.len(), 2);
+    let backward = vec![DMatch::new(1, 1, 0, 1.0), DMatch::new(1, 0, 1, 1.0)];
+    assert!(cross_check(&forward, &backward).is_empty());
+
+    let descriptors = orb_descriptors();
+    let matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForceHamming);
+    let matches = matcher.cross_check_match(&descriptors, &descriptors);
+    assert!(!matches.is_empty());
+    assert!(matches.iter().all(|m| m.distance == 0.0));
+}
+
+#[test]
+fn bow_trainer_accessors() {
+    let mut bow = BOWKMeansTrainer::new(2, TermCriteria::new(TermType::Count, 100, 0.01), 1, KMeansCenters::Pp);
+    let descriptors = sift_descriptors();
+    bow.add(&descriptors);
+    bow.add(&descriptors);
+    assert_eq!(bow.descriptors_count(), descriptors.rows * 2);
+    let added = bow.get_descriptors();
+    assert_eq!(added.len(), 2);
+    assert_eq!(added[1].rows, descriptors.rows);
+    bow.clear();
+    assert_eq!(bow.descriptors_count(), 0);
+    assert!(bow.get_descriptors().is_empty());
+}
+
+fn sift_descriptors() -> Mat {
+    let lenna = load_lenna();
+    let sift: SIFT = SIFTBuilder::default().features(200).into();
+    let (_, descriptors) = sift.detect_and_compute(&lenna, &Mat::new());
+    descriptors
+}
+
+#[test]
+fn bow_img_descriptor_extractor() {
+    let lenna = load_lenna();
+    let sift: SIFT = SIFTBuilder::default().features(200).into();
+    let mut bow = BOWKMeansTrainer::new(8, TermCriteria::new(TermType::Count, 100, 0.01), 1, KMeansCenters::Pp);
+    bow.add(&sift_descriptors());
+    let vocabulary = bow.cluster();
+
+    let matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForce);
+    let mut extractor = BOWImgDescriptorExtractor::new(&sift, &matcher);
+    assert_eq!(extractor.descriptor_size(), 0);
+    let mut keypoints = sift.detect(&lenna, &Mat::new());
+    assert!(extractor.compute(&lenna, &mut keypoints).is_err());
+
+    extractor.set_vocabulary(&vocabulary);
+    assert_eq!(extractor.descriptor_size(), 8);
+    assert_eq!(extractor.descriptor_type(), Some(CvType::Cv32FC1));
+    assert_eq!(extractor.get_vocabulary().rows, 8);
+
+    let (descriptor, clusters) = extractor.compute_with_clusters(&lenna, &mut keypoints).unwrap();
+    assert_eq!(descriptor.rows, 1);
+    assert_eq!(descriptor.cols, 8);
+    assert_eq!(clusters.len(), 8);
+    assert_eq!(clusters.iter().map(Vec::len).sum::<usize>(), keypoints.len());
+
+    let from_descriptors = extractor
+        .compute_from_descriptors(&sift.compute(&lenna, &mut keypoints))
+        .unwrap();
+    assert_eq!(from_descriptors.cols, 8);
+}
+
+#[test]
+fn bow_vocabulary_save_and_load() {
+    let mut bow = BOWKMeansTrainer::new(4, TermCriteria::new(TermType::Count, 100, 0.01), 1, KMeansCenters::Pp);
+    bow.add(&sift_descriptors());
+    let vocabulary = bow.cluster();
+
+    let sift: SIFT = SIFTBuilder::default().into();
+    let matcher = DescriptorMatcher::new(DescriptorMatcherType::BruteForce);
+    let mut extractor = BOWImgDescriptorExtractor::new(&sift, &matcher);
+    extractor.set_vocabulary(&vocabulary);
+
+    let path = std::env::temp_dir().join("cv_rs_bow_vocabulary.yml");
+    extractor.save_vocabulary(&path).unwrap();
+    let mut loaded = BOWImgDescriptorExtractor::new(&sift, &matcher);
+    loaded.load_vocabulary(&path).unwrap();
+    assert_eq!(loaded.get_vocabulary().rows, 4);
+    assert_eq!(
+        Mat::from_file_storage(&path, BOW_VOCABULARY_SECTION).unwrap().cols,
+        vocabulary.cols
+    );
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(loaded.load_vocabulary(&path).is_err());
+}
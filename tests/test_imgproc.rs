@@ -81,3 +81,376 @@ fn canny_edge_detection() {
     // error: (-206:Bad flag (parameter or structure field)) Aperture size should be odd between 3 and 7 in function \'Canny\'
     assert!(bad_edges.is_err());
 }
+
+#[test]
+fn flood_fill_whole_image() {
+    let mut mat = Mat::zeros(10, 20, CvType::Cv8UC1 as i32);
+    let (area, rect) = mat
+        .flood_fill(
+            Point2i::new(5, 5),
+            Scalar::all(255),
+            Scalar::all(0),
+            Scalar::all(0),
+            FloodFillFlags::default(),
+        )
+        .unwrap();
+    assert_eq!(area, 200);
+    assert_eq!(rect, Rect::new(0, 0, 20, 10));
+    assert_eq!(mat.count_non_zero(), 200);
+}
+
+#[test]
+fn flood_fill_mask_only() {
+    let mut mat = Mat::zeros(10, 20, CvType::Cv8UC1 as i32);
+    let mut mask = Mat::zeros(12, 22, CvType::Cv8UC1 as i32);
+    let mut flags = FloodFillFlags::default();
+    flags.mask_only = true;
+    flags.mask_fill_value = 255;
+    let (area, _) = mat
+        .flood_fill_with_mask(
+            &mut mask,
+            Point2i::new(0, 0),
+            Scalar::all(255),
+            Scalar::all(0),
+            Scalar::all(0),
+            flags,
+        )
+        .unwrap();
+    assert_eq!(area, 200);
+    assert_eq!(mat.count_non_zero(), 0);
+    assert_eq!(mask.count_non_zero(), 200);
+}
+
+#[test]
+fn watershed_labels_regions() {
+    let image = utils::load_messi_color();
+    let mut markers = Mat::zeros(image.rows, image.cols, CvType::Cv32SC1 as i32);
+    markers.rectangle_custom(Rect::new(0, 0, 20, 20), Scalar::all(1), -1, LineType::Line8);
+    markers.rectangle_custom(Rect::new(200, 150, 20, 20), Scalar::all(2), -1, LineType::Line8);
+    image.watershed(&mut markers).unwrap();
+    assert_eq!(markers.at2::<i32>(10, 10), 1);
+    assert_eq!(markers.at2::<i32>(160, 210), 2);
+}
+
+#[test]
+fn watershed_rejects_grayscale() {
+    let image = utils::load_lenna();
+    let mut markers = Mat::zeros(image.rows, image.cols, CvType::Cv32SC1 as i32);
+    assert!(image.watershed(&mut markers).is_err());
+}
+
+#[test]
+fn grab_cut_with_rect() {
+    let image = utils::load_messi_color();
+    let rect = Rect::new(50, 50, image.cols - 100, image.rows - 100);
+    let mut grab_cut = GrabCut::with_rect(&image, rect, 1).unwrap();
+    assert_eq!(grab_cut.mask().rows, image.rows);
+    assert_eq!(grab_cut.mask().cols, image.cols);
+    assert_eq!(grab_cut.mask().at2::<u8>(0, 0), GrabCutClass::Background as u8);
+    grab_cut.refine(&image, 1).unwrap();
+
+    let foreground = grab_cut.foreground_mask();
+    let inside = foreground.count_non_zero();
+    assert!(inside > 0);
+    assert!(inside <= rect.width * rect.height);
+}
+
+#[test]
+fn distance_transform_of_threshold() {
+    let lenna = utils::load_lenna();
+    let binary = lenna.threshold(100.0, 255.0, ThresholdType::Binary);
+    let dist = binary
+        .distance_transform(DistanceType::L2, DistanceTransformMask::Mask3)
+        .unwrap();
+    assert_eq!(dist.size().width, lenna.cols);
+    assert_eq!(dist.size().height, lenna.rows);
+    assert_eq!(dist.cv_type(), CvType::Cv32FC1);
+}
+
+#[test]
+fn distance_transform_with_labels() {
+    let image = Mat::zeros(50, 50, CvType::Cv8UC1 as i32);
+    image.rectangle_custom(Rect::new(10, 10, 30, 30), Scalar::all(255), -1, LineType::Line8);
+    let (dist, labels) = image
+        .distance_transform_with_labels(
+            DistanceType::L2,
+            DistanceTransformMask::Mask5,
+            DistanceTransformLabelType::ConnectedComponent,
+        )
+        .unwrap();
+    assert_eq!(dist.at2::<f32>(0, 0), 0.0);
+    assert!(dist.at2::<f32>(25, 25) > 10.0);
+    assert_eq!(labels.cv_type(), CvType::Cv32SC1);
+    assert_eq!(labels.at2::<i32>(25, 25), labels.at2::<i32>(0, 0));
+}
+
+#[test]
+fn distance_transform_rejects_color_image() {
+    let messi = utils::load_messi_color();
+    let result = messi.distance_transform(DistanceType::L2, DistanceTransformMask::Mask3);
+    assert!(result.is_err());
+}
+
+#[test]
+fn color_conversion_aliases() {
+    assert_eq!(ColorConversion::RGB2BGR, ColorConversion::BGR2RGB);
+    assert_eq!(ColorConversion::YUV2BGR_I420, ColorConversion::YUV2BGR_IYUV);
+    assert_eq!(ColorConversion::BayerBG2RGB, ColorConversion::BayerRG2BGR);
+    assert_eq!(ColorConversion::YUV420sp2BGR as i32, 93);
+}
+
+#[test]
+fn cvt_color_yuv420_to_bgr() {
+    let messi = utils::load_messi_color();
+    let yuv = messi.cvt_color(ColorConversion::BGR2YUV_I420);
+    assert_eq!(yuv.rows, messi.rows * 3 / 2);
+    let bgr = yuv.cvt_color(ColorConversion::YUV2BGR_I420);
+    assert_eq!(bgr.rows, messi.rows);
+    assert_eq!(bgr.channels, 3);
+}
+
+#[test]
+fn demosaicing_variants() {
+    let lenna = utils::load_lenna();
+    for code in &[
+        ColorConversion::BayerBG2BGR,
+        ColorConversion::BayerBG2BGR_VNG,
+        ColorConversion::BayerBG2BGR_EA,
+    ] {
+        let bgr = lenna.demosaicing(*code, 0).unwrap();
+        assert_eq!(bgr.channels, 3);
+        assert_eq!(bgr.rows, lenna.rows);
+    }
+    assert!(lenna.demosaicing(ColorConversion::BGR2GRAY, 0).is_err());
+}
+
+#[test]
+fn histogram_builder_matches_calc_hist() {
+    let image = Mat::from_path(FIRST_IMAGE_PATH, ImageReadMode::Color).unwrap();
+    let hsv = image.cvt_color(ColorConversion::BGR2HSV);
+    let expected = hsv.calc_hist(&[0, 1], &Mat::new(), &[50, 60], &[[0_f32, 180_f32], [0_f32, 256_f32]]);
+    let hist = Histogram::new()
+        .channels(&[0, 1])
+        .bins(&[50, 60])
+        .ranges(&[&[0.0, 180.0], &[0.0, 256.0]])
+        .calc(&[&hsv], &Mat::new())
+        .unwrap();
+    assert_eq!(hist.rows, 50);
+    assert_eq!(hist.cols, 60);
+    assert_eq!(hist.data(), expected.data());
+}
+
+#[test]
+fn histogram_rejects_inconsistent_dimensions() {
+    let lenna = utils::load_lenna();
+    let result = Histogram::new()
+        .channels(&[0, 1])
+        .bins(&[256])
+        .ranges(&[&[0.0, 256.0]])
+        .calc(&[&lenna], &Mat::new());
+    assert!(result.is_err());
+    let result = Histogram::new()
+        .channels(&[0])
+        .bins(&[2])
+        .ranges(&[&[0.0, 128.0, 256.0]])
+        .uniform(true)
+        .calc(&[&lenna], &Mat::new());
+    assert!(result.is_err());
+}
+
+#[test]
+fn histogram_accumulate() {
+    let lenna = utils::load_lenna();
+    let builder = Histogram::new().channels(&[0]).bins(&[16]).ranges(&[&[0.0, 256.0]]);
+    let mut hist = builder.calc(&[&lenna], &Mat::new()).unwrap();
+    builder
+        .clone()
+        .accumulate(true)
+        .calc_into(&[&lenna], &Mat::new(), &mut hist)
+        .unwrap();
+    let total: f32 = (0..16).map(|i| hist.at2::<f32>(i, 0)).sum();
+    assert_eq!(total as i32, 2 * lenna.rows * lenna.cols);
+}
+
+#[test]
+fn histogram_non_uniform_bins() {
+    let lenna = utils::load_lenna();
+    let hist = Histogram::new()
+        .channels(&[0])
+        .bins(&[2])
+        .ranges(&[&[0.0, 10.0, 256.0]])
+        .uniform(false)
+        .calc(&[&lenna], &Mat::new())
+        .unwrap();
+    let total = hist.at2::<f32>(0, 0) + hist.at2::<f32>(1, 0);
+    assert_eq!(total as i32, lenna.rows * lenna.cols);
+}
+
+#[test]
+fn histogram_back_project() {
+    let image = Mat::from_path(FIRST_IMAGE_PATH, ImageReadMode::Color).unwrap();
+    let hsv = image.cvt_color(ColorConversion::BGR2HSV);
+    let builder = Histogram::new().channels(&[0]).bins(&[30]).ranges(&[&[0.0, 180.0]]);
+    let hist = builder.calc(&[&hsv], &Mat::new()).unwrap();
+    let hist = hist.normalize(0.0, 255.0, NormType::MinMax);
+    let back_project = builder.back_project(&[&hsv], &hist, 1.0).unwrap();
+    assert_eq!(back_project.rows, hsv.rows);
+    assert_eq!(back_project.cols, hsv.cols);
+    assert!(back_project.count_non_zero() > 0);
+}
+
+#[test]
+fn sparse_histogram() {
+    let image = Mat::from_path(FIRST_IMAGE_PATH, ImageReadMode::Color).unwrap();
+    let builder = Histogram::new().channels(&[0, 1, 2]).bins(&[32, 32, 32]).ranges(&[
+        &[0.0, 256.0],
+        &[0.0, 256.0],
+        &[0.0, 256.0],
+    ]);
+    let sparse = builder.calc_sparse(&[&image], &Mat::new()).unwrap();
+    assert!(sparse.nz_count() > 0);
+    assert!(sparse.nz_count() < 32 * 32 * 32);
+    assert_eq!(sparse.dims(), 3);
+    assert!(sparse.value(&[0, 0]).is_err());
+    assert!(sparse.value(&[]).is_err());
+    let max_bin = (0..32 * 32 * 32)
+        .map(|i| sparse.value(&[i / 1024, i / 32 % 32, i % 32]).unwrap())
+        .fold(0.0, f32::max);
+    assert!(max_bin > 0.0);
+    let dense = sparse.to_dense();
+    assert_eq!(dense.count_non_zero() as usize, sparse.nz_count());
+    let other = builder.calc_sparse(&[&image], &Mat::new()).unwrap();
+    let correlation = sparse.compare(&other, HistogramComparisionMethod::Correlation).unwrap();
+    assert_eq(correlation, 1.0);
+}
+
+#[test]
+fn equalize_hist() {
+    let lenna = utils::load_lenna();
+    let equalized = lenna.equalize_hist().unwrap();
+    let (_, max, _, _) = equalized.min_max_loc(&Mat::new());
+    assert_eq!(max, 255.0);
+    assert_eq!(equalized.size().width, lenna.cols);
+    let messi = utils::load_messi_color();
+    assert!(messi.equalize_hist().is_err());
+}
+
+#[test]
+fn emd_between_signatures() {
+    let first = Mat::from_buffer(1, 2, CvType::Cv32FC1, &utils::to_bytes(&[1.0f32, 0.0]));
+    let second = Mat::from_buffer(1, 2, CvType::Cv32FC1, &utils::to_bytes(&[1.0f32, 3.0]));
+    let distance = first.emd(&second, DistanceType::L1).unwrap();
+    assert_eq(distance as f64, 3.0);
+    assert!(first.emd(&second, DistanceType::User).is_err());
+}
+
+#[test]
+fn integral_sum_of_rect() {
+    let lenna = utils::load_lenna();
+    let sum = lenna.integral(-1).unwrap();
+    assert_eq!(sum.rows, lenna.rows + 1);
+    assert_eq!(sum.cols, lenna.cols + 1);
+    assert_eq!(sum.cv_type(), CvType::Cv32SC1);
+
+    let rect = Rect::new(10, 20, 3, 2);
+    let expected: f64 = (20..22)
+        .flat_map(|y| (10..13).map(move |x| (y, x)))
+        .map(|(y, x)| f64::from(lenna.at2::<u8>(y, x)))
+        .sum();
+    assert_eq!(sum.integral_sum(rect), Some(expected));
+    assert_eq!(sum.integral_sum(Rect::new(0, 0, 0, 0)), Some(0.0));
+    let whole = Rect::new(0, 0, lenna.cols, lenna.rows);
+    assert!(sum.integral_sum(whole).is_some());
+}
+
+#[test]
+fn integral_sum_out_of_bounds() {
+    let lenna = utils::load_lenna();
+    let sum = lenna.integral(-1).unwrap();
+    assert_eq!(sum.integral_sum(Rect::new(-1, 0, 2, 2)), None);
+    assert_eq!(sum.integral_sum(Rect::new(0, 0, lenna.cols + 1, 1)), None);
+    assert_eq!(lenna.integral_sum(Rect::new(0, 0, 1, 1)), None);
+}
+
+#[test]
+fn integral2_and_integral3() {
+    let image = Mat::from_buffer(2, 2, CvType::Cv8UC1, &[1, 2, 3, 4]);
+    let (sum, sqsum) = image.integral2(CvType::Cv64FC1 as i32, -1).unwrap();
+    assert_eq!(sum.cv_type(), CvType::Cv64FC1);
+    assert_eq!(sum.integral_sum(Rect::new(0, 0, 2, 2)), Some(10.0));
+    assert_eq!(sqsum.integral_sum(Rect::new(0, 0, 2, 2)), Some(30.0));
+    assert_eq!(sqsum.integral_sum(Rect::new(1, 1, 1, 1)), Some(16.0));
+
+    let (sum, _, tilted) = image.integral3(-1, -1).unwrap();
+    assert_eq!(sum.integral_sum(Rect::new(0, 1, 2, 1)), Some(7.0));
+    assert_eq!(tilted.rows, 3);
+    assert_eq!(tilted.cols, 3);
+}
+
+#[test]
+fn good_features_to_track() {
+    let lenna = utils::load_lenna();
+    let params = GoodFeaturesParams {
+        max_corners: 50,
+        min_distance: 10.0,
+        ..Default::default()
+    };
+    let corners = lenna.good_features_to_track(&params, &Mat::new()).unwrap();
+    assert_eq!(corners.len(), 50);
+    for (i, a) in corners.iter().enumerate() {
+        assert!(a.x >= 0.0 && a.x < lenna.cols as f32);
+        assert!(a.y >= 0.0 && a.y < lenna.rows as f32);
+        for b in &corners[i + 1..] {
+            let distance = ((a.x - b.x).powi(2) + (a.y - b.y).powi(2)).sqrt();
+            assert!(distance >= 10.0);
+        }
+    }
+
+    let harris = GoodFeaturesParams {
+        max_corners: 20,
+        use_harris_detector: true,
+        ..Default::default()
+    };
+    let corners = lenna.good_features_to_track(&harris, &Mat::new()).unwrap();
+    assert_eq!(corners.len(), 20);
+}
+
+#[test]
+fn good_features_to_track_with_mask() {
+    let lenna = utils::load_lenna();
+    let mask = Mat::zeros(lenna.rows, lenna.cols, CvType::Cv8UC1 as i32);
+    mask.rectangle_custom(Rect::new(100, 100, 100, 100), Scalar::all(255), -1, LineType::Line8);
+    let corners = lenna
+        .good_features_to_track(&GoodFeaturesParams::default(), &mask)
+        .unwrap();
+    assert!(!corners.is_empty());
+    assert!(corners
+        .iter()
+        .all(|p| p.x >= 100.0 && p.x < 200.0 && p.y >= 100.0 && p.y < 200.0));
+}
+
+#[test]
+fn corner_harris_and_min_eigen_val() {
+    let lenna = utils::load_lenna();
+    let harris = lenna.corner_harris(2, 3, 0.04, BorderType::Default).unwrap();
+    assert_eq!(harris.cv_type(), CvType::Cv32FC1);
+    assert_eq!(harris.rows, lenna.rows);
+    let eigen = lenna.corner_min_eigen_val(2, 3, BorderType::Default).unwrap();
+    assert_eq!(eigen.cv_type(), CvType::Cv32FC1);
+    assert_eq!(eigen.cols, lenna.cols);
+    assert!(lenna.corner_harris(2, 4, 0.04, BorderType::Default).is_err());
+}
+
+#[test]
+fn corner_sub_pix_refines_square_corner() {
+    let image = Mat::zeros(100, 100, CvType::Cv8UC1 as i32);
+    image.rectangle_custom(Rect::new(30, 30, 40, 40), Scalar::all(255), -1, LineType::Line8);
+    let corners = vec![Point2f::new(31.0, 32.0)];
+    let criteria = TermCriteria::new(TermType::CountEps, 40, 0.001);
+    let refined = image
+        .corner_sub_pix(&corners, Size2i::new(5, 5), Size2i::new(-1, -1), &criteria)
+        .unwrap();
+    assert_eq!(refined.len(), 1);
+    assert!((refined[0].x - 29.5).abs() < 1.0);
+    assert!((refined[0].y - 29.5).abs() < 1.0);
+}
@@ -1,7 +1,13 @@
 extern crate cv;
 mod utils;
 
+use cv::imgcodecs::ImageReadMode;
+use cv::imgproc::ColorConversion;
+use cv::video::analysis::*;
+use cv::video::tracking::*;
 use cv::videoio::*;
+use cv::*;
+use utils::*;
 
 #[test]
 fn test_ayuv() {
@@ -29,3 +35,290 @@ fn test_4cc(string: &'static str, integer: u32) {
     assert_eq!(string_value, string);
     assert_eq!(integer_value, integer);
 }
+
+/// Consecutive frames where lenna moves 3 pixels to the right
+fn moving_frames() -> (Mat, Mat) {
+    let gray = load_lenna();
+    let width = gray.cols - 3;
+    let prev = gray.roi(Rect::new(3, 0, width, gray.rows));
+    let next = gray.roi(Rect::new(0, 0, width, gray.rows));
+    (prev, next)
+}
+
+#[test]
+fn optical_flow_farneback() {
+    let (prev, next) = moving_frames();
+    let params = FarnebackParams::default();
+    let flow = calc_optical_flow_farneback(&prev, &next, &params, None).unwrap();
+    assert_eq!(flow.cv_type(), CvType::Cv32FC2);
+    assert_eq!((flow.rows, flow.cols), (prev.rows, prev.cols));
+    assert!((flow.at3::<f32>(256, 250, 0) - 3.0).abs() < 0.5);
+    assert!(flow.at3::<f32>(256, 250, 4).abs() < 0.5);
+
+    let refined = calc_optical_flow_farneback(&prev, &next, &params, Some(&flow)).unwrap();
+    assert!((refined.at3::<f32>(256, 250, 0) - 3.0).abs() < 0.5);
+    assert!(calc_optical_flow_farneback(&prev, &load_lenna(), &params, None).is_err());
+}
+
+#[test]
+fn optical_flow_dis() {
+    let (prev, next) = moving_frames();
+    let mut dis = match DISOpticalFlow::new(DISPreset::Medium) {
+        Ok(dis) => dis,
+        // Not available before OpenCV 4
+        Err(e) => return assert!(e.to_string().contains("OpenCV 4")),
+    };
+    dis.set_patch_size(12);
+    assert_eq!(dis.patch_size(), 12);
+    dis.set_use_spatial_propagation(true);
+    assert!(dis.use_spatial_propagation());
+    let flow = dis.calc(&prev, &next, None).unwrap();
+    assert_eq!(flow.cv_type(), CvType::Cv32FC2);
+    assert!((flow.at3::<f32>(256, 250, 0) - 3.0).abs() < 0.5);
+}
+
+#[test]
+fn flow_visualization() {
+    let (prev, next) = moving_frames();
+    let flow = calc_optical_flow_farneback(&prev, &next, &FarnebackParams::default(), None).unwrap();
+    let color = flow_to_color(&flow, Some(3.0)).unwrap();
+    assert_eq!(color.cv_type(), CvType::Cv8UC3);
+    assert_eq!((color.rows, color.cols), (flow.rows, flow.cols));
+    // motion to the right has a red hue
+    let (b, r) = (color.at3::<u8>(256, 250, 0), color.at3::<u8>(256, 250, 2));
+    assert!(r > 200 && b < 100);
+    assert!(flow_to_color(&prev, None).is_err());
+}
+
+fn lk_points() -> Vec<Point2f> {
+    (0..5)
+        .flat_map(|i| (0..5).map(move |j| Point2f::new(200.0 + 20.0 * i as f32, 200.0 + 20.0 * j as f32)))
+        .collect()
+}
+
+fn assert_tracked(prev_pts: &[Point2f], next_pts: &[Point2f], status: &[bool], err: &[f32]) {
+    assert_eq!(next_pts.len(), prev_pts.len());
+    assert_eq!(status.len(), prev_pts.len());
+    assert_eq!(err.len(), prev_pts.len());
+    let tracked = status.iter().filter(|&&s| s).count();
+    assert!(tracked > prev_pts.len() * 3 / 4);
+    for ((p, n), _) in prev_pts.iter().zip(next_pts).zip(status).filter(|t| *t.1) {
+        assert!((n.x - p.x - 3.0).abs() < 0.2, "{:?} -> {:?}", p, n);
+        assert!((n.y - p.y).abs() < 0.2, "{:?} -> {:?}", p, n);
+    }
+}
+
+#[test]
+fn optical_flow_pyr_lk() {
+    let (prev, next) = moving_frames();
+    let prev_pts = lk_points();
+    let criteria = TermCriteria::new(TermType::CountEps, 30, 0.01);
+    let win_size = Size2i::new(21, 21);
+    let (next_pts, status, err) = calc_optical_flow_pyr_lk(&prev, &next, &prev_pts, win_size, 3, &criteria).unwrap();
+    assert_tracked(&prev_pts, &next_pts, &status, &err);
+
+    let (next_pts, status, err) = calc_optical_flow_pyr_lk(&prev, &next, &[], win_size, 3, &criteria).unwrap();
+    assert!(next_pts.is_empty() && status.is_empty() && err.is_empty());
+}
+
+#[test]
+fn optical_flow_pyr_lk_with_pyramids() {
+    let (prev, next) = moving_frames();
+    let win_size = Size2i::new(21, 21);
+    let prev_pyramid = build_optical_flow_pyramid(&prev, win_size, 3, true).unwrap();
+    let next_pyramid = build_optical_flow_pyramid(&next, win_size, 3, true).unwrap();
+    assert_eq!(prev_pyramid.len(), 8);
+    assert_eq!(prev_pyramid[0].cols, prev.cols);
+    assert_eq!(prev_pyramid[1].cv_type(), CvType::Cv16SC2);
+    assert_eq!(prev_pyramid[2].cols, prev.cols / 2);
+    assert_eq!(build_optical_flow_pyramid(&prev, win_size, 3, false).unwrap().len(), 4);
+
+    let prev_pts = lk_points();
+    let criteria = TermCriteria::new(TermType::CountEps, 30, 0.01);
+    let (next_pts, status, err) =
+        calc_optical_flow_pyr_lk_pyramids(&prev_pyramid, &next_pyramid, &prev_pts, win_size, 3, &criteria).unwrap();
+    assert_tracked(&prev_pts, &next_pts, &status, &err);
+}
+
+/// Back projection with a 30x30 blob whose center is (135, 115)
+fn blob_back_project() -> Mat {
+    let mut values = vec![0u8; 200 * 200];
+    for y in 100..130 {
+        for x in 120..150 {
+            values[y * 200 + x] = 255;
+        }
+    }
+    Mat::from_buffer(200, 200, CvType::Cv8UC1, &values)
+}
+
+#[test]
+fn mean_shift_moves_window() {
+    let back_project = blob_back_project();
+    let mut window = Rect::new(100, 80, 40, 40);
+    let criteria = TermCriteria::new(TermType::CountEps, 20, 0.5);
+    let iterations = back_project.mean_shift(&mut window, &criteria).unwrap();
+    assert!(iterations > 0 && iterations <= 20);
+    assert_eq!((window.width, window.height), (40, 40));
+    assert!((window.x + 20 - 135).abs() <= 1 && (window.y + 20 - 115).abs() <= 1);
+}
+
+#[test]
+fn cam_shift_adapts_window() {
+    let back_project = blob_back_project();
+    let mut window = Rect::new(110, 90, 30, 30);
+    let criteria = TermCriteria::new(TermType::CountEps, 20, 0.5);
+    let object = back_project.cam_shift(&mut window, &criteria).unwrap();
+    let bounds = object.bounding_rect();
+    assert!((bounds.x + bounds.width / 2 - 135).abs() <= 2 && (bounds.y + bounds.height / 2 - 115).abs() <= 2);
+    assert!(window.x <= 120 && window.y <= 100);
+    assert!(window.x + window.width >= 150 && window.y + window.height >= 130);
+
+    let mut empty = Rect::new(0, 0, 0, 0);
+    assert!(back_project.cam_shift(&mut empty, &criteria).is_err());
+}
+
+#[test]
+fn hue_back_projection() {
+    let lenna = Mat::from_path(get_asset_path("lenna.png"), ImageReadMode::Color).unwrap();
+    let hsv = lenna.cvt_color(ColorConversion::BGR2HSV);
+    let lowerb = Scalar::new(0, 30, 10, 0);
+    let upperb = Scalar::new(180, 256, 256, 0);
+    // Saturated skin and hair tones, almost every pixel passes the mask
+    let roi = Rect::new(200, 200, 100, 100);
+    let hist = calc_hue_histogram(&hsv, roi, 16, lowerb, upperb);
+    assert_eq!(hist.rows, 16);
+    assert_eq!(hist.min_max_loc(&Mat::new()).1, 255.0);
+
+    let back_project = calc_hue_back_project(&hsv, &hist, lowerb, upperb);
+    assert_eq!(back_project.cv_type(), CvType::Cv8UC1);
+    assert_eq!((back_project.rows, back_project.cols), (hsv.rows, hsv.cols));
+    assert!(back_project.roi(roi).count_non_zero() > 100 * 100 / 2);
+
+    let mut window = Rect::new(220, 220, 60, 60);
+    let criteria = TermCriteria::new(TermType::Count, 10, 1.0);
+    back_project.cam_shift(&mut window, &criteria).unwrap();
+    assert!(window.width > 0 && window.height > 0);
+}
+
+/// Uniform gray frame, with a white 20x20 square at (40, 30) if `with_object`
+fn surveillance_frame(with_object: bool) -> Mat {
+    let mut values = vec![100u8; 120 * 160];
+    if with_object {
+        for y in 30..50 {
+            for x in 40..60 {
+                values[y * 160 + x] = 250;
+            }
+        }
+    }
+    Mat::from_buffer(120, 160, CvType::Cv8UC1, &values)
+}
+
+fn check_foreground<T: BackgroundSubtractor>(subtractor: &mut T) {
+    let background = surveillance_frame(false);
+    for _ in 0..20 {
+        let mask = subtractor.apply(&background, -1.0).unwrap();
+        assert_eq!(mask.cv_type(), CvType::Cv8UC1);
+    }
+    let mask = subtractor.apply(&surveillance_frame(true), 0.0).unwrap();
+    assert_eq!((mask.rows, mask.cols), (120, 160));
+    assert_eq!(mask.at2::<u8>(40, 50), 255);
+    assert_eq!(mask.at2::<u8>(100, 100), 0);
+    assert_eq!(mask.count_non_zero(), 20 * 20);
+
+    let learned = subtractor.get_background_image().unwrap();
+    assert_eq!(learned.at2::<u8>(40, 50), 100);
+}
+
+#[test]
+fn background_subtractor_mog2() {
+    let mut mog2: BackgroundSubtractorMOG2 = BackgroundSubtractorMOG2Builder::default()
+        .history(50)
+        .detect_shadows(false)
+        .into();
+    assert_eq!(mog2.history(), 50);
+    assert_eq!(mog2.var_threshold(), 16.0);
+    assert!(!mog2.detect_shadows());
+    check_foreground(&mut mog2);
+}
+
+#[test]
+fn background_subtractor_knn() {
+    let mut knn: BackgroundSubtractorKNN = BackgroundSubtractorKNNBuilder::default()
+        .history(50)
+        .dist2_threshold(200.0)
+        .into();
+    assert_eq!(knn.history(), 50);
+    assert_eq!(knn.dist2_threshold(), 200.0);
+    assert!(knn.detect_shadows());
+    check_foreground(&mut knn);
+}
+
+/// Constant velocity model with (x, y, vx, vy) state and (x, y) measurement
+fn constant_velocity_filter() -> KalmanFilter {
+    let mut kf = KalmanFilter::new(4, 2, 0, CvType::Cv32FC1).unwrap();
+    let transition = [
+        1.0f32, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+    ];
+    let measurement = [1.0f32, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0];
+    kf.set_transition_matrix(&Mat::from_buffer(4, 4, CvType::Cv32FC1, &to_bytes(&transition)))
+        .unwrap();
+    kf.set_measurement_matrix(&Mat::from_buffer(2, 4, CvType::Cv32FC1, &to_bytes(&measurement)))
+        .unwrap();
+    kf.set_process_noise_cov(&Mat::from_buffer(4, 4, CvType::Cv32FC1, &to_bytes(&[1e-4f32; 16])))
+        .unwrap();
+    let noise = [0.1f32, 0.0, 0.0, 0.1];
+    kf.set_measurement_noise_cov(&Mat::from_buffer(2, 2, CvType::Cv32FC1, &to_bytes(&noise)))
+        .unwrap();
+    kf
+}
+
+#[test]
+fn kalman_filter_defaults() {
+    let kf = KalmanFilter::new(4, 2, 0, CvType::Cv64FC1).unwrap();
+    let transition = kf.transition_matrix();
+    assert_eq!(transition.cv_type(), CvType::Cv64FC1);
+    assert_eq!((transition.rows, transition.cols), (4, 4));
+    assert_eq!(transition.at2::<f64>(2, 2), 1.0);
+    assert_eq!(transition.at2::<f64>(0, 1), 0.0);
+    assert_eq!((kf.measurement_matrix().rows, kf.measurement_matrix().cols), (2, 4));
+    assert_eq!((kf.state_post().rows, kf.state_post().cols), (4, 1));
+    assert_eq!((kf.gain().rows, kf.gain().cols), (4, 2));
+    assert_eq!(kf.control_matrix().rows, 0);
+
+    assert!(KalmanFilter::new(0, 2, 0, CvType::Cv32FC1).is_err());
+    assert!(KalmanFilter::new(4, 2, 0, CvType::Cv8UC1).is_err());
+}
+
+#[test]
+fn kalman_filter_tracks_constant_velocity() {
+    let mut kf = constant_velocity_filter();
+    for t in 1..40 {
+        let predicted = kf.predict(None).unwrap();
+        assert_eq!((predicted.rows, predicted.cols), (4, 1));
+        let measurement = [t as f32, 2.0 * t as f32];
+        kf.correct(&Mat::from_buffer(2, 1, CvType::Cv32FC1, &to_bytes(&measurement)))
+            .unwrap();
+    }
+
+    let state = kf.state_post();
+    assert!((state.at2::<f32>(2, 0) - 1.0).abs() < 0.1);
+    assert!((state.at2::<f32>(3, 0) - 2.0).abs() < 0.1);
+    let predicted = kf.predict(None).unwrap();
+    assert!((predicted.at2::<f32>(0, 0) - 40.0).abs() < 0.5);
+    assert!((predicted.at2::<f32>(1, 0) - 80.0).abs() < 0.5);
+    assert_eq!(kf.state_pre().at2::<f32>(0, 0), predicted.at2::<f32>(0, 0));
+}
+
+#[test]
+fn kalman_filter_rejects_mismatched_matrices() {
+    let mut kf = constant_velocity_filter();
+    assert!(kf.set_transition_matrix(&Mat::eye(3, 3, CvType::Cv32FC1)).is_err());
+    assert!(kf.set_transition_matrix(&Mat::eye(4, 4, CvType::Cv64FC1)).is_err());
+    assert!(kf.correct(&Mat::eye(3, 1, CvType::Cv32FC1)).is_err());
+
+    let state = Mat::from_buffer(4, 1, CvType::Cv32FC1, &to_bytes(&[5.0f32, 6.0, 0.0, 0.0]));
+    kf.set_state_post(&state).unwrap();
+    let predicted = kf.predict(None).unwrap();
+    assert_eq!(predicted.at2::<f32>(0, 0), 5.0);
+    assert_eq!(predicted.at2::<f32>(1, 0), 6.0);
+}
@@ -17,6 +17,12 @@ pub fn close_rect(a: Rect, b: Rect, epsilon: i32) -> bool {
         && (a.height - b.height) < epsilon
 }
 
+/// Raw bytes of `values`, e.g. to build a `Mat` with `Mat::from_buffer`
+pub fn to_bytes<T: Copy>(values: &[T]) -> Vec<u8> {
+    let size = values.len() * std::mem::size_of::<T>();
+    unsafe { std::slice::from_raw_parts(values.as_ptr() as *const u8, size) }.to_vec()
+}
+
 pub fn timed<F>(label: &str, inner: F)
 where
     F: FnMut(),